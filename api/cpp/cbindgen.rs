@@ -100,6 +100,10 @@ macro_rules! struct_file {
             writeln!(structs_priv, "using slint::StandardListViewItem;")?;
             &mut structs_pub
         }};
+        (TreeViewItem) => {{
+            writeln!(structs_priv, "using slint::TreeViewItem;")?;
+            &mut structs_pub
+        }};
         ($_:ident) => {
             &mut structs_priv
         };
@@ -186,6 +190,7 @@ fn default_config() -> cbindgen::Config {
             ("VoidArg".into(), "void".into()),
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
+            ("TouchEventArg".into(), "TouchEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
             ("FloatArg".into(), "float".into()),
             ("Coord".into(), "float".into()),
@@ -246,6 +251,7 @@ fn gen_corelib(
         "TouchArea",
         "FocusScope",
         "Flickable",
+        "MultiTouchArea",
         "Text",
         "Path",
         "WindowItem",
@@ -277,6 +283,7 @@ fn gen_corelib(
         "PointerEventKind",
         "PointerEventButton",
         "PointerEvent",
+        "TouchEvent",
         "Rect",
         "SortOrder",
         "BitmapFont",
@@ -297,6 +304,7 @@ fn gen_corelib(
         "GraphicsAPI",
         "CloseRequestResponse",
         "StandardListViewItem",
+        "TreeViewItem",
         "Rgb8Pixel",
         "Rgba8Pixel",
     ];
@@ -320,11 +328,13 @@ fn gen_corelib(
         "slint_property_listener_scope_evaluate",
         "slint_property_listener_scope_is_dirty",
         "PropertyTrackerOpaque",
+        "ChangeTrackerOpaque",
         "CallbackOpaque",
         "WindowAdapterRc",
         "VoidArg",
         "KeyEventArg",
         "PointerEventArg",
+        "TouchEventArg",
         "PointArg",
         "Point",
         "slint_color_brighter",
@@ -598,7 +608,10 @@ fn gen_corelib(
     config.export.body.insert(
         "EasingCurve".to_owned(),
         "    constexpr EasingCurve() : tag(Tag::Linear), cubic_bezier{{0,0,1,1}} {}
-    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    constexpr explicit EasingCurve(EasingCurve::Tag tag) : tag(tag), cubic_bezier{{0,0,1,1}} {}
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, uint32_t count, bool jump_start) : tag(tag), steps{count, jump_start} {}
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, float mass, float stiffness, float damping) : tag(tag), spring{mass, stiffness, damping} {}".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -617,6 +630,14 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config.export.body.insert(
+        "MultiTouchArea".to_owned(),
+        "    inline MultiTouchArea(); inline ~MultiTouchArea();".into(),
+    );
+    config
+        .export
+        .pre_body
+        .insert("MultiTouchAreaDataBox".to_owned(), "struct MultiTouchAreaData;".into());
 
     cbindgen::Builder::new()
         .with_config(config)
@@ -642,7 +663,7 @@ fn gen_corelib(
     namespace cbindgen_private {
         using slint::private_api::WindowAdapterRc;
         using namespace vtable;
-        struct KeyEvent; struct PointerEvent;
+        struct KeyEvent; struct PointerEvent; struct TouchEvent;
         using private_api::Property;
         using private_api::PathData;
         using private_api::Point;
@@ -759,6 +780,28 @@ fn gen_platform(
     Ok(())
 }
 
+fn gen_testing(
+    root_dir: &Path,
+    include_dir: &Path,
+    dependencies: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let config = default_config();
+    let mut crate_dir = root_dir.to_owned();
+    crate_dir.extend(["internal", "backends", "testing"].iter());
+
+    ensure_cargo_rerun_for_crate(&crate_dir, dependencies)?;
+
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(crate_dir.join("search_api.rs"))
+        .with_include("slint_internal.h")
+        .generate()
+        .context("Unable to generate bindings for slint_testing_internal.h")?
+        .write_to_file(include_dir.join("slint_testing_internal.h"));
+
+    Ok(())
+}
+
 fn gen_interpreter(
     root_dir: &Path,
     include_dir: &Path,
@@ -878,6 +921,7 @@ pub fn gen_all(
     gen_corelib(root_dir, include_dir, &mut deps, enabled_features)?;
     gen_backend_qt(root_dir, include_dir, &mut deps)?;
     gen_platform(root_dir, include_dir, &mut deps)?;
+    gen_testing(root_dir, include_dir, &mut deps)?;
     if enabled_features.interpreter {
         gen_interpreter(root_dir, include_dir, &mut deps)?;
     }
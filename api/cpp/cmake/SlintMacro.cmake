@@ -19,6 +19,7 @@ function(SLINT_TARGET_SOURCES target)
         set(t_prop "$<TARGET_PROPERTY:${target},SLINT_EMBED_RESOURCES>")
         set(global_fallback "${DEFAULT_SLINT_EMBED_RESOURCES}")
         set(embed "$<IF:$<STREQUAL:${t_prop},>,${global_fallback},${t_prop}>")
+        set(bundle "$<TARGET_PROPERTY:${target},SLINT_BUNDLE_TRANSLATIONS>")
 
         add_custom_command(
             OUTPUT ${CMAKE_CURRENT_BINARY_DIR}/${_SLINT_BASE_NAME}.h
@@ -28,6 +29,7 @@ function(SLINT_TARGET_SOURCES target)
                 --style ${_SLINT_STYLE}
                 --embed-resources=${embed}
                 --translation-domain="${target}"
+                $<$<BOOL:${bundle}>:--bundle-translations=${bundle}>
             DEPENDS Slint::slint-compiler ${_SLINT_ABSOLUTE}
             COMMENT "Generating ${_SLINT_BASE_NAME}.h"
             DEPFILE ${CMAKE_CURRENT_BINARY_DIR}/${_SLINT_BASE_NAME}.d
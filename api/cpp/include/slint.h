@@ -59,6 +59,7 @@ using ItemArray = slint::cbindgen_private::Slice<ItemArrayEntry>;
 using cbindgen_private::KeyboardModifiers;
 using cbindgen_private::KeyEvent;
 using cbindgen_private::PointerEvent;
+using cbindgen_private::TouchEvent;
 using cbindgen_private::TableColumn;
 
 constexpr inline ItemTreeNode make_item_node(uint32_t child_count, uint32_t child_index,
@@ -319,6 +320,39 @@ box_layout_info_ortho(cbindgen_private::Slice<cbindgen_private::BoxLayoutCellDat
     return cbindgen_private::slint_box_layout_info_ortho(cells, &padding);
 }
 
+inline SharedVector<float> solve_flexbox_layout(const cbindgen_private::FlexboxLayoutData &data,
+                                                cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flexbox_layout(&data, ri, &result);
+    return result;
+}
+
+inline SharedVector<float>
+solve_flexbox_layout_cross(const cbindgen_private::FlexboxLayoutData &data,
+                           cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flexbox_layout_cross(&data, ri, &result);
+    return result;
+}
+
+inline cbindgen_private::LayoutInfo
+flexbox_layout_info(const cbindgen_private::FlexboxLayoutData &data)
+{
+    return cbindgen_private::slint_flexbox_layout_info(&data);
+}
+
+inline cbindgen_private::LayoutInfo
+flexbox_layout_info_cross(const cbindgen_private::FlexboxLayoutData &data)
+{
+    return cbindgen_private::slint_flexbox_layout_info_cross(&data);
+}
+
 /// Access the layout cache of an item within a repeater
 inline float layout_cache_access(const SharedVector<float> &cache, int offset, int repeater_index)
 {
@@ -1247,6 +1281,43 @@ inline SharedString translate(const SharedString &original, const SharedString &
     return result;
 }
 
+inline cbindgen_private::Slice<const char *> to_c_strings(std::span<const char8_t *const> strings)
+{
+    return { reinterpret_cast<const char *const *>(strings.data()), strings.size() };
+}
+
+/// Translate a string with the translations bundled in the generated code. `translations`
+/// contains the translation in each of the `languages`, or nullptr if it's not translated.
+inline SharedString translate_from_bundle(std::span<const char8_t *const> languages,
+                                          const char8_t *original,
+                                          std::span<const char8_t *const> translations,
+                                          cbindgen_private::Slice<SharedString> arguments)
+{
+    SharedString result;
+    cbindgen_private::slint_translate_from_bundle(to_c_strings(languages),
+                                                  reinterpret_cast<const char *>(original),
+                                                  to_c_strings(translations), arguments, &result);
+    return result;
+}
+
+/// Same as translate_from_bundle() for a string with a plural form. `translations` contains the
+/// plural forms of each of the `languages`, each language being terminated by a nullptr.
+inline SharedString
+translate_from_bundle_with_plural(std::span<const char8_t *const> languages,
+                                  std::span<uintptr_t (*const)(int32_t)> plural_rules,
+                                  const char8_t *original, const char8_t *original_plural,
+                                  std::span<const char8_t *const> translations,
+                                  cbindgen_private::Slice<SharedString> arguments, int n)
+{
+    SharedString result;
+    cbindgen_private::slint_translate_from_bundle_with_plural(
+            to_c_strings(languages), { plural_rules.data(), plural_rules.size() },
+            reinterpret_cast<const char *>(original),
+            reinterpret_cast<const char *>(original_plural), to_c_strings(translations),
+            arguments, n, &result);
+    return result;
+}
+
 } // namespace private_api
 
 #if !defined(DOXYGEN)
@@ -1259,6 +1330,15 @@ cbindgen_private::Flickable::~Flickable()
     slint_flickable_data_free(&data);
 }
 
+cbindgen_private::MultiTouchArea::MultiTouchArea()
+{
+    slint_multi_touch_area_data_init(&data);
+}
+cbindgen_private::MultiTouchArea::~MultiTouchArea()
+{
+    slint_multi_touch_area_data_free(&data);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics(void *)
 {
     slint_native_style_metrics_init(this);
@@ -1296,6 +1376,20 @@ inline void quit_event_loop()
     cbindgen_private::slint_quit_event_loop();
 }
 
+/// Selects the language used to translate the strings marked with `@tr(...)` in .slint files.
+///
+/// All the properties whose bindings use `@tr(...)` are marked as dirty and are re-evaluated
+/// with the new language, so this can be used to switch the language of a running application.
+/// The language is a code such as `"de"` or `"pt_BR"`. An empty string selects the default
+/// language of the system again.
+///
+/// This function must be called from the thread that runs the event loop.
+inline void select_language(const SharedString &language)
+{
+    private_api::assert_main_thread();
+    cbindgen_private::slint_select_language(&language);
+}
+
 /// Adds the specified functor to an internal queue, notifies the event loop to wake up.
 /// Once woken up, any queued up functors will be invoked.
 /// This function is thread-safe and can be called from any thread, including the one
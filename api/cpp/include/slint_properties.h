@@ -4,6 +4,8 @@
 #pragma once
 #include <string_view>
 #include <memory>
+#include <optional>
+#include <type_traits>
 
 namespace slint::cbindgen_private {
 struct PropertyAnimation;
@@ -293,4 +295,55 @@ private:
     cbindgen_private::PropertyTrackerOpaque inner;
 };
 
+/// ChangeTracker calls a function when the value computed by another function changes.
+/// This is used to implement the `changed <property> => { ... }` handlers.
+///
+/// The value is re-computed when one of the properties it depends on has changed, the next time
+/// slint::platform::update_timers_and_animations() is called.
+struct ChangeTracker
+{
+    /// Constructs a new change tracker that doesn't track anything until init() is called.
+    ChangeTracker() { cbindgen_private::slint_change_tracker_construct(&inner); }
+    /// Destroys the change tracker.
+    ~ChangeTracker() { cbindgen_private::slint_change_tracker_drop(&inner); }
+    /// The copy constructor is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker(const ChangeTracker &) = delete;
+    /// The assignment operator is intentionally deleted, change trackers cannot be copied.
+    ChangeTracker &operator=(const ChangeTracker &) = delete;
+
+    /// Starts tracking the value returned by \a fn_eval (called with \a data), and calls
+    /// \a fn_notify with \a data and the new value when it changes.
+    /// \a fn_eval is called immediately to compute the initial value.
+    template<typename Data, typename FnEval, typename FnNotify>
+    void init(Data data, FnEval fn_eval, FnNotify fn_notify) const
+    {
+        using Value = std::invoke_result_t<FnEval, const Data &>;
+        struct Inner
+        {
+            Data data;
+            FnEval fn_eval;
+            FnNotify fn_notify;
+            std::optional<Value> value;
+        };
+        cbindgen_private::slint_change_tracker_init(
+                &inner,
+                new Inner { std::move(data), std::move(fn_eval), std::move(fn_notify), {} },
+                [](void *user_data) { delete reinterpret_cast<Inner *>(user_data); },
+                [](void *user_data) {
+                    auto inner = reinterpret_cast<Inner *>(user_data);
+                    auto new_value = inner->fn_eval(inner->data);
+                    bool changed = inner->value && !(*inner->value == new_value);
+                    inner->value = std::move(new_value);
+                    return changed;
+                },
+                [](void *user_data) {
+                    auto inner = reinterpret_cast<Inner *>(user_data);
+                    inner->fn_notify(inner->data, *inner->value);
+                });
+    }
+
+private:
+    cbindgen_private::ChangeTrackerOpaque inner;
+};
+
 } // namespace slint::private_api
@@ -3,8 +3,12 @@
 
 #pragma once
 #include "slint.h"
+#include "slint_testing_internal.h"
 #include <concepts>
 #include <iostream>
+#include <optional>
+#include <string_view>
+#include <vector>
 
 namespace slint::testing {
 
@@ -38,6 +42,172 @@ inline void send_keyboard_string_sequence(const Component *component,
     cbindgen_private::send_keyboard_string_sequence(&str, &component->window().window_handle());
 }
 
+/// The role of an accessible element, as set with the `accessible-role` property.
+using AccessibleRole = cbindgen_private::AccessibleRole;
+
+/// A handle to an element of a component, used to inspect it and interact with it in tests.
+///
+/// The handle doesn't keep the element alive: when the element is destroyed, for example
+/// because it was in a `for` or `if` that got removed, the handle becomes invalid. The
+/// functions then return an empty optional or do nothing.
+class ElementHandle
+{
+    cbindgen_private::ItemWeak inner;
+
+    explicit ElementHandle(const cbindgen_private::ItemWeak &inner) : inner(inner) { }
+
+    template<typename Component, typename FindFn>
+    static std::vector<ElementHandle> find(const Component *component, FindFn find_fn)
+    {
+        auto crc = *component->self_weak.into_dyn().lock();
+        SharedVector<cbindgen_private::ItemWeak> items;
+        find_fn(&crc, &items);
+        std::vector<ElementHandle> result;
+        for (const auto &item : items) {
+            result.push_back(ElementHandle(item));
+        }
+        return result;
+    }
+
+    std::optional<SharedString>
+    accessible_string_property(cbindgen_private::AccessibleStringProperty what) const
+    {
+        SharedString result;
+        if (cbindgen_private::slint_testing_element_accessible_string_property(&inner, what,
+                                                                               &result)) {
+            return result;
+        }
+        return std::nullopt;
+    }
+
+public:
+    /// Returns the elements of the component that have the given id.
+    ///
+    /// The id can be qualified with the name of the component it's declared in
+    /// (`MyComponent::my-element`), or not (`my-element`). The root element of a component has
+    /// the id `root`.
+    ///
+    /// The ids are only available when the component was compiled with the debug info: set the
+    /// `SLINT_EMIT_DEBUG_INFO` environment variable when compiling the `.slint` files.
+    template<typename Component>
+    static std::vector<ElementHandle> find_by_element_id(const Component *component,
+                                                         std::string_view id)
+    {
+        SharedString id_str(id);
+        return find(component, [&](auto root, auto out) {
+            cbindgen_private::slint_testing_element_find_by_element_id(root, &id_str, out);
+        });
+    }
+
+    /// Returns the accessible elements of the component that have the given `accessible-label`.
+    template<typename Component>
+    static std::vector<ElementHandle> find_by_accessible_label(const Component *component,
+                                                               std::string_view label)
+    {
+        SharedString label_str(label);
+        return find(component, [&](auto root, auto out) {
+            cbindgen_private::slint_testing_element_find_by_accessible_label(root, &label_str, out);
+        });
+    }
+
+    /// Returns the accessible elements of the component that have the given `accessible-role`.
+    template<typename Component>
+    static std::vector<ElementHandle> find_by_accessible_role(const Component *component,
+                                                              AccessibleRole role)
+    {
+        return find(component, [&](auto root, auto out) {
+            cbindgen_private::slint_testing_element_find_by_accessible_role(root, role, out);
+        });
+    }
+
+    /// Returns true if the element still exists.
+    bool is_valid() const { return inner.component.lock().has_value(); }
+
+    /// Returns the `Component::id` of the elements this element was created from.
+    /// For example an element declared as `ok := Button {}` in `MyComponent` has the ids
+    /// `MyComponent::ok` and `Button::root`.
+    std::optional<std::vector<SharedString>> element_ids() const
+    {
+        SharedVector<SharedString> ids;
+        if (cbindgen_private::slint_testing_element_ids(&inner, &ids)) {
+            return std::vector<SharedString>(ids.begin(), ids.end());
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the `accessible-role` of the element, if it's accessible.
+    std::optional<AccessibleRole> accessible_role() const
+    {
+        AccessibleRole role;
+        if (cbindgen_private::slint_testing_element_accessible_role(&inner, &role)) {
+            return role;
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the `accessible-label` of the element, if it's accessible.
+    std::optional<SharedString> accessible_label() const
+    {
+        return accessible_string_property(cbindgen_private::AccessibleStringProperty::Label);
+    }
+
+    /// Returns the `accessible-description` of the element, if it's accessible.
+    std::optional<SharedString> accessible_description() const
+    {
+        return accessible_string_property(cbindgen_private::AccessibleStringProperty::Description);
+    }
+
+    /// Returns the `accessible-value` of the element, if it's accessible.
+    std::optional<SharedString> accessible_value() const
+    {
+        return accessible_string_property(cbindgen_private::AccessibleStringProperty::Value);
+    }
+
+    /// Returns the `accessible-checked` of the element, if it's accessible and checkable.
+    std::optional<bool> accessible_checked() const
+    {
+        auto checkable =
+                accessible_string_property(cbindgen_private::AccessibleStringProperty::Checkable);
+        if (!checkable || *checkable != SharedString("true")) {
+            return std::nullopt;
+        }
+        auto checked =
+                accessible_string_property(cbindgen_private::AccessibleStringProperty::Checked);
+        return checked && *checked == SharedString("true");
+    }
+
+    /// Returns the size of the element.
+    std::optional<LogicalSize> size() const
+    {
+        cbindgen_private::Rect geometry;
+        if (cbindgen_private::slint_testing_element_absolute_geometry(&inner, &geometry)) {
+            return LogicalSize({ geometry.width, geometry.height });
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the position of the element, relative to the window.
+    std::optional<LogicalPosition> absolute_position() const
+    {
+        cbindgen_private::Rect geometry;
+        if (cbindgen_private::slint_testing_element_absolute_geometry(&inner, &geometry)) {
+            return LogicalPosition({ geometry.x, geometry.y });
+        }
+        return std::nullopt;
+    }
+
+    /// Simulates a click with the left mouse button in the middle of the element.
+    void single_click() const { cbindgen_private::slint_testing_element_single_click(&inner); }
+
+    /// Clicks in the middle of the element, to give it the focus, and then simulates
+    /// typing the `text`, key by key.
+    void type_text(std::string_view text) const
+    {
+        SharedString text_str(text);
+        cbindgen_private::slint_testing_element_type_text(&inner, &text_str);
+    }
+};
+
 #define assert_eq(A, B)                                                                            \
     slint::testing::private_api::assert_eq_impl(A, B, #A, #B, __FILE__, __LINE__)
 
@@ -104,15 +104,21 @@ public:
     }
 
     template<typename Component, typename Parent>
-    void show_popup(const Parent *parent_component, cbindgen_private::Point p, bool close_on_click,
-                    cbindgen_private::ItemRc parent_item) const
+    uint32_t show_popup(const Parent *parent_component, cbindgen_private::Point p,
+                        bool close_on_click, cbindgen_private::PopupPlacement placement,
+                        cbindgen_private::ItemRc parent_item) const
     {
         auto popup = Component::create(parent_component).into_dyn();
-        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, close_on_click,
-                                                    &parent_item);
+        return cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, close_on_click,
+                                                           placement, &parent_item);
     }
 
-    void close_popup() const { cbindgen_private::slint_windowrc_close_popup(&inner); }
+    void close_popup(uint32_t popup_id) const
+    {
+        if (popup_id > 0) {
+            cbindgen_private::slint_windowrc_close_popup(&inner, popup_id);
+        }
+    }
 
     template<std::invocable<RenderingState, GraphicsAPI> F>
     std::optional<SetRenderingNotifierError> set_rendering_notifier(F callback) const
@@ -427,6 +433,62 @@ public:
         inner.dispatch_pointer_event(event);
     }
 
+    /// Dispatches a touch press event to the scene, when a finger touched the screen.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events. The first finger is also dispatched as a left mouse button press, so don't
+    /// dispatch a separate pointer press event for it.
+    ///
+    /// \a id identifies the finger until it is released, and \a pos represents the logical
+    /// position of the finger relative to the window.
+    void dispatch_touch_press_event(uint64_t id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_pressed = WindowEvent::TouchPressed_Body {
+                                    .tag = WindowEvent::Tag::TouchPressed,
+                                    .id = id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches a touch move event to the scene, when the finger \a id moved to the logical
+    /// position \a pos.
+    void dispatch_touch_move_event(uint64_t id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_moved = WindowEvent::TouchMoved_Body {
+                                    .tag = WindowEvent::Tag::TouchMoved,
+                                    .id = id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches a touch release event to the scene, when the finger \a id was lifted from the
+    /// screen at the logical position \a pos.
+    void dispatch_touch_release_event(uint64_t id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_released = WindowEvent::TouchReleased_Body {
+                                    .tag = WindowEvent::Tag::TouchReleased,
+                                    .id = id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches a touch cancel event to the scene, when the windowing system cancelled the
+    /// touch of the finger \a id.
+    void dispatch_touch_cancel_event(uint64_t id)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_cancelled = WindowEvent::TouchCancelled_Body {
+                                    .tag = WindowEvent::Tag::TouchCancelled, .id = id } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
     /// Set the logical size of this window after a resize event
     ///
     /// The backend must send this event to ensure that the `width` and `height` property of the
@@ -109,6 +109,33 @@ pub fn with_style(self, style: String) -> Self {
         Self { config }
     }
 
+    /// Create a new configuration that bundles the translations in the generated code.
+    ///
+    /// The `.po` files are read at compile time from the `path` directory, and the translated
+    /// strings are embedded in the binary, so gettext is not needed at run-time.
+    /// The files must follow the gettext layout: `<path>/<language>/LC_MESSAGES/<crate name>.po`.
+    /// A relative path is relative to the directory containing the `Cargo.toml` of the crate.
+    ///
+    /// The language of the system is used by default. Use `slint::select_language()` to change
+    /// it at run-time.
+    #[must_use]
+    pub fn with_bundled_translations(self, path: impl Into<std::path::PathBuf>) -> Self {
+        let mut config = self.config;
+        config.translation_path_bundle = Some(path.into());
+        Self { config }
+    }
+
+    /// Create a new configuration that emits the ids of the elements in the generated code,
+    /// so that the elements can be found by id in tests.
+    ///
+    /// This is also enabled when the `SLINT_EMIT_DEBUG_INFO` environment variable is set.
+    #[must_use]
+    pub fn with_debug_info(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.debug_info = enable;
+        Self { config }
+    }
+
     /// Selects how the resources such as images and font are processed.
     ///
     /// See [`EmbedResourcesKind`]
@@ -299,6 +326,13 @@ pub fn compile_with_config(
 
     let mut compiler_config = config.config;
     compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+    if let Some(translation_path) = compiler_config.translation_path_bundle.as_mut() {
+        if translation_path.is_relative() {
+            *translation_path =
+                Path::new(&env::var_os("CARGO_MANIFEST_DIR").ok_or(CompileError::NotRunViaCargo)?)
+                    .join(&translation_path);
+        }
+    }
 
     let mut rerun_if_changed = String::new();
 
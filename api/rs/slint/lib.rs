@@ -211,8 +211,11 @@ struct MyComponent{ /*...*/ }
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    ConcatModel, FilterModel, FlattenModel, FlattenedTreeModel, FlattenedTreeRow, GroupByModel,
+    LazyModel, LazyPageRequest, MapModel, Model, ModelExt, ModelGroup, ModelNotify, ModelPeer,
+    ModelRc, ModelTracker, ReverseModel, SkipModel, SortModel, StandardListViewItem, TableColumn,
+    TakeModel, TreeModel, TreeModelChangeListener, TreeModelNotify, TreeModelTracker, TreeNode,
+    TreeViewItem, VecModel, VecTreeModel, ZipModel,
 };
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
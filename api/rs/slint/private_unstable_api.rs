@@ -163,6 +163,40 @@ pub fn translate(
     i_slint_core::translations::translate(&origin, &context, &domain, args.as_slice(), n, &plural)
 }
 
+/// Wrapper around i_slint_core::translations::translate_from_bundle for the generated code
+pub fn translate_from_bundle(
+    languages: &[&str],
+    original: &str,
+    translations: &[Option<&str>],
+    args: Slice<SharedString>,
+) -> SharedString {
+    i_slint_core::translations::translate_from_bundle(
+        languages,
+        original,
+        translations,
+        args.as_slice(),
+    )
+}
+
+/// Wrapper around i_slint_core::translations::translate_from_bundle_with_plural for the generated code
+pub fn translate_from_bundle_with_plural(
+    languages: &[&str],
+    plural_rules: &[&dyn Fn(i32) -> usize],
+    original: [&str; 2],
+    translations: &[&[&str]],
+    args: Slice<SharedString>,
+    n: i32,
+) -> SharedString {
+    i_slint_core::translations::translate_from_bundle_with_plural(
+        languages,
+        plural_rules,
+        original,
+        translations,
+        args.as_slice(),
+        n,
+    )
+}
+
 #[cfg(feature = "gettext")]
 pub fn init_translations(domain: &str, dirname: impl Into<std::path::PathBuf>) {
     i_slint_core::translations::gettext_bindtextdomain(domain, dirname.into()).unwrap()
@@ -200,7 +234,9 @@ pub mod re_exports {
         logical_position_to_api, LogicalLength, LogicalPoint, LogicalRect,
     };
     pub use i_slint_core::model::*;
-    pub use i_slint_core::properties::{set_state_binding, Property, PropertyTracker, StateInfo};
+    pub use i_slint_core::properties::{
+        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+    };
     pub use i_slint_core::slice::Slice;
     pub use i_slint_core::window::{
         InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
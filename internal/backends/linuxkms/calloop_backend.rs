@@ -12,6 +12,7 @@
 use i_slint_core::platform::WindowAdapter;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
+use crate::KeyboardConfiguration;
 
 #[cfg(not(any(
     target_family = "windows",
@@ -75,6 +76,7 @@ pub struct Backend {
     >,
     sel_clipboard: RefCell<Option<String>>,
     clipboard: RefCell<Option<String>>,
+    keyboard_configuration: RefCell<KeyboardConfiguration>,
 }
 
 impl Backend {
@@ -91,13 +93,15 @@ pub fn new_with_renderer_by_name(renderer_name: Option<&str>) -> Result<Self, Pl
             Some("skia-opengl") => crate::renderer::skia::SkiaRendererAdapter::new_opengl,
             #[cfg(feature = "renderer-femtovg")]
             Some("femtovg") => crate::renderer::femtovg::FemtoVGRendererAdapter::new,
-            None => crate::renderer::try_skia_then_femtovg,
+            #[cfg(feature = "renderer-software")]
+            Some("software") => crate::renderer::sw::SoftwareRendererAdapter::new,
+            None => crate::renderer::try_skia_then_femtovg_then_software,
             Some(renderer_name) => {
                 eprintln!(
                     "slint linuxkms backend: unrecognized renderer {}, falling back default",
                     renderer_name
                 );
-                crate::renderer::try_skia_then_femtovg
+                crate::renderer::try_skia_then_femtovg_then_software
             }
         };
 
@@ -134,8 +138,19 @@ pub fn new_with_renderer_by_name(renderer_name: Option<&str>) -> Result<Self, Pl
             renderer_factory,
             sel_clipboard: Default::default(),
             clipboard: Default::default(),
+            keyboard_configuration: RefCell::new(KeyboardConfiguration::from_env()),
         })
     }
+
+    /// Returns the keyboard configuration that is used when the event loop is started.
+    pub fn keyboard_configuration(&self) -> KeyboardConfiguration {
+        self.keyboard_configuration.borrow().clone()
+    }
+
+    /// Sets the keyboard layout and key repeat. This must be called before the event loop is started.
+    pub fn set_keyboard_configuration(&self, configuration: KeyboardConfiguration) {
+        *self.keyboard_configuration.borrow_mut() = configuration;
+    }
 }
 
 impl i_slint_core::platform::Platform for Backend {
@@ -182,8 +197,12 @@ fn run_event_loop(&self) -> Result<(), PlatformError> {
         *self.proxy.loop_signal.lock().unwrap() = Some(loop_signal.clone());
         let quit_loop = self.proxy.quit_loop.clone();
 
-        let mouse_position_property =
-            input::LibInputHandler::init(adapter.window(), &event_loop.handle(), &self.seat)?;
+        let mouse_position_property = input::LibInputHandler::init(
+            adapter.window(),
+            &event_loop.handle(),
+            &self.seat,
+            &self.keyboard_configuration.borrow(),
+        )?;
 
         let Some(user_event_receiver) = self.user_event_receiver.borrow_mut().take() else {
             return Err(
@@ -10,16 +10,21 @@
 use std::path::Path;
 use std::pin::Pin;
 use std::rc::Rc;
+use std::time::Duration;
 
 use i_slint_core::api::LogicalPosition;
 use i_slint_core::platform::{PlatformError, PointerEventButton, WindowEvent};
+use i_slint_core::timers::{Timer, TimerMode};
+use i_slint_core::window::WindowInner;
 use i_slint_core::{Property, SharedString};
 use input::LibinputInterface;
 
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
-use input::event::touch::TouchEventPosition;
+use input::event::touch::{TouchEventPosition, TouchEventSlot};
 use xkbcommon::*;
 
+use crate::KeyboardConfiguration;
+
 struct SeatWrap {
     seat: Rc<RefCell<libseat::Seat>>,
     device_for_fd: HashMap<RawFd, libseat::Device>,
@@ -56,9 +61,15 @@ pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
     mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
+    /// The position of the fingers touching the screen, by seat slot
+    touch_pos: HashMap<u32, LogicalPosition>,
     window: &'a i_slint_core::api::Window,
     keystate: xkb::State,
+    repeat_delay: Duration,
+    repeat_interval: Option<Duration>,
+    /// Fires the key presses of the key that is held down
+    repeat_timer: Rc<Timer>,
+    repeating_key: Option<xkb::Keycode>,
 }
 
 impl<'a> LibInputHandler<'a> {
@@ -66,6 +77,7 @@ pub fn init<T>(
         window: &'a i_slint_core::api::Window,
         event_loop_handle: &calloop::LoopHandle<'a, T>,
         seat: &'a Rc<RefCell<libseat::Seat>>,
+        keyboard_configuration: &KeyboardConfiguration,
     ) -> Result<Pin<Rc<Property<Option<LogicalPosition>>>>, PlatformError> {
         let seat_name = seat.borrow_mut().name().to_string();
         let mut libinput = input::Libinput::new_with_udev(SeatWrap {
@@ -75,8 +87,23 @@ pub fn init<T>(
         libinput.udev_assign_seat(&seat_name).unwrap();
 
         let xkb_context = xkb::Context::new(xkb::CONTEXT_NO_FLAGS);
-        let keymap = xkb::Keymap::new_from_names(&xkb_context, "", "", "", "", None, 0)
-            .ok_or_else(|| format!("Error compiling keymap"))?;
+        let keymap = xkb::Keymap::new_from_names(
+            &xkb_context,
+            &keyboard_configuration.rules,
+            &keyboard_configuration.model,
+            &keyboard_configuration.layout,
+            &keyboard_configuration.variant,
+            // An empty string disables the options, while None selects the default ones
+            (!keyboard_configuration.options.is_empty())
+                .then(|| keyboard_configuration.options.clone()),
+            xkb::COMPILE_NO_FLAGS,
+        )
+        .ok_or_else(|| {
+            format!(
+                "Error compiling keymap for layout '{}' and variant '{}'",
+                keyboard_configuration.layout, keyboard_configuration.variant
+            )
+        })?;
         let keystate = xkb::State::new(&keymap);
 
         let mouse_pos_property = Rc::pin(Property::new(None));
@@ -85,9 +112,13 @@ pub fn init<T>(
             libinput,
             token: Default::default(),
             mouse_pos: mouse_pos_property.clone(),
-            last_touch_pos: Default::default(),
+            touch_pos: Default::default(),
             window,
             keystate,
+            repeat_delay: keyboard_configuration.repeat_delay,
+            repeat_interval: keyboard_configuration.repeat_interval(),
+            repeat_timer: Default::default(),
+            repeating_key: None,
         };
 
         event_loop_handle
@@ -98,6 +129,43 @@ pub fn init<T>(
     }
 }
 
+impl<'a> LibInputHandler<'a> {
+    /// Repeats the key presses of `key_code` while it is held down, if that key repeats.
+    /// Only the last pressed key that repeats is repeated, other keys such as modifiers don't stop it.
+    fn start_key_repeat(&mut self, key_code: xkb::Keycode, text: Option<SharedString>) {
+        let (Some(interval), Some(text)) = (self.repeat_interval, text) else { return };
+        if !self.keystate.get_keymap().key_repeats(key_code) {
+            return;
+        }
+
+        self.stop_key_repeat();
+        self.repeating_key = Some(key_code);
+
+        let window_adapter = Rc::downgrade(&WindowInner::from_pub(self.window).window_adapter());
+        let timer = Rc::downgrade(&self.repeat_timer);
+        let mut first_repeat = true;
+        // The timer first fires after the delay, and then at the repeat interval
+        self.repeat_timer.start(TimerMode::Repeated, self.repeat_delay, move || {
+            if first_repeat {
+                first_repeat = false;
+                if let Some(timer) = timer.upgrade() {
+                    timer.set_interval(interval);
+                }
+            }
+            if let Some(window_adapter) = window_adapter.upgrade() {
+                window_adapter
+                    .window()
+                    .dispatch_event(WindowEvent::KeyPressed { text: text.clone() });
+            }
+        });
+    }
+
+    fn stop_key_repeat(&mut self) {
+        self.repeat_timer.stop();
+        self.repeating_key = None;
+    }
+}
+
 impl<'a> calloop::EventSource for LibInputHandler<'a> {
     type Event = i_slint_core::platform::WindowEvent;
     type Metadata = ();
@@ -166,31 +234,45 @@ fn process_events<F>(
                 }
                 input::Event::Touch(touch_event) => {
                     let screen_size = self.window.size();
-                    if let Some(event) = match touch_event {
+                    match touch_event {
                         input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let id = touch_down_event.seat_slot();
+                            let position = LogicalPosition::new(
                                 touch_down_event.x_transformed(screen_size.width as u32) as _,
                                 touch_down_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
-                                button: PointerEventButton::Left,
-                            })
+                            self.touch_pos.insert(id, position);
+                            self.window.dispatch_event(WindowEvent::TouchPressed {
+                                id: id as _,
+                                position,
+                            });
+                        }
+                        input::event::TouchEvent::Up(touch_up_event) => {
+                            let id = touch_up_event.seat_slot();
+                            if let Some(position) = self.touch_pos.remove(&id) {
+                                self.window.dispatch_event(WindowEvent::TouchReleased {
+                                    id: id as _,
+                                    position,
+                                });
+                            }
                         }
-                        input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
-                            button: PointerEventButton::Left,
-                        }),
                         input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let id = touch_motion_event.seat_slot();
+                            let position = LogicalPosition::new(
                                 touch_motion_event.x_transformed(screen_size.width as u32) as _,
                                 touch_motion_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
+                            self.touch_pos.insert(id, position);
+                            self.window
+                                .dispatch_event(WindowEvent::TouchMoved { id: id as _, position });
                         }
-                        _ => None,
-                    } {
-                        self.window.dispatch_event(event);
+                        input::event::TouchEvent::Cancel(..) => {
+                            for (id, _) in self.touch_pos.drain() {
+                                self.window
+                                    .dispatch_event(WindowEvent::TouchCancelled { id: id as _ });
+                            }
+                        }
+                        _ => {}
                     }
                 }
                 input::Event::Keyboard(input::event::KeyboardEvent::Key(key_event)) => {
@@ -234,6 +316,14 @@ fn process_events<F>(
                         }
                     }
 
+                    match state {
+                        KeyState::Pressed => self.start_key_repeat(key_code, map_key_sym(sym)),
+                        KeyState::Released if self.repeating_key == Some(key_code) => {
+                            self.stop_key_repeat()
+                        }
+                        KeyState::Released => {}
+                    }
+
                     if let Some(text) = map_key_sym(sym) {
                         let event = match state {
                             KeyState::Pressed => WindowEvent::KeyPressed { text },
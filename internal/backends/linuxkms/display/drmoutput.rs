@@ -0,0 +1,187 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use std::cell::Cell;
+use std::os::fd::{AsFd, BorrowedFd};
+use std::sync::Arc;
+
+use crate::DeviceOpener;
+use drm::control::Device;
+use i_slint_core::api::PhysicalSize as PhysicalWindowSize;
+use i_slint_core::platform::PlatformError;
+
+// Wrapped needed because gbm::Device<T> wants T to be sized.
+#[derive(Clone)]
+pub struct SharedFd(Arc<dyn AsFd>);
+impl AsFd for SharedFd {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+impl drm::Device for SharedFd {}
+
+impl drm::control::Device for SharedFd {}
+
+/// A connected display output of a DRM device, with the mode and crtc to drive it.
+pub struct DrmOutput {
+    pub drm_device: SharedFd,
+    connector: drm::control::connector::Info,
+    mode: drm::control::Mode,
+    crtc: drm::control::crtc::Handle,
+    crtc_configured: Cell<bool>,
+    pub size: PhysicalWindowSize,
+}
+
+impl DrmOutput {
+    pub fn new(device_opener: &DeviceOpener) -> Result<Self, PlatformError> {
+        let mut last_err = None;
+        if let Ok(drm_devices) = std::fs::read_dir("/dev/dri/") {
+            for device in drm_devices {
+                if let Ok(device) = device.map_err(|e| format!("Error opening DRM device: {e}")) {
+                    match Self::new_with_path(device_opener, &device.path()) {
+                        Ok(output) => return Ok(output),
+                        Err(e) => last_err = Some(e),
+                    }
+                }
+            }
+        }
+        Err(last_err
+            .unwrap_or_else(|| "Could not find a DRM device with a connected output".into()))
+    }
+
+    fn new_with_path(
+        device_opener: &DeviceOpener,
+        device: &std::path::Path,
+    ) -> Result<Self, PlatformError> {
+        let drm_device = SharedFd(device_opener(device)?);
+
+        let resources = drm_device
+            .resource_handles()
+            .map_err(|e| format!("Error reading DRM resource handles: {e}"))?;
+
+        let connector = if let Ok(requested_connector_name) = std::env::var("SLINT_DRM_OUTPUT") {
+            let mut connectors = resources.connectors().iter().filter_map(|handle| {
+                let connector = drm_device.get_connector(*handle, false).ok()?;
+                let name =
+                    format!("{}-{}", connector.interface().as_str(), connector.interface_id());
+                let connected = connector.state() == drm::control::connector::State::Connected;
+                Some((name, connector, connected))
+            });
+
+            if requested_connector_name.eq_ignore_ascii_case("list") {
+                let names_and_status = connectors
+                    .map(|(name, _, connected)| format!("{} (connected: {})", name, connected))
+                    .collect::<Vec<_>>();
+                // Can't return error here because newlines are escaped.
+                panic!("\nDRM Output List Requested:\n{}\n", names_and_status.join("\n"));
+            } else {
+                let (_, connector, connected) =
+                    connectors.find(|(name, _, _)| name == &requested_connector_name).ok_or_else(
+                        || format!("No output with the name '{}' found", requested_connector_name),
+                    )?;
+
+                if !connected {
+                    return Err(format!(
+                        "Requested output '{}' is not connected",
+                        requested_connector_name
+                    )
+                    .into());
+                };
+
+                connector
+            }
+        } else {
+            resources
+                .connectors()
+                .iter()
+                .find_map(|handle| {
+                    let connector = drm_device.get_connector(*handle, false).ok()?;
+                    (connector.state() == drm::control::connector::State::Connected)
+                        .then(|| connector)
+                })
+                .ok_or_else(|| format!("No connected display connector found"))?
+        };
+
+        let mode = *connector
+            .modes()
+            .iter()
+            .max_by(|current_mode, next_mode| {
+                let current = (
+                    current_mode.mode_type().contains(drm::control::ModeTypeFlags::PREFERRED),
+                    current_mode.size().0 as u32 * current_mode.size().1 as u32,
+                );
+                let next = (
+                    next_mode.mode_type().contains(drm::control::ModeTypeFlags::PREFERRED),
+                    next_mode.size().0 as u32 * next_mode.size().1 as u32,
+                );
+
+                current.cmp(&next)
+            })
+            .ok_or_else(|| format!("No preferred or non-zero size display mode found"))?;
+
+        let encoder = connector
+            .encoders()
+            .iter()
+            .find_map(|handle| {
+                if connector.current_encoder() == Some(*handle) {
+                    drm_device.get_encoder(*handle).ok()
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| format!("Not encoder found for connector"))?;
+
+        let crtc = encoder.crtc().ok_or_else(|| format!("no crtc for encoder"))?;
+
+        let (width, height) = mode.size();
+        if width == 0 || height == 0 {
+            return Err(format!("Invalid mode screen size {width}x{height}").into());
+        }
+
+        Ok(Self {
+            drm_device,
+            connector,
+            mode,
+            crtc,
+            crtc_configured: Cell::new(false),
+            size: PhysicalWindowSize::new(width as u32, height as u32),
+        })
+    }
+
+    /// Shows the given framebuffer on the output. The first call configures the crtc, later calls
+    /// schedule a page flip and block until it happened, after which the previously presented
+    /// framebuffer may be re-used.
+    pub fn present(
+        &self,
+        framebuffer: drm::control::framebuffer::Handle,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if self.crtc_configured.replace(true) {
+            self.drm_device
+                .page_flip(self.crtc, framebuffer, drm::control::PageFlipFlags::EVENT, None)
+                .map_err(|e| format!("Error presenting fb: {e}"))?;
+
+            for event in self
+                .drm_device
+                .receive_events()
+                .map_err(|e| format!("Error waiting for page flip: {e}"))?
+            {
+                if matches!(event, drm::control::Event::PageFlip(..)) {
+                    break;
+                }
+            }
+        } else {
+            self.drm_device
+                .set_crtc(
+                    self.crtc,
+                    Some(framebuffer),
+                    (0, 0),
+                    &[self.connector.handle()],
+                    Some(self.mode),
+                )
+                .map_err(|e| format!("Error presenting fb: {e}"))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use std::cell::RefCell;
+
+use crate::display::drmoutput::{DrmOutput, SharedFd};
+use crate::DeviceOpener;
+use drm::buffer::Buffer;
+use drm::control::Device;
+use i_slint_core::api::PhysicalSize as PhysicalWindowSize;
+use i_slint_core::platform::PlatformError;
+
+/// A display that is driven by two CPU accessible DRM dumb buffers: The software renderer draws
+/// into the back buffer, which is then page flipped to become the front buffer.
+pub struct SoftwareBufferDisplay {
+    front_buffer: RefCell<DumbBuffer>,
+    back_buffer: RefCell<DumbBuffer>,
+    drm_output: DrmOutput,
+    pub size: PhysicalWindowSize,
+}
+
+impl SoftwareBufferDisplay {
+    pub fn new(device_opener: &DeviceOpener) -> Result<Self, PlatformError> {
+        let drm_output = DrmOutput::new(device_opener)?;
+        let size = drm_output.size;
+
+        let front_buffer = DumbBuffer::allocate(&drm_output.drm_device, size)?;
+        let back_buffer = DumbBuffer::allocate(&drm_output.drm_device, size)?;
+
+        Ok(Self {
+            front_buffer: RefCell::new(front_buffer),
+            back_buffer: RefCell::new(back_buffer),
+            drm_output,
+            size,
+        })
+    }
+
+    /// Maps the back buffer into memory and calls the callback with its pixels and the
+    /// number of bytes per line.
+    pub fn map_back_buffer(
+        &self,
+        callback: &mut dyn FnMut(&mut [u8], usize),
+    ) -> Result<(), PlatformError> {
+        let mut back_buffer = self.back_buffer.borrow_mut();
+        let pitch = back_buffer.pitch() as usize;
+        let buffer = back_buffer.buffer.as_mut().unwrap();
+        let mut mapping = self
+            .drm_output
+            .drm_device
+            .map_dumb_buffer(buffer)
+            .map_err(|e| format!("Error mapping dumb buffer into memory: {e}"))?;
+        callback(mapping.as_mut(), pitch);
+        Ok(())
+    }
+}
+
+impl super::Presenter for SoftwareBufferDisplay {
+    fn present(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.drm_output.present(self.back_buffer.borrow().fb_handle)?;
+        // After the page flip, the previous front buffer is not scanned out anymore and becomes
+        // the buffer to render the next frame into.
+        self.front_buffer.swap(&self.back_buffer);
+        Ok(())
+    }
+}
+
+struct DumbBuffer {
+    fb_handle: drm::control::framebuffer::Handle,
+    // Only None while being destroyed
+    buffer: Option<drm::control::dumbbuffer::DumbBuffer>,
+    device: SharedFd,
+}
+
+impl DumbBuffer {
+    fn allocate(device: &SharedFd, size: PhysicalWindowSize) -> Result<Self, PlatformError> {
+        let buffer = device
+            .create_dumb_buffer((size.width, size.height), drm::buffer::DrmFourcc::Xrgb8888, 32)
+            .map_err(|e| {
+                format!("Error creating dumb buffer ({}x{}): {e}", size.width, size.height)
+            })?;
+
+        let fb_handle = match device.add_framebuffer(&buffer, 24, 32) {
+            Ok(fb_handle) => fb_handle,
+            Err(e) => {
+                device.destroy_dumb_buffer(buffer).ok();
+                return Err(format!("Error creating framebuffer for dumb buffer: {e}").into());
+            }
+        };
+
+        Ok(Self { fb_handle, buffer: Some(buffer), device: device.clone() })
+    }
+
+    fn pitch(&self) -> u32 {
+        self.buffer.as_ref().map_or(0, |buffer| buffer.pitch())
+    }
+}
+
+impl Drop for DumbBuffer {
+    fn drop(&mut self) {
+        self.device.destroy_framebuffer(self.fb_handle).ok();
+        if let Some(buffer) = self.buffer.take() {
+            self.device.destroy_dumb_buffer(buffer).ok();
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use std::time::Duration;
+
+/// The configuration of the keyboard: its xkb keymap and the key repeat.
+///
+/// The keymap names are passed to xkbcommon. Empty names select xkbcommon's defaults, which
+/// can be set with the `XKB_DEFAULT_RULES`, `XKB_DEFAULT_MODEL`, `XKB_DEFAULT_LAYOUT`,
+/// `XKB_DEFAULT_VARIANT` and `XKB_DEFAULT_OPTIONS` environment variables, and otherwise
+/// describe a US keyboard.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct KeyboardConfiguration {
+    /// The xkb rules file, for example `evdev`
+    pub rules: String,
+    /// The keyboard model, for example `pc105`
+    pub model: String,
+    /// A comma separated list of layouts, for example `de` or `fr,us`
+    pub layout: String,
+    /// A comma separated list of variants, one per layout, for example `nodeadkeys`
+    pub variant: String,
+    /// A comma separated list of options, for example `grp:alt_shift_toggle`
+    pub options: String,
+    /// The time a key must be held down before it starts repeating
+    pub repeat_delay: Duration,
+    /// The number of times per second a held down key is repeated. Zero disables the key repeat.
+    pub repeat_rate: u32,
+}
+
+impl Default for KeyboardConfiguration {
+    fn default() -> Self {
+        Self {
+            rules: Default::default(),
+            model: Default::default(),
+            layout: Default::default(),
+            variant: Default::default(),
+            options: Default::default(),
+            repeat_delay: Duration::from_millis(600),
+            repeat_rate: 25,
+        }
+    }
+}
+
+impl KeyboardConfiguration {
+    /// The default configuration, with the key repeat set with the `SLINT_KEYBOARD_REPEAT_DELAY`
+    /// (in milliseconds) and `SLINT_KEYBOARD_REPEAT_RATE` (per second) environment variables.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    pub(crate) fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(delay) =
+            std::env::var("SLINT_KEYBOARD_REPEAT_DELAY").ok().and_then(|delay| delay.parse().ok())
+        {
+            config.repeat_delay = Duration::from_millis(delay);
+        }
+        if let Some(rate) =
+            std::env::var("SLINT_KEYBOARD_REPEAT_RATE").ok().and_then(|rate| rate.parse().ok())
+        {
+            config.repeat_rate = rate;
+        }
+        config
+    }
+
+    /// The interval between two repetitions of a held down key, or None if keys don't repeat.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    pub(crate) fn repeat_interval(&self) -> Option<Duration> {
+        (self.repeat_rate > 0).then(|| Duration::from_secs(1) / self.repeat_rate)
+    }
+}
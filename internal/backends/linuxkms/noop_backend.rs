@@ -1,6 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
+use crate::KeyboardConfiguration;
 use i_slint_core::platform::PlatformError;
 pub struct Backend {}
 
@@ -11,6 +12,10 @@ pub fn new() -> Result<Self, PlatformError> {
     pub fn new_with_renderer_by_name(_renderer_name: Option<&str>) -> Result<Self, PlatformError> {
         Ok(Backend {})
     }
+    pub fn keyboard_configuration(&self) -> KeyboardConfiguration {
+        Default::default()
+    }
+    pub fn set_keyboard_configuration(&self, _configuration: KeyboardConfiguration) {}
 }
 
 impl i_slint_core::platform::Platform for Backend {
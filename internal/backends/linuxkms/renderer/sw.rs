@@ -0,0 +1,67 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Delegate the rendering to the [`i_slint_core::software_renderer::SoftwareRenderer`]
+
+use i_slint_core::api::PhysicalSize as PhysicalWindowSize;
+use i_slint_core::item_rendering::ItemRenderer;
+use i_slint_core::platform::PlatformError;
+use i_slint_core::software_renderer::{RepaintBufferType, SoftwareRenderer, Xrgb8888Pixel};
+
+use crate::display::{swdisplay::SoftwareBufferDisplay, Presenter};
+
+pub struct SoftwareRendererAdapter {
+    renderer: SoftwareRenderer,
+    display: SoftwareBufferDisplay,
+    size: PhysicalWindowSize,
+}
+
+impl SoftwareRendererAdapter {
+    pub fn new(
+        device_opener: &crate::DeviceOpener,
+    ) -> Result<Box<dyn crate::fullscreenwindowadapter::FullscreenRenderer>, PlatformError> {
+        let display = SoftwareBufferDisplay::new(device_opener)?;
+
+        let size = display.size;
+
+        // The display alternates between two buffers, so only the regions that changed in
+        // the last two frames need to be redrawn.
+        let renderer = Box::new(Self {
+            renderer: SoftwareRenderer::new_with_repaint_buffer_type(
+                RepaintBufferType::SwappedBuffers,
+            ),
+            display,
+            size,
+        });
+
+        eprintln!("Using Software renderer");
+
+        Ok(renderer)
+    }
+}
+
+impl crate::fullscreenwindowadapter::FullscreenRenderer for SoftwareRendererAdapter {
+    fn as_core_renderer(&self) -> &dyn i_slint_core::renderer::Renderer {
+        &self.renderer
+    }
+
+    fn render_and_present(
+        &self,
+        draw_mouse_cursor_callback: &dyn Fn(&mut dyn ItemRenderer),
+    ) -> Result<(), PlatformError> {
+        self.display.map_back_buffer(&mut |pixels, pitch| {
+            let buffer: &mut [Xrgb8888Pixel] = bytemuck::cast_slice_mut(pixels);
+            self.renderer.render_with_post_callback(
+                buffer,
+                pitch / std::mem::size_of::<Xrgb8888Pixel>(),
+                draw_mouse_cursor_callback,
+            );
+        })?;
+        self.display.present()?;
+        Ok(())
+    }
+
+    fn size(&self) -> i_slint_core::api::PhysicalSize {
+        self.size
+    }
+}
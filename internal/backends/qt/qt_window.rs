@@ -158,7 +158,7 @@ struct SlintWidget : QWidget {
             });
             if (parent_of_popup_to_close) {
                 rust!(Slint_mouseReleaseEventClosePopup [parent_of_popup_to_close: &QtWindow as "void*"] {
-                    parent_of_popup_to_close.close_popup();
+                    parent_of_popup_to_close.close_top_popup();
                 });
             }
         }
@@ -1080,6 +1080,13 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         }}
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        let painter: &mut QPainterPtr = &mut self.painter;
+        cpp! { unsafe [painter as "QPainterPtr*", x_factor as "float", y_factor as "float"] {
+            (*painter)->scale(x_factor, y_factor);
+        }}
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         let painter: &mut QPainterPtr = &mut self.painter;
         cpp! { unsafe [painter as "QPainterPtr*", opacity as "float"] {
@@ -1491,8 +1498,8 @@ fn key_event(&self, key: i32, text: qttypes::QString, released: bool) {
         timer_event();
     }
 
-    fn close_popup(&self) {
-        WindowInner::from_pub(&self.window).close_popup();
+    fn close_top_popup(&self) {
+        WindowInner::from_pub(&self.window).close_top_popup();
     }
 
     fn close_popup_after_click(&self) -> bool {
@@ -2055,15 +2062,19 @@ fn text_size(&self, text: &str, max_width: Option<f32>) -> LogicalSize {
 pub(crate) fn timer_event() {
     i_slint_core::platform::update_timers_and_animations();
 
-    let timeout = i_slint_core::timers::TimerList::next_timeout().map(|instant| {
-        let now = std::time::Instant::now();
-        let instant: std::time::Instant = instant.into();
-        if instant > now {
-            instant.duration_since(now).as_millis() as i32
-        } else {
-            0
-        }
-    });
+    let timeout = if i_slint_core::properties::ChangeTracker::has_pending_change_handlers() {
+        Some(0)
+    } else {
+        i_slint_core::timers::TimerList::next_timeout().map(|instant| {
+            let now = std::time::Instant::now();
+            let instant: std::time::Instant = instant.into();
+            if instant > now {
+                instant.duration_since(now).as_millis() as i32
+            } else {
+                0
+            }
+        })
+    };
     if let Some(timeout) = timeout {
         cpp! { unsafe [timeout as "int"] {
             ensure_initialized(true);
@@ -16,6 +16,11 @@
 use std::rc::Rc;
 use std::sync::Mutex;
 
+pub use i_slint_core::items::AccessibleRole;
+
+mod search_api;
+pub use search_api::*;
+
 #[derive(Default)]
 pub struct TestingBackend {
     clipboard: Mutex<Option<String>>,
@@ -0,0 +1,328 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! API to find elements in a component and to interact with them, for tests.
+
+use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::api::{ComponentHandle, LogicalPosition, LogicalSize};
+use i_slint_core::item_tree::{ItemRc, ItemWeak};
+use i_slint_core::items::AccessibleRole;
+use i_slint_core::window::WindowAdapterRc;
+use i_slint_core::SharedString;
+
+/// Returns the item and all its descendants, in depth-first order.
+fn descendants(root: ItemRc) -> impl Iterator<Item = ItemRc> {
+    let mut candidates = Vec::new();
+    if let Some(child) = root.first_child() {
+        candidates.push(child);
+    }
+
+    core::iter::once(root).chain(core::iter::from_fn(move || {
+        let item = candidates.pop()?;
+        if let Some(next) = item.next_sibling() {
+            candidates.push(next);
+        }
+        if let Some(child) = item.first_child() {
+            candidates.push(child);
+        }
+        Some(item)
+    }))
+}
+
+fn root_item<
+    X: vtable::HasStaticVTable<i_slint_core::component::ComponentVTable> + 'static,
+    Component: Into<vtable::VRc<i_slint_core::component::ComponentVTable, X>> + ComponentHandle,
+>(
+    component: &Component,
+) -> ItemRc {
+    let rc = component.clone_strong().into();
+    ItemRc::new(vtable::VRc::into_dyn(rc), 0)
+}
+
+/// Returns true if one of the `Component::id` of the element matches `id`.
+/// `id` can either be qualified with the name of the component (`MyComponent::my-element`)
+/// or just be the id of the element (`my-element`).
+fn matches_element_id(item: &ItemRc, id: &str) -> bool {
+    item.element_infos().map_or(false, |infos| {
+        infos.lines().any(|qualified_id| {
+            qualified_id == id
+                || qualified_id.split_once("::").map_or(false, |(_, local_id)| local_id == id)
+        })
+    })
+}
+
+fn has_accessible_label(item: &ItemRc, label: &str) -> bool {
+    item.is_accessible()
+        && item.accessible_string_property(AccessibleStringProperty::Label) == label
+}
+
+fn has_accessible_role(item: &ItemRc, role: AccessibleRole) -> bool {
+    item.is_accessible() && item.accessible_role() == role
+}
+
+/// A handle to an element of a component, used to inspect it and interact with it in tests.
+///
+/// The handle doesn't keep the element alive: when the element is destroyed, for example
+/// because it was in a `for` or `if` that got removed, the handle becomes invalid. The
+/// functions then return `None` or do nothing.
+#[derive(Clone)]
+pub struct ElementHandle(ItemWeak);
+
+impl ElementHandle {
+    fn collect(
+        root: ItemRc,
+        mut predicate: impl FnMut(&ItemRc) -> bool,
+    ) -> impl Iterator<Item = Self> {
+        descendants(root).filter(move |item| predicate(item)).map(|item| Self(item.downgrade()))
+    }
+
+    /// Returns the elements of the component that have the given id.
+    ///
+    /// The id can be qualified with the name of the component it's declared in
+    /// (`MyComponent::my-element`), or not (`my-element`). The root element of a component has the
+    /// id `root`.
+    ///
+    /// The ids are only available when the component was compiled with the debug info: set the
+    /// `SLINT_EMIT_DEBUG_INFO` environment variable when compiling the `.slint` files, or
+    /// use `slint_build::CompilerConfiguration::with_debug_info()`.
+    pub fn find_by_element_id<
+        X: vtable::HasStaticVTable<i_slint_core::component::ComponentVTable> + 'static,
+        Component: Into<vtable::VRc<i_slint_core::component::ComponentVTable, X>> + ComponentHandle,
+    >(
+        component: &Component,
+        id: &str,
+    ) -> impl Iterator<Item = Self> {
+        let id = id.to_string();
+        Self::collect(root_item(component), move |item| matches_element_id(item, &id))
+    }
+
+    /// Returns the accessible elements of the component that have the given `accessible-label`.
+    pub fn find_by_accessible_label<
+        X: vtable::HasStaticVTable<i_slint_core::component::ComponentVTable> + 'static,
+        Component: Into<vtable::VRc<i_slint_core::component::ComponentVTable, X>> + ComponentHandle,
+    >(
+        component: &Component,
+        label: &str,
+    ) -> impl Iterator<Item = Self> {
+        let label = label.to_string();
+        Self::collect(root_item(component), move |item| has_accessible_label(item, &label))
+    }
+
+    /// Returns the accessible elements of the component that have the given `accessible-role`.
+    pub fn find_by_accessible_role<
+        X: vtable::HasStaticVTable<i_slint_core::component::ComponentVTable> + 'static,
+        Component: Into<vtable::VRc<i_slint_core::component::ComponentVTable, X>> + ComponentHandle,
+    >(
+        component: &Component,
+        role: AccessibleRole,
+    ) -> impl Iterator<Item = Self> {
+        Self::collect(root_item(component), move |item| has_accessible_role(item, role))
+    }
+
+    /// Returns true if the element still exists.
+    pub fn is_valid(&self) -> bool {
+        self.0.upgrade().is_some()
+    }
+
+    /// Returns the `Component::id` of the elements this element was created from.
+    /// For example an element declared as `ok := Button {}` in `MyComponent` has the ids
+    /// `MyComponent::ok` and `Button::root`.
+    pub fn element_ids(&self) -> Option<Vec<SharedString>> {
+        let infos = self.0.upgrade()?.element_infos()?;
+        Some(infos.lines().map(SharedString::from).collect())
+    }
+
+    /// Returns the `accessible-role` of the element, if it's accessible.
+    pub fn accessible_role(&self) -> Option<AccessibleRole> {
+        self.0.upgrade().filter(|item| item.is_accessible()).map(|item| item.accessible_role())
+    }
+
+    fn accessible_string_property(&self, what: AccessibleStringProperty) -> Option<SharedString> {
+        self.0
+            .upgrade()
+            .filter(|item| item.is_accessible())
+            .map(|item| item.accessible_string_property(what))
+    }
+
+    /// Returns the `accessible-label` of the element, if it's accessible.
+    pub fn accessible_label(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Label)
+    }
+
+    /// Returns the `accessible-description` of the element, if it's accessible.
+    pub fn accessible_description(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Description)
+    }
+
+    /// Returns the `accessible-value` of the element, if it's accessible.
+    pub fn accessible_value(&self) -> Option<SharedString> {
+        self.accessible_string_property(AccessibleStringProperty::Value)
+    }
+
+    /// Returns the `accessible-checked` of the element, if it's accessible and checkable.
+    pub fn accessible_checked(&self) -> Option<bool> {
+        if self.accessible_string_property(AccessibleStringProperty::Checkable)? != "true" {
+            return None;
+        }
+        Some(self.accessible_string_property(AccessibleStringProperty::Checked)? == "true")
+    }
+
+    /// Returns the size of the element.
+    pub fn size(&self) -> Option<LogicalSize> {
+        let size = self.0.upgrade()?.geometry().size;
+        Some(LogicalSize::new(size.width, size.height))
+    }
+
+    /// Returns the position of the element, relative to the window.
+    pub fn absolute_position(&self) -> Option<LogicalPosition> {
+        let item = self.0.upgrade()?;
+        let position = item.map_to_window(item.geometry().origin);
+        Some(LogicalPosition::new(position.x, position.y))
+    }
+
+    fn window_adapter(&self) -> Option<WindowAdapterRc> {
+        let item = self.0.upgrade()?;
+        let mut window_adapter = None;
+        vtable::VRc::borrow_pin(item.component())
+            .as_ref()
+            .window_adapter(false, &mut window_adapter);
+        window_adapter
+    }
+
+    /// Simulates a click with the left mouse button in the middle of the element.
+    pub fn single_click(&self) {
+        let (Some(position), Some(size), Some(window_adapter)) =
+            (self.absolute_position(), self.size(), self.window_adapter())
+        else {
+            return;
+        };
+        let x = position.x + size.width / 2.;
+        let y = position.y + size.height / 2.;
+        let component = self.0.upgrade().unwrap().component().clone();
+        i_slint_core::tests::slint_send_mouse_click(&component, x, y, &window_adapter);
+    }
+
+    /// Clicks in the middle of the element, to give it the focus, and then simulates
+    /// typing the `text`, key by key.
+    pub fn type_text(&self, text: &str) {
+        self.single_click();
+        if let Some(window_adapter) = self.window_adapter() {
+            i_slint_core::tests::send_keyboard_string_sequence(
+                &SharedString::from(text),
+                &window_adapter,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "ffi")]
+pub mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use i_slint_core::component::ComponentRc;
+    use i_slint_core::lengths::LogicalRect;
+    use i_slint_core::SharedVector;
+
+    fn find(
+        root: &ComponentRc,
+        out: &mut SharedVector<ItemWeak>,
+        predicate: impl FnMut(&ItemRc) -> bool,
+    ) {
+        out.clear();
+        out.extend(ElementHandle::collect(ItemRc::new(root.clone(), 0), predicate).map(|e| e.0));
+    }
+
+    /// Fills `out` with the elements of the component that have the given id.
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_find_by_element_id(
+        root: &ComponentRc,
+        id: &SharedString,
+        out: &mut SharedVector<ItemWeak>,
+    ) {
+        find(root, out, |item| matches_element_id(item, id))
+    }
+
+    /// Fills `out` with the accessible elements of the component that have the given label.
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_find_by_accessible_label(
+        root: &ComponentRc,
+        label: &SharedString,
+        out: &mut SharedVector<ItemWeak>,
+    ) {
+        find(root, out, |item| has_accessible_label(item, label))
+    }
+
+    /// Fills `out` with the accessible elements of the component that have the given role.
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_find_by_accessible_role(
+        root: &ComponentRc,
+        role: AccessibleRole,
+        out: &mut SharedVector<ItemWeak>,
+    ) {
+        find(root, out, |item| has_accessible_role(item, role))
+    }
+
+    /// Fills `out` with the ids of the element. Returns false if there are none.
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_ids(
+        element: &ItemWeak,
+        out: &mut SharedVector<SharedString>,
+    ) -> bool {
+        match ElementHandle(element.clone()).element_ids() {
+            Some(ids) => {
+                out.clear();
+                out.extend(ids);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets `out` to the accessible role of the element. Returns false if it's not accessible.
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_accessible_role(
+        element: &ItemWeak,
+        out: &mut AccessibleRole,
+    ) -> bool {
+        ElementHandle(element.clone()).accessible_role().map(|role| *out = role).is_some()
+    }
+
+    /// Sets `out` to an accessible property of the element. Returns false if it's not accessible.
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_accessible_string_property(
+        element: &ItemWeak,
+        what: AccessibleStringProperty,
+        out: &mut SharedString,
+    ) -> bool {
+        ElementHandle(element.clone())
+            .accessible_string_property(what)
+            .map(|value| *out = value)
+            .is_some()
+    }
+
+    /// Sets `out` to the geometry of the element, relative to the window.
+    /// Returns false if the element no longer exists.
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_absolute_geometry(
+        element: &ItemWeak,
+        out: &mut LogicalRect,
+    ) -> bool {
+        let element = ElementHandle(element.clone());
+        let (Some(position), Some(size)) = (element.absolute_position(), element.size()) else {
+            return false;
+        };
+        *out = LogicalRect::new((position.x, position.y).into(), (size.width, size.height).into());
+        true
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_single_click(element: &ItemWeak) {
+        ElementHandle(element.clone()).single_click()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_testing_element_type_text(element: &ItemWeak, text: &SharedString) {
+        ElementHandle(element.clone()).type_text(text)
+    }
+}
@@ -414,27 +414,26 @@ fn process_window_event(
                 )
             };
             let location = location.to_logical(runtime_window.scale_factor() as f64);
-            let position = euclid::point2(location.x, location.y);
+            let position = corelib::api::LogicalPosition::new(location.x, location.y);
+            let id = touch.id;
             let ev = match touch.phase {
                 winit::event::TouchPhase::Started => {
                     *pressed = true;
-                    MouseEvent::Pressed {
-                        position,
-                        button: PointerEventButton::Left,
-                        click_count: 0,
-                    }
+                    corelib::platform::WindowEvent::TouchPressed { id, position }
                 }
-                winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                winit::event::TouchPhase::Ended => {
                     *pressed = false;
-                    MouseEvent::Released {
-                        position,
-                        button: PointerEventButton::Left,
-                        click_count: 0,
-                    }
+                    corelib::platform::WindowEvent::TouchReleased { id, position }
+                }
+                winit::event::TouchPhase::Cancelled => {
+                    *pressed = false;
+                    corelib::platform::WindowEvent::TouchCancelled { id }
+                }
+                winit::event::TouchPhase::Moved => {
+                    corelib::platform::WindowEvent::TouchMoved { id, position }
                 }
-                winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
             };
-            runtime_window.process_mouse_input(ev);
+            window.window().dispatch_event(ev);
         }
         WindowEvent::ScaleFactorChanged { scale_factor, new_inner_size } => {
             if std::env::var("SLINT_SCALE_FACTOR").is_err() {
@@ -66,6 +66,25 @@ struct PointerEvent {
                 }
             }
 
+            /// Represents the event of a finger touching the screen.
+            /// This structure is generated and passed to the `touch-event` callback of the `MultiTouchArea` element.
+            struct TouchEvent {
+                @name = "slint::private_api::TouchEvent"
+                export {
+                    /// Identifies the finger. It stays the same while the finger touches the screen.
+                    /// The first finger has the id 0, and each new finger gets the smallest id not used by another finger.
+                    id: i32,
+                    /// The kind of the event
+                    kind: PointerEventKind,
+                    /// The position of the finger, relative to the `MultiTouchArea`
+                    x: Coord,
+                    /// The position of the finger, relative to the `MultiTouchArea`
+                    y: Coord,
+                }
+                private {
+                }
+            }
+
             /// This structure is generated and passed to the key press and release callbacks of the `FocusScope` element.
             struct KeyEvent {
                 @name = "slint::private_api::KeyEvent"
@@ -99,6 +118,24 @@ struct StandardListViewItem {
                 }
             }
 
+            /// Represents a visible row of a TreeView.
+            #[non_exhaustive]
+            struct TreeViewItem {
+                @name = "slint::TreeViewItem"
+                export {
+                    /// The text content of the item
+                    text: SharedString,
+                    /// The depth of the item in the tree. The top level items have the level 0
+                    level: i32,
+                    /// Whether the item has children that can be shown by expanding it
+                    has_children: bool,
+                    /// Whether the children of the item are shown
+                    expanded: bool,
+                }
+                private {
+                }
+            }
+
             /// This is used to define the column and the column header of a TableView
             #[non_exhaustive]
             struct TableColumn {
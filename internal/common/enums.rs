@@ -129,6 +129,8 @@ enum PointerEventKind {
                 Down,
                 /// The button was released.
                 Up,
+                /// The pointer or finger was moved.
+                Move,
             }
 
             /// This enum describes the different types of buttons for a pointer event,
@@ -270,6 +272,30 @@ enum LayoutAlignment {
                 SpaceAround,
             }
 
+            /// This enum describes the direction in which the items of a
+            /// [`FlexboxLayout`](elements.md#flexboxlayout) are placed.
+            enum FlexDirection {
+                /// The items are placed horizontally from left to right, and the lines from top to bottom.
+                Row,
+                /// The items are placed horizontally from right to left, and the lines from top to bottom.
+                RowReverse,
+                /// The items are placed vertically from top to bottom, and the lines from left to right.
+                Column,
+                /// The items are placed vertically from bottom to top, and the lines from left to right.
+                ColumnReverse,
+            }
+
+            /// This enum describes whether the items of a [`FlexboxLayout`](elements.md#flexboxlayout)
+            /// wrap onto several lines.
+            enum FlexWrap {
+                /// The items wrap onto a new line when there is not enough space left in the current line.
+                Wrap,
+                /// All the items are placed in a single line, and are shrunk if needed.
+                NoWrap,
+                /// Like `wrap`, but the lines are placed in the reverse order.
+                WrapReverse,
+            }
+
             /// PathEvent is a low-level data structure describing the composition of a path. Typically it is
             /// generated at compile time from a higher-level description, such as SVG commands.
             enum PathEvent {
@@ -328,6 +354,15 @@ enum Orientation {
                 /// Element is oriented vertically.
                 Vertical,
             }
+
+            /// This enum describes how a [`PopupWindow`](elements.md#popupwindow) is positioned when it's shown.
+            enum PopupPlacement {
+                /// The popup is placed at its `x` and `y` position, even if it doesn't fit in the window.
+                Fixed,
+                /// The popup is placed at its `x` and `y` position if it fits in the window. Otherwise, it's
+                /// flipped to the other side of its parent element, and moved horizontally to stay within the window.
+                Auto,
+            }
         ];
     };
 }
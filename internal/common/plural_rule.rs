@@ -0,0 +1,191 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The plural rules of the gettext translations, shared by the compiler for the bundled
+//! translations and by the runtime for the `.mo` files.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Extract the `plural=...` expression from the `Plural-Forms` field of the header of a `.po` or `.mo` file
+pub fn plural_rule_from_header(header: &str) -> Option<&str> {
+    let forms = header.lines().find_map(|l| l.trim().strip_prefix("Plural-Forms:"))?;
+    let rule = forms.split(';').find_map(|f| f.trim().strip_prefix("plural="))?;
+    Some(rule.trim())
+}
+
+/// The expression that computes the index of the plural form from the number `n`.
+///
+/// This is the subset of the C language used in the `Plural-Forms` header of `.po` files.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluralRule {
+    N,
+    Constant(i64),
+    Not(Box<PluralRule>),
+    /// The operator uses the same characters as the binary expressions of the compiler,
+    /// and also `%` for the remainder
+    Binary(Box<PluralRule>, char, Box<PluralRule>),
+    Condition(Box<PluralRule>, Box<PluralRule>, Box<PluralRule>),
+}
+
+impl PluralRule {
+    /// Parse a plural rule such as `n==1 ? 0 : n%10>=2 && n%10<=4 ? 1 : 2`
+    pub fn parse(rule: &str) -> Result<Self, String> {
+        let mut parser = PluralRuleParser { rest: rule };
+        let result = parser.parse_condition()?;
+        parser.skip_whitespace();
+        if !parser.rest.is_empty() {
+            return Err(format!("unexpected '{}'", parser.rest));
+        }
+        Ok(result)
+    }
+
+    /// Evaluate the rule for the number `n`.
+    /// Like in C, the comparisons and logical operators return 0 or 1.
+    pub fn evaluate(&self, n: i64) -> i64 {
+        match self {
+            PluralRule::N => n,
+            PluralRule::Constant(value) => *value,
+            PluralRule::Not(value) => (value.evaluate(n) == 0) as i64,
+            PluralRule::Binary(lhs, op, rhs) => {
+                let lhs = lhs.evaluate(n);
+                match op {
+                    '&' => (lhs != 0 && rhs.evaluate(n) != 0) as i64,
+                    '|' => (lhs != 0 || rhs.evaluate(n) != 0) as i64,
+                    _ => {
+                        let rhs = rhs.evaluate(n);
+                        match op {
+                            '+' => lhs.wrapping_add(rhs),
+                            '-' => lhs.wrapping_sub(rhs),
+                            '*' => lhs.wrapping_mul(rhs),
+                            '/' => lhs.checked_div(rhs).unwrap_or(0),
+                            '%' => lhs.checked_rem(rhs).unwrap_or(0),
+                            '=' => (lhs == rhs) as i64,
+                            '!' => (lhs != rhs) as i64,
+                            '<' => (lhs < rhs) as i64,
+                            '>' => (lhs > rhs) as i64,
+                            '≤' => (lhs <= rhs) as i64,
+                            '≥' => (lhs >= rhs) as i64,
+                            _ => unreachable!("invalid operator {op}"),
+                        }
+                    }
+                }
+            }
+            PluralRule::Condition(condition, true_value, false_value) => {
+                if condition.evaluate(n) != 0 {
+                    true_value.evaluate(n)
+                } else {
+                    false_value.evaluate(n)
+                }
+            }
+        }
+    }
+}
+
+/// A recursive descent parser for the plural rules, following the precedence of the C operators
+struct PluralRuleParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> PluralRuleParser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// Consume the first of the `tokens` that is at the current position
+    fn eat(&mut self, tokens: &[&'static str]) -> Option<&'static str> {
+        self.skip_whitespace();
+        let token = tokens.iter().find(|t| self.rest.starts_with(**t))?;
+        self.rest = &self.rest[token.len()..];
+        Some(token)
+    }
+
+    fn expect(&mut self, token: &'static str) -> Result<(), String> {
+        self.eat(&[token]).map(|_| ()).ok_or_else(|| format!("expected '{token}'"))
+    }
+
+    fn parse_condition(&mut self) -> Result<PluralRule, String> {
+        let condition = self.parse_binary(0)?;
+        if self.eat(&["?"]).is_none() {
+            return Ok(condition);
+        }
+        let true_value = self.parse_condition()?;
+        self.expect(":")?;
+        let false_value = self.parse_condition()?;
+        Ok(PluralRule::Condition(condition.into(), true_value.into(), false_value.into()))
+    }
+
+    /// Parse the binary operators, from the lowest precedence `level`
+    fn parse_binary(&mut self, level: usize) -> Result<PluralRule, String> {
+        // The operators for each level of precedence, and the corresponding operator character
+        const LEVELS: &[&[(&str, char)]] = &[
+            &[("||", '|')],
+            &[("&&", '&')],
+            &[("==", '='), ("!=", '!')],
+            &[("<=", '≤'), (">=", '≥'), ("<", '<'), (">", '>')],
+            &[("+", '+'), ("-", '-')],
+            &[("*", '*'), ("/", '/'), ("%", '%')],
+        ];
+        let Some(operators) = LEVELS.get(level) else { return self.parse_unary() };
+        let tokens = operators.iter().map(|(t, _)| *t).collect::<Vec<_>>();
+        let mut lhs = self.parse_binary(level + 1)?;
+        while let Some(token) = self.eat(&tokens) {
+            let op = operators.iter().find(|(t, _)| *t == token).unwrap().1;
+            let rhs = self.parse_binary(level + 1)?;
+            lhs = PluralRule::Binary(lhs.into(), op, rhs.into());
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<PluralRule, String> {
+        if self.eat(&["!"]).is_some() {
+            return Ok(PluralRule::Not(self.parse_unary()?.into()));
+        }
+        if self.eat(&["("]).is_some() {
+            let result = self.parse_condition()?;
+            self.expect(")")?;
+            return Ok(result);
+        }
+        if self.eat(&["n"]).is_some() {
+            return Ok(PluralRule::N);
+        }
+        let digits =
+            self.rest.len() - self.rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return Err(if self.rest.is_empty() {
+                "unexpected end of the expression".into()
+            } else {
+                format!("unexpected '{}'", self.rest)
+            });
+        }
+        let value = self.rest[..digits].parse().map_err(|e| format!("{e}"))?;
+        self.rest = &self.rest[digits..];
+        Ok(PluralRule::Constant(value))
+    }
+}
+
+#[test]
+fn test_plural_rules() {
+    let evaluate = |rule: &str, n: i64| PluralRule::parse(rule).unwrap().evaluate(n);
+    assert_eq!(evaluate("0", 5), 0);
+    assert_eq!(evaluate("(n != 1)", 1), 0);
+    assert_eq!(evaluate("(n != 1)", 0), 1);
+    // Polish
+    let polish = "(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2)";
+    let forms = [1, 2, 5, 12, 22, 25, 104, 111].map(|n| evaluate(polish, n));
+    assert_eq!(forms, [0, 1, 2, 2, 1, 2, 1, 2]);
+    // Arabic
+    let arabic = "n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : n%100>=11 ? 4 : 5";
+    let forms = [0, 1, 2, 3, 11, 100, 102].map(|n| evaluate(arabic, n));
+    assert_eq!(forms, [0, 1, 2, 3, 4, 5, 5]);
+    assert_eq!(evaluate("!(n % 2) + 2 * 3 - 1", 4), 6);
+    assert_eq!(evaluate("n / 0", 4), 0);
+
+    assert!(PluralRule::parse("n ==").is_err());
+    assert!(PluralRule::parse("(n == 1").is_err());
+    assert!(PluralRule::parse("n == 1 ? 0").is_err());
+    assert!(PluralRule::parse("x").is_err());
+    assert!(PluralRule::parse("n n").is_err());
+}
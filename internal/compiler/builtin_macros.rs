@@ -9,7 +9,7 @@
     BuiltinFunction, BuiltinMacroFunction, EasingCurve, Expression, MinMaxOp, Unit,
 };
 use crate::langtype::{EnumerationValue, Type};
-use crate::parser::NodeOrToken;
+use crate::parser::{NodeOrToken, SyntaxKind, SyntaxNode};
 
 /// Used for uniquely name some variables
 static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(1);
@@ -37,19 +37,13 @@ pub fn lower_macro(
                     has_error.get_or_insert((n.clone(), "Not enough arguments"));
                     0.
                 }
-                Some((Expression::NumberLiteral(val, Unit::None), _)) => val as f32,
-                // handle negative numbers
-                Some((Expression::UnaryOp { sub, op: '-' }, n)) => match *sub {
-                    Expression::NumberLiteral(val, Unit::None) => (-1.0 * val) as f32,
-                    _ => {
+                Some((expr, n)) => number_literal(&expr).map_or_else(
+                    || {
                         has_error.get_or_insert((n, expected_argument_type_error));
                         0.
-                    }
-                },
-                Some((_, n)) => {
-                    has_error.get_or_insert((n, expected_argument_type_error));
-                    0.
-                }
+                    },
+                    |val| val as f32,
+                ),
             };
             let expr = Expression::EasingCurve(EasingCurve::CubicBezier(a(), a(), a(), a()));
             if let Some((_, n)) = sub_expr.next() {
@@ -61,10 +55,97 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Steps => steps_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
     }
 }
 
+/// The value of a number literal without unit, possibly negative
+fn number_literal(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(val, Unit::None) => Some(*val),
+        Expression::UnaryOp { sub, op: '-' } => match **sub {
+            Expression::NumberLiteral(val, Unit::None) => Some(-val),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// If the expression node is the `start` or `end` keyword of `steps(n, start|end)`,
+/// returns whether the jump happens at the start
+pub fn steps_jump_keyword(node: &SyntaxNode) -> Option<bool> {
+    let qn = node.child_node(SyntaxKind::QualifiedName)?;
+    match qn.text().to_string().trim() {
+        "start" => Some(true),
+        "end" => Some(false),
+        _ => None,
+    }
+}
+
+fn steps_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.is_empty() || args.len() > 2 {
+        diag.push_error("Needs 1 or 2 arguments".into(), &node);
+        return Expression::Invalid;
+    }
+    let mut args = args.into_iter();
+    let (count, count_node) = args.next().unwrap();
+    let count = match number_literal(&count) {
+        Some(count) if count >= 1. && count.fract() == 0. && count <= u32::MAX as f64 => {
+            count as u32
+        }
+        _ => {
+            diag.push_error(
+                "The number of steps must be a positive integer literal".into(),
+                &count_node,
+            );
+            1
+        }
+    };
+    let jump_start = match args.next() {
+        None => false,
+        Some((_, jump_node)) => {
+            match jump_node.as_ref().and_then(|n| n.as_node()).and_then(steps_jump_keyword) {
+                Some(jump_start) => jump_start,
+                None => {
+                    diag.push_error("Expected 'start' or 'end'".into(), &jump_node);
+                    false
+                }
+            }
+        }
+    };
+    Expression::EasingCurve(EasingCurve::Steps { count, jump_start })
+}
+
+fn spring_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 3 {
+        diag.push_error(
+            "Needs 3 arguments: the mass, the stiffness and the damping of the spring".into(),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut values = args.into_iter().map(|(expr, n)| match number_literal(&expr) {
+        Some(val) if val > 0. => val as f32,
+        _ => {
+            diag.push_error("Arguments to spring must be positive number literals".into(), &n);
+            1.
+        }
+    });
+    let (mass, stiffness, damping) =
+        (values.next().unwrap(), values.next().unwrap(), values.next().unwrap());
+    Expression::EasingCurve(EasingCurve::Spring { mass, stiffness, damping })
+}
+
 fn min_max_macro(
     node: Option<NodeOrToken>,
     op: MinMaxOp,
@@ -79,6 +79,9 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Mod,
     CubicBezier,
+    /// `steps(n, start|end)`: the second argument is the `start` or `end` keyword
+    Steps,
+    Spring,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
@@ -1476,6 +1479,21 @@ pub enum EasingCurve {
     #[default]
     Linear,
     CubicBezier(f32, f32, f32, f32),
+    EaseInElastic,
+    EaseOutElastic,
+    EaseInOutElastic,
+    EaseInBounce,
+    EaseOutBounce,
+    EaseInOutBounce,
+    Steps {
+        count: u32,
+        jump_start: bool,
+    },
+    Spring {
+        mass: f32,
+        stiffness: f32,
+        damping: f32,
+    },
     // CubicBezierNonConst([Box<Expression>; 4]),
     // Custom(Box<dyn Fn(f32)->f32>),
 }
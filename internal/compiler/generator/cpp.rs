@@ -744,6 +744,10 @@ pub fn generate(doc: &Document) -> impl std::fmt::Display {
 
     let llr = llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
 
+    if let Some(translations) = &llr.translations {
+        generate_translations(translations, &mut file);
+    }
+
     // Forward-declare the root so that sub-components can access singletons, the window, etc.
     file.declarations.push(Declaration::Struct(Struct {
         name: ident(&llr.item_tree.root.name),
@@ -1348,6 +1352,28 @@ fn generate_item_tree(
         }),
     ));
 
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "item_element_infos".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ComponentRef component, uint32_t index, slint::SharedString *result) -> bool"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![
+                format!(
+                    "if (auto infos = reinterpret_cast<const {}*>(component.instance)->item_element_infos(index)) {{",
+                    item_tree_class_name
+                ),
+                "    *result = *infos;".into(),
+                "    return true;".into(),
+                "}".into(),
+                "return false;".into(),
+            ]),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         Access::Private,
         Declaration::Function(Function {
@@ -1378,7 +1404,7 @@ fn generate_item_tree(
         init: Some(format!(
             "{{ visit_children, get_item_ref, get_subtree_range, get_subtree_component, \
                 get_item_tree, parent_node, embed_component, subtree_index, layout_info, \
-                item_geometry, accessible_role, accessible_string_property, window_adapter, \
+                item_geometry, accessible_role, accessible_string_property, item_element_infos, window_adapter, \
                 slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
             item_tree_class_name
         )),
@@ -1553,6 +1579,17 @@ fn generate_sub_component(
         file.definitions.extend(popup_struct.extract_definitions().collect::<Vec<_>>());
         file.declarations.push(Declaration::Struct(popup_struct));
     });
+    for popup_index in 0..component.popup_windows.len() {
+        target_struct.members.push((
+            field_access,
+            Declaration::Var(Var {
+                ty: "mutable uint32_t".into(),
+                name: popup_id_field_name(popup_index),
+                init: Some("0".into()),
+                ..Default::default()
+            }),
+        ));
+    }
 
     for property in component.properties.iter().filter(|p| p.use_count.get() > 0) {
         let cpp_name = ident(&property.name);
@@ -1769,6 +1806,23 @@ fn generate_sub_component(
 
     init.extend(properties_init_code);
 
+    for (i, (prop, handler)) in component.change_callbacks.iter().enumerate() {
+        let tracker = format!("change_tracker{}", i);
+        user_init.push(format!(
+            "self->{tracker}.init(self, [](auto self) {{ return {prop}.get(); }}, []([[maybe_unused]] auto self, const auto &) {{ {handler}; }});",
+            prop = access_member(prop, &ctx),
+            handler = compile_expression(&handler.borrow(), &ctx),
+        ));
+        target_struct.members.push((
+            field_access,
+            Declaration::Var(Var {
+                ty: "slint::private_api::ChangeTracker".into(),
+                name: tracker,
+                ..Default::default()
+            }),
+        ));
+    }
+
     user_init.extend(component.init_code.iter().map(|e| {
         let mut expr_str = compile_expression(&e.borrow(), &ctx);
         expr_str.push(';');
@@ -1907,6 +1961,28 @@ fn generate_sub_component(
         accessible_string_cases,
     );
 
+    let mut element_infos_cases = vec!["switch (index) {".to_string()];
+    for (index, infos) in &component.element_infos {
+        let infos = escape_string(infos);
+        match component.sub_components.iter().find(|sub| sub.index_in_tree == *index) {
+            // The root of a sub-component also has the ids from within the sub-component
+            Some(sub) => element_infos_cases.push(format!(
+                "    case {index}: {{ slint::SharedString infos(u8\"{infos}\"); if (auto sub_infos = self->{}.item_element_infos(0)) {{ infos += \"\\n\"; infos += *sub_infos; }} return infos; }}",
+                ident(&sub.name)
+            )),
+            None => element_infos_cases
+                .push(format!("    case {index}: return slint::SharedString(u8\"{infos}\");")),
+        }
+    }
+    element_infos_cases.push("}".into());
+
+    dispatch_item_function(
+        "item_element_infos",
+        "(uint32_t index) const -> std::optional<slint::SharedString>",
+        "",
+        element_infos_cases,
+    );
+
     if !children_visitor_cases.is_empty() {
         target_struct.members.push((
             field_access,
@@ -2052,6 +2128,27 @@ fn generate_repeated_component(
                 ..Function::default()
             }),
         ));
+        let flex_value = |p: &Option<llr::PropertyReference>, default: f64| match p {
+            Some(p) => compile_expression(&llr::Expression::PropertyReference(p.clone()), &ctx),
+            None => compile_expression(&llr::Expression::NumberLiteral(default), &ctx),
+        };
+        repeater_struct.members.push((
+            Access::Public, // Because Repeater accesses it
+            Declaration::Function(Function {
+                name: "flexbox_layout_data".into(),
+                signature: "(slint::cbindgen_private::Orientation o) const -> slint::cbindgen_private::FlexboxLayoutCellData".to_owned(),
+                statements: Some(vec![
+                    "[[maybe_unused]] auto self = this;".into(),
+                    format!(
+                        "return {{ layout_info({{&static_vtable, const_cast<void *>(static_cast<const void *>(this))}}, o), {}, {}, {} }};",
+                        flex_value(&repeated.flexbox_item.grow, 0.),
+                        flex_value(&repeated.flexbox_item.shrink, 1.),
+                        flex_value(&repeated.flexbox_item.basis, -1.),
+                    ),
+                ]),
+                ..Function::default()
+            }),
+        ));
     }
 
     if let Some(index_prop) = repeated.index_prop {
@@ -2368,6 +2465,12 @@ fn follow_sub_component_path<'a>(
     (compo_path, sub_component)
 }
 
+/// The name of the member of the sub-component that holds the id of its popup with the given index,
+/// while that popup is shown
+fn popup_id_field_name(popup_index: usize) -> String {
+    format!("popup_id_{}", popup_index)
+}
+
 fn access_window_field(ctx: &EvaluationContext) -> String {
     let root = &ctx.generator_state.root_access;
     format!("{}->window().window_handle()", root)
@@ -2816,6 +2919,20 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::CubicBezier, {}, {}, {}, {})",
             a, b, c, d
         ),
+        Expression::EasingCurve(EasingCurve::EaseInElastic) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInElastic)".into(),
+        Expression::EasingCurve(EasingCurve::EaseOutElastic) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic)".into(),
+        Expression::EasingCurve(EasingCurve::EaseInOutElastic) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic)".into(),
+        Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInBounce)".into(),
+        Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseOutBounce)".into(),
+        Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::EaseInOutBounce)".into(),
+        Expression::EasingCurve(EasingCurve::Steps { count, jump_start }) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::Steps, {}, {})",
+            count, jump_start
+        ),
+        Expression::EasingCurve(EasingCurve::Spring { mass, stiffness, damping }) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::Spring, {}, {}, {})",
+            mass, stiffness, damping
+        ),
         Expression::LinearGradient{angle, stops} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
@@ -2860,12 +2977,14 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             repeater_indices,
             elements,
             orientation,
+            flexbox,
             sub_expression,
         } => box_layout_function(
             cells_variable,
             repeater_indices.as_ref().map(String::as_str),
             elements,
             *orientation,
+            *flexbox,
             sub_expression,
             ctx,
         ),
@@ -3051,7 +3170,7 @@ fn compile_builtin_function_call(
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, llr::Expression::PropertyReference(parent_ref)] =
+            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, placement, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -3072,16 +3191,33 @@ fn compile_builtin_function_call(
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
                 let close_on_click = compile_expression(close_on_click, ctx);
+                let placement = compile_expression(placement, ctx);
+                let popup_id =
+                    format!("{component_access}->{}", popup_id_field_name(*popup_index as usize));
                 format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {{ {parent_component} }})"
+                    "[&] {{ {window}.close_popup({popup_id}); {popup_id} = {window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {placement}, {{ {parent_component} }}); }}()"
                 )
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
         BuiltinFunction::ClosePopupWindow => {
-            let window = access_window_field(ctx);
-            format!("{window}.close_popup()")
+            if let [llr::Expression::NumberLiteral(popup_index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut component_access = "self".to_string();
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access = format!("{}->parent", component_access);
+                    }
+                };
+                let window = access_window_field(ctx);
+                let popup_id =
+                    format!("{component_access}->{}", popup_id_field_name(*popup_index as usize));
+                format!("[&] {{ {window}.close_popup({popup_id}); {popup_id} = 0; }}()")
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
         }
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [llr::Expression::PropertyReference(pr)] = arguments {
@@ -3155,22 +3291,150 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::Translate => {
+            if let (
+                Some(translations),
+                [llr::Expression::StringLiteral(original), llr::Expression::StringLiteral(context), _, args, n, llr::Expression::StringLiteral(plural)],
+            ) = (ctx.public_component.translations.as_ref(), arguments)
+            {
+                return compile_bundled_translation(
+                    translations,
+                    original,
+                    context,
+                    plural,
+                    compile_expression(args, ctx),
+                    compile_expression(n, ctx),
+                );
+            }
             format!("slint::private_api::translate({})", a.join(","))
         }
     }
 }
 
+/// Generate the call to translate a string with the translations embedded in the generated code.
+/// The languages and the plural rules are in the variables generated by [`generate_translations`]
+fn compile_bundled_translation(
+    translations: &crate::translations::Translations,
+    original: &str,
+    context: &str,
+    plural: &str,
+    args: String,
+    n: String,
+) -> String {
+    let translated = translations.lookup(context, original).unwrap_or_default();
+    if plural.is_empty() {
+        let translated = translated
+            .iter()
+            .map(|forms| match forms.first() {
+                Some(s) if !s.is_empty() => string_literal(s),
+                _ => "nullptr".into(),
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "slint::private_api::translate_from_bundle(slint_bundled_languages, {original}, std::array<const char8_t *, {count}>{{ {translated} }}, {args})",
+            original = string_literal(original),
+            count = translated.len(),
+            translated = translated.join(", "),
+        )
+    } else {
+        // The plural forms of each language are terminated by a nullptr
+        let translated = translated
+            .iter()
+            .flat_map(|forms| {
+                forms.iter().map(|s| string_literal(s)).chain(std::iter::once("nullptr".into()))
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "slint::private_api::translate_from_bundle_with_plural(slint_bundled_languages, slint_bundled_plural_rules, {original}, {plural}, std::array<const char8_t *, {count}>{{ {translated} }}, {args}, {n})",
+            original = string_literal(original),
+            plural = string_literal(plural),
+            count = translated.len(),
+            translated = translated.join(", "),
+        )
+    }
+}
+
+/// A `const char8_t *` literal for the string
+fn string_literal(s: &str) -> String {
+    format!(r#"u8"{}""#, escape_string(s))
+}
+
+/// Generate the variables with the languages and the plural rules of the bundled translations
+fn generate_translations(translations: &crate::translations::Translations, file: &mut File) {
+    let languages = translations.languages.iter().map(|l| string_literal(l)).collect::<Vec<_>>();
+    file.declarations.push(Declaration::Var(Var {
+        ty: format!("inline const std::array<const char8_t *, {}>", languages.len()),
+        name: "slint_bundled_languages".into(),
+        array_size: None,
+        init: Some(format!("{{ {} }}", languages.join(", "))),
+    }));
+    let plural_rules = translations
+        .plural_rules
+        .iter()
+        .map(|rule| {
+            format!(
+                "[](int32_t n32) -> uintptr_t {{ int64_t n = n32; return uintptr_t({}); }}",
+                compile_plural_rule(rule)
+            )
+        })
+        .collect::<Vec<_>>();
+    file.declarations.push(Declaration::Var(Var {
+        ty: format!("inline const std::array<uintptr_t (*)(int32_t), {}>", plural_rules.len()),
+        name: "slint_bundled_plural_rules".into(),
+        array_size: None,
+        init: Some(format!("{{ {} }}", plural_rules.join(", "))),
+    }));
+}
+
+/// Compile the plural rule to a C++ expression of type `int64_t`, which use the `n` variable
+fn compile_plural_rule(rule: &crate::translations::PluralRule) -> String {
+    use crate::translations::PluralRule;
+    match rule {
+        PluralRule::N => "n".into(),
+        PluralRule::Constant(value) => format!("int64_t({value})"),
+        PluralRule::Not(value) => format!("int64_t(!({}))", compile_plural_rule(value)),
+        PluralRule::Binary(lhs, op, rhs) => {
+            let lhs = compile_plural_rule(lhs);
+            let rhs = compile_plural_rule(rhs);
+            match op {
+                '&' => format!("int64_t(({lhs}) && ({rhs}))"),
+                '|' => format!("int64_t(({lhs}) || ({rhs}))"),
+                // Unsigned arithmetic wraps around on overflow, as in the Rust generator
+                '+' | '-' | '*' => format!("int64_t(uint64_t({lhs}) {op} uint64_t({rhs}))"),
+                '/' | '%' => format!("(({rhs}) != 0 ? ({lhs}) {op} ({rhs}) : 0)"),
+                '=' => format!("int64_t(({lhs}) == ({rhs}))"),
+                '!' => format!("int64_t(({lhs}) != ({rhs}))"),
+                '<' => format!("int64_t(({lhs}) < ({rhs}))"),
+                '>' => format!("int64_t(({lhs}) > ({rhs}))"),
+                '≤' => format!("int64_t(({lhs}) <= ({rhs}))"),
+                '≥' => format!("int64_t(({lhs}) >= ({rhs}))"),
+                _ => panic!("internal error: invalid operator {op} in plural rule"),
+            }
+        }
+        PluralRule::Condition(condition, true_value, false_value) => format!(
+            "(({}) != 0 ? ({}) : ({}))",
+            compile_plural_rule(condition),
+            compile_plural_rule(true_value),
+            compile_plural_rule(false_value)
+        ),
+    }
+}
+
 fn box_layout_function(
     cells_variable: &str,
     repeated_indices: Option<&str>,
     elements: &[Either<llr::Expression, u32>],
     orientation: Orientation,
+    flexbox: bool,
     sub_expression: &llr::Expression,
     ctx: &llr_EvaluationContext<CppGeneratorContext>,
 ) -> String {
     let repeated_indices = repeated_indices.map(ident);
-    let mut push_code =
-        "std::vector<slint::cbindgen_private::BoxLayoutCellData> cells_vector;".to_owned();
+    let (cell_type, layout_data_fn) = if flexbox {
+        ("slint::cbindgen_private::FlexboxLayoutCellData", "flexbox_layout_data")
+    } else {
+        ("slint::cbindgen_private::BoxLayoutCellData", "box_layout_data")
+    };
+    let mut push_code = format!("std::vector<{cell_type}> cells_vector;");
     let mut repeater_idx = 0usize;
 
     for item in elements {
@@ -3201,8 +3465,9 @@ fn box_layout_function(
                     push_code,
                     "if (self->repeater_{id}.inner) \
                         for (auto &&sub_comp : self->repeater_{id}.inner->data) \
-                           cells_vector.push_back((*sub_comp.ptr)->box_layout_data({o}));",
+                           cells_vector.push_back((*sub_comp.ptr)->{f}({o}));",
                     id = repeater,
+                    f = layout_data_fn,
                     o = to_cpp_orientation(orientation),
                 )
                 .unwrap();
@@ -3220,9 +3485,10 @@ fn box_layout_function(
         format!("std::array<int, {}> {}_array;", 2 * repeater_idx, ri)
     });
     format!(
-        "[&]{{ {} {} slint::cbindgen_private::Slice<slint::cbindgen_private::BoxLayoutCellData>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
+        "[&]{{ {} {} slint::cbindgen_private::Slice<{}>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
         ri,
         push_code,
+        cell_type,
         ident(cells_variable),
         compile_expression(sub_expression, ctx)
     )
@@ -186,6 +186,7 @@ pub fn generate(doc: &Document) -> TokenStream {
     });
 
     let resource_symbols = generate_resources(doc);
+    let translations = llr.translations.as_ref().map(|t| generate_translations(t));
 
     quote! {
         #[allow(non_snake_case)]
@@ -208,6 +209,7 @@ mod #compo_module {
             #(#sub_compos)*
             #compo
             #(#resource_symbols)*
+            #translations
             const _THE_SAME_VERSION_MUST_BE_USED_FOR_THE_COMPILER_AND_THE_RUNTIME : slint::#version_check = slint::#version_check;
         }
         pub use #compo_module::{#compo_id #(,#structs_and_enums_ids)* #(,#globals_ids)* };
@@ -602,6 +604,8 @@ fn generate_sub_component(
         .iter()
         .map(|c| generate_item_tree(c, root, Some(ParentCtx::new(&ctx, None)), quote!(), None))
         .collect::<Vec<_>>();
+    let popup_id_names =
+        (0..component.popup_windows.len()).map(popup_id_field_name).collect::<Vec<_>>();
 
     let mut declared_property_vars = vec![];
     let mut declared_property_types = vec![];
@@ -800,6 +804,9 @@ fn generate_sub_component(
         })
         .collect::<Vec<_>>();
 
+    let mut element_infos = component.element_infos.clone();
+    let mut item_element_infos_branch = vec![];
+
     let mut user_init_code: Vec<TokenStream> = Vec::new();
 
     let mut sub_component_names: Vec<Ident> = vec![];
@@ -865,6 +872,17 @@ fn generate_sub_component(
         accessible_string_property_branch.push(quote!(
             (#local_tree_index, _) => #sub_compo_field.apply_pin(_self).accessible_string_property(0, what),
         ));
+        item_element_infos_branch.push(match element_infos.remove(&local_tree_index) {
+            Some(infos) => quote!(
+                #local_tree_index => Some(match #sub_compo_field.apply_pin(_self).item_element_infos(0) {
+                    Some(sub_infos) => sp::format!("{}\n{}", #infos, sub_infos).as_str().into(),
+                    None => #infos.into(),
+                }),
+            ),
+            None => quote!(
+                #local_tree_index => #sub_compo_field.apply_pin(_self).item_element_infos(0),
+            ),
+        });
         if sub_items_count > 1 {
             let range_begin = local_index_of_first_child;
             let range_end = range_begin + sub_items_count - 2 + sub.ty.repeater_count();
@@ -877,12 +895,18 @@ fn generate_sub_component(
             item_geometry_branch.push(quote!(
                 #range_begin..=#range_end => return #sub_compo_field.apply_pin(_self).item_geometry(index - #range_begin + 1),
             ));
+            item_element_infos_branch.push(quote!(
+                #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).item_element_infos(index - #range_begin + 1),
+            ));
         }
 
         sub_component_names.push(field_name);
         sub_component_types.push(sub_component_id);
     }
 
+    item_element_infos_branch
+        .extend(element_infos.iter().map(|(index, infos)| quote!(#index => Some(#infos.into()),)));
+
     for (prop1, prop2) in &component.two_way_bindings {
         let p1 = access_member(prop1, &ctx);
         let p2 = access_member(prop2, &ctx);
@@ -918,6 +942,30 @@ fn generate_sub_component(
         quote!(sp::VWeakMapped::<sp::ComponentVTable, #parent_component_id>)
     });
 
+    let mut change_tracker_names = vec![];
+    for (i, (prop, handler)) in component.change_callbacks.iter().enumerate() {
+        let tracker = format_ident!("change_tracker{}", i);
+        let prop = access_member(prop, &ctx);
+        let handler = compile_expression(&handler.borrow(), &ctx);
+        user_init_code.push(quote!(
+            #[allow(unreachable_code, unused)]
+            _self.#tracker.init(
+                sp::VRcMapped::downgrade(&self_rc),
+                |self_weak| {
+                    let self_rc = self_weak.upgrade().unwrap();
+                    let _self = self_rc.as_pin_ref();
+                    #prop.get()
+                },
+                |self_weak, _| {
+                    let self_rc = self_weak.upgrade().unwrap();
+                    let _self = self_rc.as_pin_ref();
+                    #handler;
+                }
+            );
+        ));
+        change_tracker_names.push(tracker);
+    }
+
     user_init_code.extend(component.init_code.iter().map(|e| {
         let code = compile_expression(&e.borrow(), &ctx);
         quote!(#code;)
@@ -954,6 +1002,8 @@ struct #inner_component_id {
             #(#declared_property_vars : sp::Property<#declared_property_types>,)*
             #(#declared_callbacks : sp::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             #(#repeated_element_names : sp::Repeater<#repeated_element_components>,)*
+            #(#change_tracker_names : sp::ChangeTracker,)*
+            #(#popup_id_names : ::core::cell::Cell<Option<::core::num::NonZeroU32>>,)*
             self_weak : sp::OnceCell<sp::VWeakMapped<sp::ComponentVTable, #inner_component_id>>,
             #(parent : #parent_component_type,)*
             root : sp::OnceCell<sp::VWeak<sp::ComponentVTable, #root_component_id>>,
@@ -1062,6 +1112,15 @@ fn accessible_string_property(
                 }
             }
 
+            fn item_element_infos(self: ::core::pin::Pin<&Self>, index: u32) -> Option<sp::SharedString> {
+                #![allow(unused)]
+                let _self = self;
+                match index {
+                    #(#item_element_infos_branch)*
+                    _ => None,
+                }
+            }
+
             #(#declared_functions)*
         }
 
@@ -1504,6 +1563,19 @@ fn accessible_string_property(
                 *result = self.accessible_string_property(index, what);
             }
 
+            fn item_element_infos(
+                self: ::core::pin::Pin<&Self>,
+                index: u32,
+                result: &mut sp::SharedString,
+            ) -> bool {
+                if let Some(infos) = self.item_element_infos(index) {
+                    *result = infos;
+                    true
+                } else {
+                    false
+                }
+            }
+
             fn window_adapter(
                 self: ::core::pin::Pin<&Self>,
                 do_create: bool,
@@ -1569,6 +1641,13 @@ fn listview_layout(
             }
         }
     } else {
+        let flex_value = |p: &Option<llr::PropertyReference>, default: f32| match p {
+            Some(p) => compile_expression(&Expression::PropertyReference(p.clone()), &ctx),
+            None => quote!(#default),
+        };
+        let grow = flex_value(&repeated.flexbox_item.grow, 0.);
+        let shrink = flex_value(&repeated.flexbox_item.shrink, 1.);
+        let basis = flex_value(&repeated.flexbox_item.basis, -1.);
         // TODO: we could generate this code only if we know that this component is in a box layout
         quote! {
             fn box_layout_data(self: ::core::pin::Pin<&Self>, o: sp::Orientation)
@@ -1576,6 +1655,17 @@ fn box_layout_data(self: ::core::pin::Pin<&Self>, o: sp::Orientation)
             {
                 BoxLayoutCellData { constraint: self.as_ref().layout_info(o) }
             }
+            fn flexbox_layout_data(self: ::core::pin::Pin<&Self>, o: sp::Orientation)
+                -> sp::FlexboxLayoutCellData
+            {
+                let _self = self;
+                sp::FlexboxLayoutCellData {
+                    constraint: self.as_ref().layout_info(o),
+                    grow: #grow as _,
+                    shrink: #shrink as _,
+                    basis: #basis as _,
+                }
+            }
         }
     };
 
@@ -1815,6 +1905,12 @@ fn follow_sub_component_path<'a>(
     (compo_path, sub_component)
 }
 
+/// The name of the field of the sub-component that holds the id of its popup with the given index,
+/// while that popup is shown
+fn popup_id_field_name(popup_index: usize) -> Ident {
+    format_ident!("popup_id_{}", popup_index)
+}
+
 fn access_window_adapter_field(ctx: &EvaluationContext) -> TokenStream {
     let root = &ctx.generator_state;
     quote!((&#root.window_adapter_impl()))
@@ -2190,6 +2286,34 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::CubicBezier(a, b, c, d)) => {
             quote!(sp::EasingCurve::CubicBezier([#a, #b, #c, #d]))
         }
+        Expression::EasingCurve(EasingCurve::EaseInElastic) => {
+            quote!(sp::EasingCurve::EaseInElastic)
+        }
+        Expression::EasingCurve(EasingCurve::EaseOutElastic) => {
+            quote!(sp::EasingCurve::EaseOutElastic)
+        }
+        Expression::EasingCurve(EasingCurve::EaseInOutElastic) => {
+            quote!(sp::EasingCurve::EaseInOutElastic)
+        }
+        Expression::EasingCurve(EasingCurve::EaseInBounce) => {
+            quote!(sp::EasingCurve::EaseInBounce)
+        }
+        Expression::EasingCurve(EasingCurve::EaseOutBounce) => {
+            quote!(sp::EasingCurve::EaseOutBounce)
+        }
+        Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
+            quote!(sp::EasingCurve::EaseInOutBounce)
+        }
+        Expression::EasingCurve(EasingCurve::Steps { count, jump_start }) => {
+            quote!(sp::EasingCurve::Steps { count: #count, jump_start: #jump_start })
+        }
+        Expression::EasingCurve(EasingCurve::Spring { mass, stiffness, damping }) => {
+            quote!(sp::EasingCurve::Spring {
+                mass: #mass,
+                stiffness: #stiffness,
+                damping: #damping
+            })
+        }
         Expression::LinearGradient { angle, stops } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
@@ -2237,12 +2361,14 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             repeater_indices,
             elements,
             orientation,
+            flexbox,
             sub_expression,
         } => box_layout_function(
             cells_variable,
             repeater_indices.as_ref().map(String::as_str),
             elements,
             *orientation,
+            *flexbox,
             sub_expression,
             ctx,
         ),
@@ -2300,7 +2426,7 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, Expression::PropertyReference(parent_ref)] =
+            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, placement, Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2320,9 +2446,16 @@ fn compile_builtin_function_call(
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
                 let close_on_click = compile_expression(close_on_click, ctx);
+                let placement = compile_expression(placement, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
-                quote!(
-                    sp::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
+                let popup_id_name = popup_id_field_name(*popup_index as usize);
+                quote!({
+                    let window_adapter = #window_adapter_tokens;
+                    let window_inner = sp::WindowInner::from_pub(window_adapter.window());
+                    if let Some(current_id) = #component_access_tokens.#popup_id_name.take() {
+                        window_inner.close_popup(current_id);
+                    }
+                    let popup_id = window_inner.show_popup(
                         &VRc::into_dyn({
                             let instance = #popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone());
                             #popup_window_id::user_init(sp::VRc::map(instance.clone(), |x| x));
@@ -2330,18 +2463,36 @@ fn compile_builtin_function_call(
                         }),
                         Point::new(#x as sp::Coord, #y as sp::Coord),
                         #close_on_click,
+                        #placement,
                         #parent_component
-                    )
-                )
+                    );
+                    #component_access_tokens.#popup_id_name.set(Some(popup_id));
+                })
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
         BuiltinFunction::ClosePopupWindow => {
-            let window_adapter_tokens = access_window_adapter_field(ctx);
-            quote!(
-                sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup()
-            )
+            if let [Expression::NumberLiteral(popup_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut component_access_tokens = quote!(_self);
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access_tokens =
+                            quote!(#component_access_tokens.parent.upgrade().unwrap().as_pin_ref());
+                    }
+                }
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                let popup_id_name = popup_id_field_name(*popup_index as usize);
+                quote!(
+                    if let Some(current_id) = #component_access_tokens.#popup_id_name.take() {
+                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup(current_id);
+                    }
+                )
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
         }
         BuiltinFunction::ItemMemberFunction(name) => {
             if let [Expression::PropertyReference(pr)] = arguments {
@@ -2492,6 +2643,20 @@ fn compile_builtin_function_call(
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).set_text_input_focused(#(#a)*))
         }
         BuiltinFunction::Translate => {
+            if let (
+                Some(translations),
+                [Expression::StringLiteral(original), Expression::StringLiteral(context), _, args, n, Expression::StringLiteral(plural)],
+            ) = (ctx.public_component.translations.as_ref(), arguments)
+            {
+                return compile_bundled_translation(
+                    translations,
+                    original,
+                    context,
+                    plural,
+                    compile_expression(args, ctx),
+                    compile_expression(n, ctx),
+                );
+            }
             quote!(slint::private_unstable_api::translate(#((#a) as _),*))
         }
         BuiltinFunction::ItemAbsolutePosition => {
@@ -2507,6 +2672,98 @@ fn compile_builtin_function_call(
     }
 }
 
+/// Generate the call to translate a string with the translations embedded in the generated code.
+/// The languages and the plural rules are in the constants generated by [`generate_translations`]
+fn compile_bundled_translation(
+    translations: &crate::translations::Translations,
+    original: &str,
+    context: &str,
+    plural: &str,
+    args: TokenStream,
+    n: TokenStream,
+) -> TokenStream {
+    let translated = translations.lookup(context, original).unwrap_or_default();
+    if plural.is_empty() {
+        let translated = translated.iter().map(|forms| match forms.first() {
+            Some(s) if !s.is_empty() => quote!(Some(#s)),
+            _ => quote!(None),
+        });
+        quote!(slint::private_unstable_api::translate_from_bundle(
+            SLINT_BUNDLED_LANGUAGES,
+            #original,
+            &[#(#translated),*],
+            (#args) as _,
+        ))
+    } else {
+        let translated = translated.iter().map(|forms| quote!(&[#(#forms),*]));
+        quote!(slint::private_unstable_api::translate_from_bundle_with_plural(
+            SLINT_BUNDLED_LANGUAGES,
+            SLINT_BUNDLED_PLURAL_RULES,
+            [#original, #plural],
+            &[#(#translated),*],
+            (#args) as _,
+            (#n) as _,
+        ))
+    }
+}
+
+/// Generate the constants with the languages and the plural rules of the bundled translations
+fn generate_translations(translations: &crate::translations::Translations) -> TokenStream {
+    let languages = &translations.languages;
+    let plural_rules = translations.plural_rules.iter().map(|rule| {
+        let rule = compile_plural_rule(rule);
+        quote!(&|n: i32| { let n = n as i64; (#rule) as usize })
+    });
+    quote!(
+        #[allow(dead_code)]
+        const SLINT_BUNDLED_LANGUAGES: &[&str] = &[#(#languages),*];
+        #[allow(dead_code)]
+        const SLINT_BUNDLED_PLURAL_RULES: &[&dyn Fn(i32) -> usize] = &[#(#plural_rules),*];
+    )
+}
+
+/// Compile the plural rule to a Rust expression of type `i64`, which use the `n: i64` variable
+fn compile_plural_rule(rule: &crate::translations::PluralRule) -> TokenStream {
+    use crate::translations::PluralRule;
+    match rule {
+        PluralRule::N => quote!(n),
+        PluralRule::Constant(value) => {
+            let value = proc_macro2::Literal::i64_suffixed(*value);
+            quote!(#value)
+        }
+        PluralRule::Not(value) => {
+            let value = compile_plural_rule(value);
+            quote!(((#value) == 0) as i64)
+        }
+        PluralRule::Binary(lhs, op, rhs) => {
+            let lhs = compile_plural_rule(lhs);
+            let rhs = compile_plural_rule(rhs);
+            match op {
+                '&' => quote!(((#lhs) != 0 && (#rhs) != 0) as i64),
+                '|' => quote!(((#lhs) != 0 || (#rhs) != 0) as i64),
+                '+' => quote!((#lhs).wrapping_add(#rhs)),
+                '-' => quote!((#lhs).wrapping_sub(#rhs)),
+                '*' => quote!((#lhs).wrapping_mul(#rhs)),
+                '/' => quote!((#lhs).checked_div(#rhs).unwrap_or(0)),
+                '%' => quote!((#lhs).checked_rem(#rhs).unwrap_or(0)),
+                '=' => quote!(((#lhs) == (#rhs)) as i64),
+                '!' => quote!(((#lhs) != (#rhs)) as i64),
+                '<' => quote!(((#lhs) < (#rhs)) as i64),
+                '>' => quote!(((#lhs) > (#rhs)) as i64),
+                '≤' => quote!(((#lhs) <= (#rhs)) as i64),
+                '≥' => quote!(((#lhs) >= (#rhs)) as i64),
+                _ => panic!("internal error: invalid operator {op} in plural rule"),
+            }
+        }
+        PluralRule::Condition(condition, true_value, false_value) => {
+            let condition = compile_plural_rule(condition);
+            let true_value = compile_plural_rule(true_value);
+            let false_value = compile_plural_rule(false_value);
+            quote!(if (#condition) != 0 { #true_value } else { #false_value })
+        }
+    }
+}
+
 /// Return a TokenStream for a name (as in [`Type::Struct::name`])
 fn struct_name_to_tokens(name: &str) -> TokenStream {
     // the name match the C++ signature so we need to change that to the rust namespace
@@ -2522,10 +2779,16 @@ fn box_layout_function(
     repeated_indices: Option<&str>,
     elements: &[Either<Expression, u32>],
     orientation: Orientation,
+    flexbox: bool,
     sub_expression: &Expression,
     ctx: &EvaluationContext,
 ) -> TokenStream {
     let repeated_indices = repeated_indices.map(ident);
+    let layout_data_fn = if flexbox {
+        format_ident!("flexbox_layout_data")
+    } else {
+        format_ident!("box_layout_data")
+    };
     let inner_component_id = self::inner_component_id(ctx.current_sub_component.unwrap());
     let mut fixed_count = 0usize;
     let mut repeated_count = quote!();
@@ -2558,7 +2821,7 @@ fn box_layout_function(
                         let internal_vec = _self.#repeater_id.components_vec();
                         #ri
                         for sub_comp in &internal_vec {
-                            items_vec.push(sub_comp.as_pin_ref().box_layout_data(#orientation))
+                            items_vec.push(sub_comp.as_pin_ref().#layout_data_fn(#orientation))
                         }
                     ));
             }
@@ -415,7 +415,10 @@ pub fn lookup_property<'a>(&self, name: &'a str) -> PropertyLookupResult<'a> {
                                 is_local_to_component: false,
                             }
                         } else {
-                            crate::typeregister::reserved_property(name)
+                            crate::typeregister::reserved_property_for_class(
+                                &b.native_class.class_name,
+                                name,
+                            )
                         }
                     }
                     Some(p) => PropertyLookupResult {
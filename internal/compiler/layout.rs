@@ -5,7 +5,7 @@
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::*;
-use crate::langtype::{ElementType, PropertyLookupResult, Type};
+use crate::langtype::{ElementType, EnumerationValue, PropertyLookupResult, Type};
 use crate::object_tree::{Component, ElementRc};
 
 use std::cell::RefCell;
@@ -21,6 +21,7 @@ pub enum Orientation {
 pub enum Layout {
     GridLayout(GridLayout),
     BoxLayout(BoxLayout),
+    FlexboxLayout(FlexboxLayout),
 }
 
 impl Layout {
@@ -28,18 +29,21 @@ pub fn rect(&self) -> &LayoutRect {
         match self {
             Layout::GridLayout(g) => &g.geometry.rect,
             Layout::BoxLayout(g) => &g.geometry.rect,
+            Layout::FlexboxLayout(g) => &g.geometry.rect,
         }
     }
     pub fn rect_mut(&mut self) -> &mut LayoutRect {
         match self {
             Layout::GridLayout(g) => &mut g.geometry.rect,
             Layout::BoxLayout(g) => &mut g.geometry.rect,
+            Layout::FlexboxLayout(g) => &mut g.geometry.rect,
         }
     }
     pub fn geometry(&self) -> &LayoutGeometry {
         match self {
             Layout::GridLayout(l) => &l.geometry,
             Layout::BoxLayout(l) => &l.geometry,
+            Layout::FlexboxLayout(l) => &l.geometry,
         }
     }
 }
@@ -50,6 +54,7 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
         match self {
             Layout::GridLayout(grid) => grid.visit_named_references(visitor),
             Layout::BoxLayout(l) => l.visit_named_references(visitor),
+            Layout::FlexboxLayout(l) => l.visit_named_references(visitor),
         }
     }
 }
@@ -126,6 +131,9 @@ pub struct LayoutConstraints {
     pub preferred_height: Option<NamedReference>,
     pub horizontal_stretch: Option<NamedReference>,
     pub vertical_stretch: Option<NamedReference>,
+    pub flex_grow: Option<NamedReference>,
+    pub flex_shrink: Option<NamedReference>,
+    pub flex_basis: Option<NamedReference>,
     pub fixed_width: bool,
     pub fixed_height: bool,
 }
@@ -141,6 +149,9 @@ pub fn new(element: &ElementRc, diag: &mut BuildDiagnostics) -> Self {
             preferred_height: binding_reference(element, "preferred-height"),
             horizontal_stretch: binding_reference(element, "horizontal-stretch"),
             vertical_stretch: binding_reference(element, "vertical-stretch"),
+            flex_grow: binding_reference(element, "flex-grow"),
+            flex_shrink: binding_reference(element, "flex-shrink"),
+            flex_basis: binding_reference(element, "flex-basis"),
             fixed_width: false,
             fixed_height: false,
         };
@@ -235,6 +246,14 @@ pub fn for_each_restrictions(
             .chain(stretch.as_ref().map(|x| (x, "stretch")))
     }
 
+    // Iterate over the flex properties that are set, with the corresponding member in the i_slint_core::layout::FlexboxLayoutCellData struct
+    pub fn for_each_flex_property(&self) -> impl Iterator<Item = (&NamedReference, &'static str)> {
+        std::iter::empty()
+            .chain(self.flex_grow.as_ref().map(|x| (x, "grow")))
+            .chain(self.flex_shrink.as_ref().map(|x| (x, "shrink")))
+            .chain(self.flex_basis.as_ref().map(|x| (x, "basis")))
+    }
+
     pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
         if let Some(e) = self.max_width.as_mut() {
             visitor(&mut *e);
@@ -260,6 +279,15 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
         if let Some(e) = self.vertical_stretch.as_mut() {
             visitor(&mut *e);
         }
+        if let Some(e) = self.flex_grow.as_mut() {
+            visitor(&mut *e);
+        }
+        if let Some(e) = self.flex_shrink.as_mut() {
+            visitor(&mut *e);
+        }
+        if let Some(e) = self.flex_basis.as_mut() {
+            visitor(&mut *e);
+        }
     }
 }
 
@@ -446,6 +474,56 @@ fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReferenc
     }
 }
 
+/// Internal representation of a FlexboxLayout
+#[derive(Debug, Clone)]
+pub struct FlexboxLayout {
+    /// The axis along which the items are placed. This is decided by the `direction` property,
+    /// which must be known at compile time.
+    pub main_axis: Orientation,
+    /// The value of the `direction` property
+    pub direction: EnumerationValue,
+    pub wrap: Option<NamedReference>,
+    pub justify_content: Option<NamedReference>,
+    pub align_content: Option<NamedReference>,
+    pub elems: Vec<LayoutItem>,
+    pub geometry: LayoutGeometry,
+}
+
+impl FlexboxLayout {
+    pub fn new(layout_element: &ElementRc, direction: EnumerationValue) -> Self {
+        let main_axis = if direction.enumeration.values[direction.value].starts_with("row") {
+            Orientation::Horizontal
+        } else {
+            Orientation::Vertical
+        };
+        Self {
+            main_axis,
+            direction,
+            wrap: binding_reference(layout_element, "wrap"),
+            justify_content: binding_reference(layout_element, "justify-content"),
+            align_content: binding_reference(layout_element, "align-content"),
+            elems: Default::default(),
+            geometry: LayoutGeometry::new(layout_element),
+        }
+    }
+
+    fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
+        for cell in &mut self.elems {
+            cell.constraints.visit_named_references(visitor);
+        }
+        self.geometry.visit_named_references(visitor);
+        if let Some(e) = self.wrap.as_mut() {
+            visitor(&mut *e)
+        }
+        if let Some(e) = self.justify_content.as_mut() {
+            visitor(&mut *e)
+        }
+        if let Some(e) = self.align_content.as_mut() {
+            visitor(&mut *e)
+        }
+    }
+}
+
 /// The [`Type`] for a runtime LayoutInfo structure
 pub fn layout_info_type() -> Type {
     Type::Struct {
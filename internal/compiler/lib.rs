@@ -30,6 +30,7 @@
 pub mod namedreference;
 pub mod object_tree;
 pub mod parser;
+pub mod translations;
 pub mod typeloader;
 pub mod typeregister;
 
@@ -83,6 +84,15 @@ pub struct CompilerConfiguration {
 
     /// The domain used as one of the parameter to the translate function
     pub translation_domain: Option<String>,
+
+    /// When set, the translations are read from the `.po` files in this directory at compile
+    /// time and embedded in the generated code, instead of being looked up with gettext at run-time.
+    /// The files are expected at `<path>/<language>/LC_MESSAGES/<translation_domain>.po`
+    pub translation_path_bundle: Option<std::path::PathBuf>,
+
+    /// Emit the ids of the elements in the generated code, so that the testing API can find
+    /// elements by id.
+    pub debug_info: bool,
 }
 
 impl CompilerConfiguration {
@@ -131,6 +141,8 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
 
         let enable_component_containers = enable_experimental_features;
 
+        let debug_info = std::env::var_os("SLINT_EMIT_DEBUG_INFO").is_some();
+
         Self {
             embed_resources,
             include_paths: Default::default(),
@@ -141,6 +153,8 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             accessibility: true,
             enable_component_containers,
             translation_domain: None,
+            translation_path_bundle: None,
+            debug_info,
         }
     }
 }
@@ -202,6 +216,9 @@ pub async fn compile_syntax_node(
     }
 
     diagnostics.all_loaded_files = loader.all_files().cloned().collect();
+    if let Some(translations) = doc.root_component.translations.borrow().as_ref() {
+        diagnostics.all_loaded_files.extend(translations.files.iter().cloned());
+    }
 
     (doc, diagnostics)
 }
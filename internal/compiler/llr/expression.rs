@@ -159,7 +159,7 @@ pub enum Expression {
         repeater_index: Option<Box<Expression>>,
     },
     /// Will call the sub_expression, with the cell variable set to the
-    /// array of BoxLayoutCellData from the elements
+    /// array of BoxLayoutCellData (or FlexboxLayoutCellData) from the elements
     BoxLayoutFunction {
         /// The local variable (as read with [`Self::ReadLocalVariable`]) that contains the sell
         cells_variable: String,
@@ -168,6 +168,8 @@ pub enum Expression {
         /// Either an expression of type BoxLayoutCellData, or an index to the repeater
         elements: Vec<Either<Expression, u32>>,
         orientation: Orientation,
+        /// When true, the cells are of type FlexboxLayoutCellData instead of BoxLayoutCellData
+        flexbox: bool,
         sub_expression: Box<Expression>,
     },
 
@@ -128,6 +128,15 @@ pub struct ListViewInfo {
     pub prop_height: PropertyReference,
 }
 
+#[derive(Debug, Clone, Default)]
+/// The `flex-grow`, `flex-shrink` and `flex-basis` properties of the root element of the
+/// repeated component, if they are set. (in the repeated component context)
+pub struct FlexboxItemInfo {
+    pub grow: Option<PropertyReference>,
+    pub shrink: Option<PropertyReference>,
+    pub basis: Option<PropertyReference>,
+}
+
 #[derive(Debug)]
 pub struct RepeatedElement {
     pub model: MutExpression,
@@ -140,6 +149,9 @@ pub struct RepeatedElement {
     pub index_in_tree: u32,
 
     pub listview: Option<ListViewInfo>,
+
+    /// Used when the repeated element is in a FlexboxLayout
+    pub flexbox_item: FlexboxItemInfo,
 }
 
 #[derive(Clone, Debug)]
@@ -278,6 +290,9 @@ pub struct SubComponent {
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
     pub init_code: Vec<MutExpression>,
+    /// The `changed <property> => { ... }` handlers: the tracked property and the code to run
+    /// when its value changes
+    pub change_callbacks: Vec<(PropertyReference, MutExpression)>,
 
     /// For each node, an expression that returns a `{x: length, y: length, width: length, height: length}`
     pub geometries: Vec<Option<MutExpression>>,
@@ -288,6 +303,10 @@ pub struct SubComponent {
     /// Maps (item_index, property) to an expression
     pub accessible_prop: BTreeMap<(u32, String), MutExpression>,
 
+    /// Maps item_index to the `Component::id` of the elements, separated by newlines.
+    /// Only filled when the debug info are enabled.
+    pub element_infos: BTreeMap<u32, String>,
+
     pub prop_analysis: HashMap<PropertyReference, PropAnalysis>,
 }
 
@@ -356,6 +375,8 @@ pub struct PublicComponent {
     pub item_tree: ItemTree,
     pub sub_components: Vec<Rc<SubComponent>>,
     pub globals: Vec<GlobalComponent>,
+    /// The translations to embed in the generated code, if any
+    pub translations: Option<Rc<crate::translations::Translations>>,
 }
 
 impl PublicComponent {
@@ -397,6 +418,9 @@ pub fn for_each_expression<'a>(
             for e in &sc.init_code {
                 visitor(e, ctx);
             }
+            for (_, e) in &sc.change_callbacks {
+                visitor(e, ctx);
+            }
             for (_, e) in &sc.property_init {
                 visitor(&e.expression, ctx);
             }
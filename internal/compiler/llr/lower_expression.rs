@@ -11,7 +11,7 @@
 use super::lower_to_item_tree::{LoweredElement, LoweredSubComponentMapping, LoweringState};
 use super::{Animation, PropertyReference};
 use crate::expression_tree::{BuiltinFunction, Expression as tree_Expression};
-use crate::langtype::{EnumerationValue, Type};
+use crate::langtype::{Enumeration, EnumerationValue, Type};
 use crate::layout::Orientation;
 use crate::llr::Expression as llr_Expression;
 use crate::namedreference::NamedReference;
@@ -117,11 +117,7 @@ pub fn lower_expression(
                 lower_show_popup(arguments, ctx)
             }
             tree_Expression::BuiltinFunctionReference(BuiltinFunction::ClosePopupWindow, _) => {
-                // FIXME: right now, `popup.close()` will close any visible popup, as the popup argument is ignored
-                llr_Expression::BuiltinFunctionCall {
-                    function: BuiltinFunction::ClosePopupWindow,
-                    arguments: vec![],
-                }
+                lower_close_popup(arguments, ctx)
             }
             tree_Expression::BuiltinFunctionReference(f, _) => {
                 let mut arguments =
@@ -348,45 +344,65 @@ fn repeater_special_property(
     llr_Expression::PropertyReference(r)
 }
 
+/// Call `f` with the index of the popup in the `popup_windows` of the component containing it,
+/// and the popup itself
+fn with_popup_window<R>(
+    popup_window: &ElementRc,
+    f: impl FnOnce(usize, &crate::object_tree::PopupWindow) -> R,
+) -> R {
+    let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+    let parent_component =
+        pop_comp.parent_element.upgrade().unwrap().borrow().enclosing_component.upgrade().unwrap();
+    let popup_list = parent_component.popup_windows.borrow();
+    let (popup_index, popup) =
+        popup_list.iter().enumerate().find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp)).unwrap();
+    f(popup_index, popup)
+}
+
 fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
     if let [tree_Expression::ElementReference(e)] = args {
-        let popup_window = e.upgrade().unwrap();
-        let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
-        let parent_component = pop_comp
-            .parent_element
-            .upgrade()
-            .unwrap()
-            .borrow()
-            .enclosing_component
-            .upgrade()
-            .unwrap();
-        let popup_list = parent_component.popup_windows.borrow();
-        let (popup_index, popup) = popup_list
-            .iter()
-            .enumerate()
-            .find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp))
-            .unwrap();
-        let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
-        let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
-        let item_ref = lower_expression(
-            &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
-            ctx,
-        );
-        llr_Expression::BuiltinFunctionCall {
-            function: BuiltinFunction::ShowPopupWindow,
-            arguments: vec![
-                llr_Expression::NumberLiteral(popup_index as _),
-                x,
-                y,
-                llr_Expression::BoolLiteral(popup.close_on_click),
-                item_ref,
-            ],
-        }
+        with_popup_window(&e.upgrade().unwrap(), |popup_index, popup| {
+            let x = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.x));
+            let y = llr_Expression::PropertyReference(ctx.map_property_reference(&popup.y));
+            let item_ref = lower_expression(
+                &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
+                ctx,
+            );
+            llr_Expression::BuiltinFunctionCall {
+                function: BuiltinFunction::ShowPopupWindow,
+                arguments: vec![
+                    llr_Expression::NumberLiteral(popup_index as _),
+                    x,
+                    y,
+                    llr_Expression::BoolLiteral(popup.close_on_click),
+                    llr_Expression::EnumerationValue(popup.placement.clone()),
+                    item_ref,
+                ],
+            }
+        })
     } else {
         panic!("invalid arguments to ShowPopupWindow");
     }
 }
 
+fn lower_close_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
+    if let [tree_Expression::ElementReference(e)] = args {
+        with_popup_window(&e.upgrade().unwrap(), |popup_index, popup| {
+            // The parent item is only used to find the component that holds the id of the popup
+            let item_ref = lower_expression(
+                &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
+                ctx,
+            );
+            llr_Expression::BuiltinFunctionCall {
+                function: BuiltinFunction::ClosePopupWindow,
+                arguments: vec![llr_Expression::NumberLiteral(popup_index as _), item_ref],
+            }
+        })
+    } else {
+        panic!("invalid arguments to ClosePopupWindow");
+    }
+}
+
 pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> Animation {
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
@@ -515,11 +531,13 @@ fn compute_layout_info(
                     repeater_indices: None,
                     elements,
                     orientation: o,
+                    flexbox: false,
                     sub_expression: Box::new(sub_expression),
                 },
                 None => sub_expression,
             }
         }
+        crate::layout::Layout::FlexboxLayout(layout) => flexbox_layout(layout, o, false, ctx),
     }
 }
 
@@ -619,6 +637,7 @@ fn solve_layout(
                     repeater_indices: Some("repeated_indices".into()),
                     elements,
                     orientation: o,
+                    flexbox: false,
                     sub_expression: Box::new(llr_Expression::ExtraBuiltinFunctionCall {
                         function: "solve_box_layout".into(),
                         arguments: vec![
@@ -645,6 +664,7 @@ fn solve_layout(
                 },
             }
         }
+        crate::layout::Layout::FlexboxLayout(layout) => flexbox_layout(layout, o, true, ctx),
     }
 }
 
@@ -730,6 +750,210 @@ fn box_layout_data(
     }
 }
 
+/// Compute the layout info of the FlexboxLayout (or solve it, if `solve` is true) along the given orientation
+fn flexbox_layout(
+    layout: &crate::layout::FlexboxLayout,
+    o: Orientation,
+    solve: bool,
+    ctx: &ExpressionContext,
+) -> llr_Expression {
+    let main_axis = layout.main_axis;
+    let cross_axis = match main_axis {
+        Orientation::Horizontal => Orientation::Vertical,
+        Orientation::Vertical => Orientation::Horizontal,
+    };
+    let is_main = o == main_axis;
+    let (main_padding, spacing) =
+        generate_layout_padding_and_spacing(&layout.geometry, main_axis, ctx);
+    let (cross_padding, _) = generate_layout_padding_and_spacing(&layout.geometry, cross_axis, ctx);
+
+    let enum_property = |nr: &Option<NamedReference>, enumeration: Rc<Enumeration>| match nr {
+        Some(nr) => llr_Expression::PropertyReference(ctx.map_property_reference(nr)),
+        None => llr_Expression::EnumerationValue(EnumerationValue {
+            value: enumeration.default_value,
+            enumeration,
+        }),
+    };
+    let (wrap_enum, alignment_enum) = crate::typeregister::BUILTIN_ENUMS
+        .with(|e| (e.FlexWrap.clone(), e.LayoutAlignment.clone()));
+
+    let has_repeater = layout.elems.iter().any(|i| i.element.borrow().repeated.is_some());
+    // Return the cells, and the elements for the BoxLayoutFunction if there are repeaters
+    let make_cells =
+        |cells_variable: &str,
+         name: &str,
+         fields: BTreeMap<String, Type>,
+         cell: &dyn Fn(&crate::layout::LayoutItem) -> llr_Expression| {
+            let element_ty =
+                Type::Struct { fields, name: Some(name.into()), node: None, rust_attributes: None };
+            if !has_repeater {
+                let values = layout.elems.iter().map(cell).collect();
+                return (llr_Expression::Array { element_ty, values, as_model: false }, None);
+            }
+            let elements = layout
+                .elems
+                .iter()
+                .map(|item| {
+                    if item.element.borrow().repeated.is_some() {
+                        match ctx.mapping.element_mapping.get(&item.element.clone().into()).unwrap()
+                        {
+                            LoweredElement::Repeated { repeated_index } => {
+                                Either::Right(*repeated_index)
+                            }
+                            _ => panic!(),
+                        }
+                    } else {
+                        Either::Left(cell(item))
+                    }
+                })
+                .collect();
+            let cells = llr_Expression::ReadLocalVariable {
+                name: cells_variable.into(),
+                ty: Type::Array(Box::new(element_ty)),
+            };
+            (cells, Some(elements))
+        };
+
+    let flex_value = |nr: &Option<NamedReference>, default: f64| match nr {
+        Some(nr) => llr_Expression::PropertyReference(ctx.map_property_reference(nr)),
+        None => llr_Expression::NumberLiteral(default),
+    };
+    let (cells, main_elements) = make_cells(
+        "cells",
+        "FlexboxLayoutCellData",
+        IntoIterator::into_iter([
+            ("constraint".to_string(), crate::layout::layout_info_type()),
+            ("grow".to_string(), Type::Float32),
+            ("shrink".to_string(), Type::Float32),
+            ("basis".to_string(), Type::Float32),
+        ])
+        .collect(),
+        &|item: &crate::layout::LayoutItem| {
+            let layout_info = get_layout_info(&item.element, ctx, &item.constraints, main_axis);
+            make_struct(
+                "FlexboxLayoutCellData".into(),
+                [
+                    ("constraint", crate::layout::layout_info_type(), layout_info),
+                    ("grow", Type::Float32, flex_value(&item.constraints.flex_grow, 0.)),
+                    ("shrink", Type::Float32, flex_value(&item.constraints.flex_shrink, 1.)),
+                    ("basis", Type::Float32, flex_value(&item.constraints.flex_basis, -1.)),
+                ],
+            )
+        },
+    );
+    let box_cell_fields: BTreeMap<String, Type> =
+        IntoIterator::into_iter([("constraint".to_string(), crate::layout::layout_info_type())])
+            .collect();
+    // The cells along the cross axis are not needed for the main axis.
+    let (cross_cells, cross_elements) = if is_main {
+        let element_ty = Type::Struct {
+            fields: box_cell_fields,
+            name: Some("BoxLayoutCellData".into()),
+            node: None,
+            rust_attributes: None,
+        };
+        (llr_Expression::Array { element_ty, values: vec![], as_model: false }, None)
+    } else {
+        make_cells(
+            "cross_cells",
+            "BoxLayoutCellData",
+            box_cell_fields,
+            &|item: &crate::layout::LayoutItem| {
+                let layout_info =
+                    get_layout_info(&item.element, ctx, &item.constraints, cross_axis);
+                make_struct(
+                    "BoxLayoutCellData".into(),
+                    [("constraint", crate::layout::layout_info_type(), layout_info)],
+                )
+            },
+        )
+    };
+
+    // Only read the sizes that are needed, to avoid dependency loops
+    let main_size = if is_main && !solve {
+        llr_Expression::NumberLiteral(0.)
+    } else {
+        layout_geometry_size(&layout.geometry.rect, main_axis, ctx)
+    };
+    let cross_size = if !is_main && solve {
+        layout_geometry_size(&layout.geometry.rect, cross_axis, ctx)
+    } else {
+        llr_Expression::NumberLiteral(0.)
+    };
+
+    let data = make_struct(
+        "FlexboxLayoutData".into(),
+        [
+            ("main_size", Type::Float32, main_size),
+            ("cross_size", Type::Float32, cross_size),
+            ("spacing", Type::Float32, spacing),
+            ("main_padding", main_padding.ty(ctx), main_padding),
+            ("cross_padding", cross_padding.ty(ctx), cross_padding),
+            (
+                "direction",
+                Type::Enumeration(layout.direction.enumeration.clone()),
+                llr_Expression::EnumerationValue(layout.direction.clone()),
+            ),
+            ("wrap", Type::Enumeration(wrap_enum.clone()), enum_property(&layout.wrap, wrap_enum)),
+            (
+                "justify_content",
+                Type::Enumeration(alignment_enum.clone()),
+                enum_property(&layout.justify_content, alignment_enum.clone()),
+            ),
+            (
+                "align_content",
+                Type::Enumeration(alignment_enum.clone()),
+                enum_property(&layout.align_content, alignment_enum),
+            ),
+            ("cells", cells.ty(ctx), cells),
+            ("cross_cells", cross_cells.ty(ctx), cross_cells),
+        ],
+    );
+
+    let mut arguments = vec![data];
+    let (function, return_ty) = if solve {
+        arguments.push(if has_repeater {
+            llr_Expression::ReadLocalVariable {
+                name: "repeated_indices".into(),
+                ty: Type::Array(Type::Int32.into()),
+            }
+        } else {
+            llr_Expression::Array { element_ty: Type::Int32, values: vec![], as_model: false }
+        });
+        let f = if is_main { "solve_flexbox_layout" } else { "solve_flexbox_layout_cross" };
+        (f, Type::LayoutCache)
+    } else {
+        let f = if is_main { "flexbox_layout_info" } else { "flexbox_layout_info_cross" };
+        (f, crate::layout::layout_info_type())
+    };
+    let mut expr = llr_Expression::ExtraBuiltinFunctionCall {
+        function: function.into(),
+        arguments,
+        return_ty,
+    };
+    if let Some(elements) = main_elements {
+        expr = llr_Expression::BoxLayoutFunction {
+            cells_variable: "cells".into(),
+            repeater_indices: solve.then(|| "repeated_indices".into()),
+            elements,
+            orientation: main_axis,
+            flexbox: true,
+            sub_expression: Box::new(expr),
+        };
+    }
+    if let Some(elements) = cross_elements {
+        expr = llr_Expression::BoxLayoutFunction {
+            cells_variable: "cross_cells".into(),
+            repeater_indices: None,
+            elements,
+            orientation: cross_axis,
+            flexbox: false,
+            sub_expression: Box::new(expr),
+        };
+    }
+    expr
+}
+
 fn grid_layout_cell_data(
     layout: &crate::layout::GridLayout,
     orientation: Orientation,
@@ -46,6 +46,7 @@ pub fn lower_to_item_tree(component: &Rc<Component>) -> PublicComponent {
             .collect(),
         public_properties,
         private_properties: component.private_properties.borrow().clone(),
+        translations: component.translations.borrow().clone(),
     };
     super::optim_passes::run_passes(&root);
     root
@@ -193,11 +194,13 @@ fn lower_sub_component(
         two_way_bindings: Default::default(),
         const_properties: Default::default(),
         init_code: Default::default(),
+        change_callbacks: Default::default(),
         geometries: Default::default(),
         // just initialize to dummy expression right now and it will be set later
         layout_info_h: super::Expression::BoolLiteral(false).into(),
         layout_info_v: super::Expression::BoolLiteral(false).into(),
         accessible_prop: Default::default(),
+        element_infos: Default::default(),
         prop_analysis: Default::default(),
     };
     let mut mapping = LoweredSubComponentMapping::default();
@@ -307,6 +310,11 @@ fn lower_sub_component(
             }
             _ => unreachable!(),
         };
+        if !elem.qualified_ids.is_empty() {
+            sub_component
+                .element_infos
+                .insert(*elem.item_index.get().unwrap(), elem.qualified_ids.join("\n"));
+        }
         for (key, nr) in &elem.accessibility_props.0 {
             // TODO: we also want to split by type (role/string/...)
             let enum_value =
@@ -419,6 +427,15 @@ fn lower_sub_component(
         .map(|e| super::lower_expression::lower_expression(e, &ctx).into())
         .collect();
 
+    crate::object_tree::recurse_elem(&component.root_element, &(), &mut |element, _| {
+        for (nr, handler) in &element.borrow().change_callbacks {
+            sub_component.change_callbacks.push((
+                ctx.map_property_reference(nr),
+                super::lower_expression::lower_expression(handler, &ctx).into(),
+            ));
+        }
+    });
+
     sub_component.layout_info_h = super::lower_expression::get_layout_info(
         &component.root_element,
         &ctx,
@@ -540,6 +557,18 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
         prop_height: map_inner_prop("height"),
     });
 
+    let flexbox_item = {
+        let root_constraints = component.root_constraints.borrow();
+        let map_flex_prop = |nr: &Option<NamedReference>| {
+            nr.as_ref().map(|nr| sc.mapping.map_property_reference(nr, ctx.state))
+        };
+        FlexboxItemInfo {
+            grow: map_flex_prop(&root_constraints.flex_grow),
+            shrink: map_flex_prop(&root_constraints.flex_shrink),
+            basis: map_flex_prop(&root_constraints.flex_basis),
+        }
+    };
+
     RepeatedElement {
         model: super::lower_expression::lower_expression(&repeated.model, ctx).into(),
         sub_tree: ItemTree {
@@ -551,6 +580,7 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
         data_prop: (!repeated.is_conditional_element).then_some(0),
         index_in_tree: *e.item_index.get().unwrap(),
         listview,
+        flexbox_item,
     }
 }
 
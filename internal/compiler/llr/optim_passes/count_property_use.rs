@@ -54,10 +54,14 @@ pub fn count_property_use(root: &PublicComponent) {
             expr.use_count.set(c + 1);
             expr.expression.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
         }
-        // 3. the init code
+        // 3. the init code and the change callbacks
         for expr in &sc.init_code {
             expr.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
         }
+        for (prop, expr) in &sc.change_callbacks {
+            visit_property(prop, ctx);
+            expr.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
+        }
         // 4. the models
         for (idx, r) in sc.repeated.iter().enumerate() {
             r.model.borrow().visit_recursive(&mut |e| visit_expression(e, ctx));
@@ -78,6 +82,16 @@ pub fn count_property_use(root: &PublicComponent) {
                 visit_property(&lv.prop_width, &rep_ctx);
                 visit_property(&lv.prop_height, &rep_ctx);
             }
+            let flex = &r.flexbox_item;
+            for p in flex.grow.iter().chain(&flex.shrink).chain(&flex.basis) {
+                let rep_ctx = EvaluationContext::new_sub_component(
+                    root,
+                    &r.sub_tree.root,
+                    (),
+                    Some(ParentCtx::new(ctx, Some(idx as u32))),
+                );
+                visit_property(p, &rep_ctx);
+            }
             for idx in r.data_prop.iter().chain(r.index_prop.iter()) {
                 // prevent optimizing model properties
                 let p = &r.sub_tree.root.properties[*idx];
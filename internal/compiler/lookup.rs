@@ -402,7 +402,9 @@ fn for_each_entry<R>(
             }
         }
         if !matches!(self.borrow().base_type, ElementType::Global) {
-            for (name, ty) in crate::typeregister::reserved_properties() {
+            let class_name =
+                self.borrow().native_class().map(|n| n.class_name.clone()).unwrap_or_default();
+            for (name, ty) in crate::typeregister::reserved_properties_for_class(&class_name) {
                 let e = expression_from_reference(
                     NamedReference::new(self, name),
                     &ty,
@@ -581,7 +583,10 @@ fn for_each_entry<R>(
         ctx: &LookupCtx,
         f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
     ) -> Option<R> {
-        use EasingCurve::CubicBezier;
+        use EasingCurve::{
+            CubicBezier, EaseInBounce, EaseInElastic, EaseInOutBounce, EaseInOutElastic,
+            EaseOutBounce, EaseOutElastic,
+        };
         None.or_else(|| f("linear", Expression::EasingCurve(EasingCurve::Linear).into()))
             .or_else(|| {
                 f("ease", Expression::EasingCurve(CubicBezier(0.25, 0.1, 0.25, 1.0)).into())
@@ -595,6 +600,12 @@ fn for_each_entry<R>(
             .or_else(|| {
                 f("ease-out", Expression::EasingCurve(CubicBezier(0.0, 0.0, 0.58, 1.0)).into())
             })
+            .or_else(|| f("ease-in-elastic", Expression::EasingCurve(EaseInElastic).into()))
+            .or_else(|| f("ease-out-elastic", Expression::EasingCurve(EaseOutElastic).into()))
+            .or_else(|| f("ease-in-out-elastic", Expression::EasingCurve(EaseInOutElastic).into()))
+            .or_else(|| f("ease-in-bounce", Expression::EasingCurve(EaseInBounce).into()))
+            .or_else(|| f("ease-out-bounce", Expression::EasingCurve(EaseOutBounce).into()))
+            .or_else(|| f("ease-in-out-bounce", Expression::EasingCurve(EaseInOutBounce).into()))
             .or_else(|| {
                 f(
                     "cubic-bezier",
@@ -605,6 +616,26 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "steps",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Steps,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
     }
 }
 
@@ -9,7 +9,7 @@
 
 use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
 use crate::expression_tree::{self, BindingExpression, Expression, Unit};
-use crate::langtype::{BuiltinElement, Enumeration, NativeClass, Type};
+use crate::langtype::{BuiltinElement, Enumeration, EnumerationValue, NativeClass, Type};
 use crate::langtype::{ElementType, PropertyLookupResult};
 use crate::layout::{LayoutConstraints, Orientation};
 use crate::namedreference::NamedReference;
@@ -247,6 +247,7 @@ pub struct PopupWindow {
     pub x: NamedReference,
     pub y: NamedReference,
     pub close_on_click: bool,
+    pub placement: EnumerationValue,
     pub parent_element: ElementRc,
 }
 
@@ -315,6 +316,10 @@ pub struct Component {
     pub embedded_file_resources:
         RefCell<HashMap<String, crate::embedded_resources::EmbeddedResources>>,
 
+    /// The translations to embed in the generated code, when bundling the translations.
+    /// (This only make sense on the root component)
+    pub translations: RefCell<Option<Rc<crate::translations::Translations>>>,
+
     /// The layout constraints of the root item
     pub root_constraints: RefCell<LayoutConstraints>,
 
@@ -604,6 +609,8 @@ pub struct Element {
 
     pub states: Vec<State>,
     pub transitions: Vec<Transition>,
+    /// The `changed <property> => { ... }` handlers, with a reference to the property they track
+    pub change_callbacks: Vec<(NamedReference, Expression)>,
 
     /// true when this item's geometry is handled by a layout
     pub child_of_layout: bool,
@@ -639,6 +646,10 @@ pub struct Element {
 
     /// The AST node, if available
     pub node: Option<syntax_nodes::Element>,
+
+    /// The `Component::id` of this element and of the elements that were inlined into it,
+    /// as written in the source.
+    pub qualified_ids: Vec<String>,
 }
 
 impl Spanned for Element {
@@ -720,6 +731,12 @@ macro_rules! indent {
         indent!();
         writeln!(f, "transitions {:?} ", e.transitions)?;
     }
+    for (nr, expr) in &e.change_callbacks {
+        indent!();
+        write!(f, "changed {:?} => ", nr)?;
+        expression_tree::pretty_print(f, expr)?;
+        writeln!(f)?;
+    }
     for c in &e.children {
         indent!();
         pretty_print(f, &c.borrow(), indentation)?
@@ -1257,6 +1274,41 @@ pub fn from_node(
         let mut children_placeholder = None;
         let r = r.make_rc();
 
+        for ch in node.PropertyChangedCallback() {
+            let unresolved_name =
+                unwrap_or_continue!(parser::identifier_text(&ch.DeclaredIdentifier()); diag);
+            let lookup_result = r.borrow().lookup_property(&unresolved_name);
+            if !lookup_result.is_valid() {
+                diag.push_error(
+                    format!("Property '{unresolved_name}' does not exist"),
+                    &ch.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            if !lookup_result.property_type.is_property_type() {
+                diag.push_error(
+                    format!("Change callback can only be set on properties, and '{unresolved_name}' is not a property"),
+                    &ch.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            if lookup_result.property_visibility == PropertyVisibility::Private
+                && !lookup_result.is_local_to_component
+            {
+                diag.push_error(
+                    format!("Change callback on a private property '{unresolved_name}'"),
+                    &ch.DeclaredIdentifier(),
+                );
+                continue;
+            }
+            if matches!(r.borrow().base_type, ElementType::Global) {
+                diag.push_error("Change callbacks are not supported in globals".into(), &ch);
+                continue;
+            }
+            let nr = NamedReference::new(&r, &lookup_result.resolved_name);
+            r.borrow_mut().change_callbacks.push((nr, Expression::Uncompiled(ch.into())));
+        }
+
         for se in node.children() {
             if se.kind() == SyntaxKind::SubElement {
                 let parent_type = r.borrow().base_type.clone();
@@ -1985,8 +2037,25 @@ pub fn recurse_elem_including_sub_components_no_borrow<State>(
 /// This code will temporarily move the bindings or states member so it can call the visitor without
 /// maintaining a borrow on the RefCell.
 pub fn visit_element_expressions(
+    elem: &ElementRc,
+    vis: impl FnMut(&mut Expression, Option<&str>, &dyn Fn() -> Type),
+) {
+    visit_element_expressions_impl(elem, vis, true)
+}
+
+/// Same as [`visit_element_expressions`], but the expressions of the `changed` callbacks are not
+/// visited.
+pub fn visit_element_expressions_except_change_callbacks(
+    elem: &ElementRc,
+    vis: impl FnMut(&mut Expression, Option<&str>, &dyn Fn() -> Type),
+) {
+    visit_element_expressions_impl(elem, vis, false)
+}
+
+fn visit_element_expressions_impl(
     elem: &ElementRc,
     mut vis: impl FnMut(&mut Expression, Option<&str>, &dyn Fn() -> Type),
+    visit_change_callbacks: bool,
 ) {
     fn visit_element_expressions_simple(
         elem: &ElementRc,
@@ -2038,6 +2107,14 @@ fn visit_element_expressions_simple(
     }
     elem.borrow_mut().transitions = transitions;
 
+    if visit_change_callbacks {
+        let mut change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+        for (_, e) in &mut change_callbacks {
+            vis(e, None, &|| Type::Void);
+        }
+        elem.borrow_mut().change_callbacks = change_callbacks;
+    }
+
     let component = elem.borrow().enclosing_component.upgrade().unwrap();
     if Rc::ptr_eq(&component.root_element, elem) {
         for e in component.init_code.borrow_mut().iter_mut() {
@@ -2095,6 +2172,11 @@ pub fn visit_all_named_references_in_element(
         }
     }
     elem.borrow_mut().transitions = transitions;
+    let mut change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+    for (r, _) in &mut change_callbacks {
+        vis(r);
+    }
+    elem.borrow_mut().change_callbacks = change_callbacks;
     let mut repeated = std::mem::take(&mut elem.borrow_mut().repeated);
     if let Some(r) = &mut repeated {
         if let Some(lv) = &mut r.is_listview {
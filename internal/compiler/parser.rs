@@ -331,7 +331,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
                      *CallbackDeclaration, *Function, *SubElement, *RepeatedElement, *PropertyAnimation,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *PropertyChangedCallback, *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
@@ -341,6 +341,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `-> type`  (but without the ->)
         ReturnType -> [Type],
         CallbackConnection -> [ *DeclaredIdentifier,  CodeBlock ],
+        /// `changed <property> => { ... }`
+        PropertyChangedCallback -> [ DeclaredIdentifier, CodeBlock ],
         /// Declaration of a property.
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
@@ -32,6 +32,7 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// for xx in model: Sub {}
 /// if condition : Sub {}
 /// clicked => {}
+/// changed width => {}
 /// callback foobar;
 /// property<int> width;
 /// animate someProp { }
@@ -55,6 +56,12 @@ pub fn parse_element_content(p: &mut impl Parser) {
                 SyntaxKind::Identifier if p.peek().as_str() == "for" => {
                     parse_repeated_element(&mut *p);
                 }
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "changed"
+                        && p.nth(2).kind() == SyntaxKind::FatArrow =>
+                {
+                    parse_changed_callback(&mut *p);
+                }
                 SyntaxKind::Identifier
                     if p.peek().as_str() == "callback"
                         || (p.peek().as_str() == "pure" && p.nth(1).as_str() == "callback") =>
@@ -274,6 +281,23 @@ fn parse_callback_connection(p: &mut impl Parser) {
     parse_code_block(&mut *p);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,PropertyChangedCallback
+/// changed the-property => {}
+/// changed foo => { bar; goo; }
+/// ```
+fn parse_changed_callback(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::PropertyChangedCallback);
+    debug_assert_eq!(p.peek().as_str(), "changed");
+    p.expect(SyntaxKind::Identifier); // "changed"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,TwoWayBinding
 /// foo <=> bar;
@@ -39,6 +39,7 @@
 pub mod move_declarations;
 mod optimize_useless_rectangles;
 mod purity_check;
+mod qualified_ids;
 mod remove_aliases;
 mod remove_return;
 mod remove_unused_properties;
@@ -86,6 +87,9 @@ pub async fn run_passes(
     for component in (root_component.used_types.borrow().sub_components.iter())
         .chain(std::iter::once(root_component))
     {
+        if compiler_config.debug_info {
+            qualified_ids::record_qualified_ids(component);
+        }
         compile_paths::compile_paths(
             component,
             &doc.local_registry,
@@ -154,6 +158,7 @@ pub async fn run_passes(
             crate::typeregister::RESERVED_ROTATION_PROPERTIES[0].0,
             crate::typeregister::RESERVED_ROTATION_PROPERTIES[1..]
                 .iter()
+                .chain(crate::typeregister::RESERVED_SCALE_PROPERTIES)
                 .map(|(prop_name, _)| *prop_name),
             Some(&|e, prop| Expression::BinaryExpression {
                 lhs: Expression::PropertyReference(NamedReference::new(
@@ -162,6 +167,12 @@ pub async fn run_passes(
                         "rotation-origin-x" => "width",
                         "rotation-origin-y" => "height",
                         "rotation-angle" => return Expression::Invalid,
+                        "scale-x" | "scale-y" => {
+                            return Expression::NumberLiteral(
+                                1.,
+                                crate::expression_tree::Unit::None,
+                            )
+                        }
                         _ => unreachable!(),
                     },
                 ))
@@ -224,6 +235,12 @@ pub async fn run_passes(
 
     remove_return::remove_return(doc);
 
+    if let Some(path) = &compiler_config.translation_path_bundle {
+        let domain = compiler_config.translation_domain.as_deref().unwrap_or_default();
+        let translations = crate::translations::load_translations(path, domain, diag);
+        *root_component.translations.borrow_mut() = Some(Rc::new(translations));
+    }
+
     embed_images::embed_images(
         root_component,
         compiler_config.embed_resources,
@@ -248,6 +265,9 @@ pub async fn run_passes(
                 );
                 embed_glyphs::scan_string_literals(component, &mut characters_seen);
             }
+            if let Some(translations) = root_component.translations.borrow().as_ref() {
+                characters_seen.extend(translations.all_strings().flat_map(|s| s.chars()));
+            }
 
             embed_glyphs::embed_glyphs(
                 root_component,
@@ -173,6 +173,13 @@ fn analyze_element(
         }
     }
 
+    for (nr, handler) in &elem.borrow().change_callbacks {
+        process_property(&nr.clone().into(), context, reverse_aliases, diag);
+        recurse_expression(handler, &mut |prop| {
+            process_property(prop, context, reverse_aliases, diag);
+        });
+    }
+
     if let Some(repeated) = &elem.borrow().repeated {
         recurse_expression(&repeated.model, &mut |prop| {
             process_property(prop, context, reverse_aliases, diag);
@@ -388,6 +395,25 @@ fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&PropertyPath)) {
                 crate::layout::Layout::BoxLayout(l) => {
                     visit_layout_items_dependencies(l.elems.iter(), *o, vis)
                 }
+                crate::layout::Layout::FlexboxLayout(l) => {
+                    visit_layout_items_dependencies(l.elems.iter(), l.main_axis, vis);
+                    if *o != l.main_axis {
+                        // How the items wrap depends on the size along the main axis
+                        if let Some(nr) = l.geometry.rect.size_reference(l.main_axis) {
+                            vis(&nr.clone().into());
+                        }
+                        visit_layout_items_dependencies(l.elems.iter(), *o, vis);
+                    }
+                    for it in &l.elems {
+                        for (nr, _) in it.constraints.for_each_flex_property() {
+                            vis(&nr.clone().into())
+                        }
+                    }
+                    for nr in [&l.wrap, &l.justify_content, &l.align_content].into_iter().flatten()
+                    {
+                        vis(&nr.clone().into())
+                    }
+                }
             }
 
             let mut g = l.geometry().clone();
@@ -3,35 +3,47 @@
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::diagnostics::Spanned;
-use crate::langtype::ElementType;
+use crate::langtype::{ElementType, Type};
 use crate::object_tree::Element;
 
-/// Check that the rotation is only on Image
+/// Check that the rotation and the scale are only on Image, Rectangle and Text
 pub fn check_rotation(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
     for cmp in &doc.inner_components {
         crate::object_tree::recurse_elem_including_sub_components(cmp, &(), &mut |elem, _| {
             let e = elem.borrow();
-            if crate::typeregister::RESERVED_ROTATION_PROPERTIES
+            let builtin = e.builtin_type();
+            // Skip the properties of the same name declared in a component (the scale
+            // properties are not reserved in every element)
+            let mut transform_properties = crate::typeregister::RESERVED_ROTATION_PROPERTIES
                 .iter()
-                .any(|(property_name, _)| is_property_set(&e, property_name))
+                .chain(crate::typeregister::RESERVED_SCALE_PROPERTIES)
+                .map(|(property_name, _)| *property_name)
+                .filter(|property_name| {
+                    builtin.as_ref().map_or(false, |b| {
+                        ElementType::Builtin(b.clone()).lookup_property(property_name).property_type
+                            != Type::Invalid
+                    })
+                });
+            if !transform_properties.clone().any(|property_name| is_property_set(&e, property_name))
             {
-                if matches!(e.native_class(), Some(native) if native.class_name != "ClippedImage") {
-                    let span = e
-                        .bindings
-                        .get("rotation-angle")
-                        .and_then(|e| e.borrow().span.clone())
-                        .unwrap_or_else(|| e.to_source_location());
+                return;
+            }
+            if matches!(e.native_class(), Some(native) if !crate::typeregister::TRANSFORMABLE_CLASSES.contains(&native.class_name.as_str()))
+            {
+                let span = transform_properties
+                    .find_map(|property_name| e.bindings.get(property_name)?.borrow().span.clone())
+                    .unwrap_or_else(|| e.to_source_location());
 
-                    diag.push_error_with_span(
-                        "rotation properties can only be applied to the Image element".into(),
-                        span,
-                    );
-                } else if has_any_children(&e) {
-                    diag.push_error_with_span(
-                        "Elements with rotation properties cannot have children elements".into(),
-                        e.to_source_location(),
-                    );
-                }
+                diag.push_error_with_span(
+                    "transform properties can only be applied to the Image, Rectangle and Text elements"
+                        .into(),
+                    span,
+                );
+            } else if has_any_children(&e) {
+                diag.push_error_with_span(
+                    "Elements with transform properties cannot have children elements".into(),
+                    e.to_source_location(),
+                );
             }
         });
     }
@@ -42,6 +42,7 @@ pub fn ensure_window(
         repeated: Default::default(),
         states: Default::default(),
         transitions: Default::default(),
+        change_callbacks: Default::default(),
         child_of_layout: false,
         has_popup_child: false,
         layout_info_prop: Default::default(),
@@ -52,6 +53,7 @@ pub fn ensure_window(
         item_index: Default::default(),
         item_index_of_first_children: Default::default(),
         node: win_elem_mut.node.clone(),
+        qualified_ids: std::mem::take(&mut win_elem_mut.qualified_ids),
         inline_depth: 0,
         is_legacy_syntax: false,
     };
@@ -87,6 +87,9 @@ fn inline_element(
     elem_mut.property_declarations.extend(
         inlined_component.root_element.borrow().property_declarations.iter().map(clone_tuple),
     );
+    elem_mut
+        .qualified_ids
+        .extend(inlined_component.root_element.borrow().qualified_ids.iter().cloned());
 
     for (p, a) in inlined_component.root_element.borrow().property_analysis.borrow().iter() {
         elem_mut.property_analysis.borrow_mut().entry(p.clone()).or_default().merge_with_base(a);
@@ -164,6 +167,10 @@ fn inline_element(
         }
     }
 
+    elem_mut
+        .change_callbacks
+        .extend(inlined_component.root_element.borrow().change_callbacks.iter().cloned());
+
     if let Some(orig) = &inlined_component.root_element.borrow().layout_info_prop {
         if let Some(_new) = &mut elem_mut.layout_info_prop {
             todo!("Merge layout infos");
@@ -237,6 +244,7 @@ fn duplicate_element_with_mapping(
         repeated: elem.repeated.clone(),
         is_component_placeholder: elem.is_component_placeholder,
         node: elem.node.clone(),
+        qualified_ids: elem.qualified_ids.clone(),
         enclosing_component: Rc::downgrade(root_component),
         states: elem.states.clone(),
         transitions: elem
@@ -244,6 +252,7 @@ fn duplicate_element_with_mapping(
             .iter()
             .map(|t| duplicate_transition(t, mapping, root_component, priority_delta))
             .collect(),
+        change_callbacks: elem.change_callbacks.clone(),
         child_of_layout: elem.child_of_layout,
         layout_info_prop: elem.layout_info_prop.clone(),
         default_fill_parent: elem.default_fill_parent,
@@ -302,6 +311,7 @@ fn duplicate_sub_component(
                 .collect(),
         ),
         embedded_file_resources: component_to_duplicate.embedded_file_resources.clone(),
+        translations: component_to_duplicate.translations.clone(),
         root_constraints: component_to_duplicate.root_constraints.clone(),
         child_insertion_point: component_to_duplicate.child_insertion_point.clone(),
         init_code: component_to_duplicate.init_code.clone(),
@@ -347,6 +357,7 @@ fn duplicate_popup(
         x: p.x.clone(),
         y: p.y.clone(),
         close_on_click: p.close_on_click,
+        placement: p.placement.clone(),
         component: duplicate_sub_component(&p.component, &parent, mapping, priority_delta),
         parent_element: mapping
             .get(&element_key(p.parent_element.clone()))
@@ -443,6 +454,11 @@ fn fixup_element_references(
                     fxe(&mut e.element);
                 }
             }
+            crate::layout::Layout::FlexboxLayout(l) => {
+                for e in &mut l.elems {
+                    fxe(&mut e.element);
+                }
+            }
         },
         Expression::RepeaterModelReference { element }
         | Expression::RepeaterIndexReference { element } => fx(element),
@@ -9,6 +9,7 @@
 use crate::diagnostics::Spanned;
 use crate::expression_tree::*;
 use crate::langtype::ElementType;
+use crate::langtype::EnumerationValue;
 use crate::langtype::Type;
 use crate::layout::*;
 use crate::object_tree::*;
@@ -102,6 +103,7 @@ fn lower_element_layout(
         "GridLayout" => lower_grid_layout(component, elem, diag),
         "HorizontalLayout" => lower_box_layout(elem, diag, Orientation::Horizontal),
         "VerticalLayout" => lower_box_layout(elem, diag, Orientation::Vertical),
+        "FlexboxLayout" => lower_flexbox_layout(elem, diag),
         "Dialog" => {
             lower_dialog_layout(elem, style_metrics, diag);
             return; // the Dialog stays in the tree as a Dialog
@@ -126,7 +128,7 @@ fn lower_element_layout(
 }
 
 pub fn is_layout_element(element: &ElementRc) -> bool {
-    matches!(&element.borrow().base_type, ElementType::Builtin(n) if n.name == "GridLayout" || n.name == "HorizontalLayout" || n.name == "VerticalLayout")
+    matches!(&element.borrow().base_type, ElementType::Builtin(n) if n.name == "GridLayout" || n.name == "HorizontalLayout" || n.name == "VerticalLayout" || n.name == "FlexboxLayout")
 }
 
 fn lower_grid_layout(
@@ -422,6 +424,99 @@ fn lower_box_layout(
     layout_element.borrow_mut().layout_info_prop = Some((layout_info_prop_h, layout_info_prop_v));
 }
 
+fn lower_flexbox_layout(layout_element: &ElementRc, diag: &mut BuildDiagnostics) {
+    let direction_enum = crate::typeregister::BUILTIN_ENUMS.with(|e| e.FlexDirection.clone());
+    let mut direction =
+        EnumerationValue { value: direction_enum.default_value, enumeration: direction_enum };
+    if let Some(binding) = layout_element.borrow().bindings.get("direction") {
+        let binding = &*binding.borrow();
+        if let Expression::EnumerationValue(val) = &binding.expression {
+            direction = val.clone();
+        } else {
+            diag.push_error(
+                "The `direction` property of a FlexboxLayout must be known at compile-time".into(),
+                binding,
+            );
+        }
+    }
+    let mut layout = FlexboxLayout::new(layout_element, direction);
+
+    let layout_cache_prop_h = create_new_prop(layout_element, "layout-cache-h", Type::LayoutCache);
+    let layout_cache_prop_v = create_new_prop(layout_element, "layout-cache-v", Type::LayoutCache);
+    let layout_info_prop_h = create_new_prop(layout_element, "layoutinfo-h", layout_info_type());
+    let layout_info_prop_v = create_new_prop(layout_element, "layoutinfo-v", layout_info_type());
+
+    let layout_children = std::mem::take(&mut layout_element.borrow_mut().children);
+    for layout_child in &layout_children {
+        if let Some(item) = create_layout_item(layout_child, diag) {
+            let index = layout.elems.len() * 2;
+            let rep_idx = &item.repeater_index;
+            let actual_elem = &item.elem;
+            set_prop_from_cache(actual_elem, "x", &layout_cache_prop_h, index, rep_idx, diag);
+            if !item.item.constraints.fixed_width {
+                set_prop_from_cache(
+                    actual_elem,
+                    "width",
+                    &layout_cache_prop_h,
+                    index + 1,
+                    rep_idx,
+                    diag,
+                );
+            }
+            set_prop_from_cache(actual_elem, "y", &layout_cache_prop_v, index, rep_idx, diag);
+            if !item.item.constraints.fixed_height {
+                set_prop_from_cache(
+                    actual_elem,
+                    "height",
+                    &layout_cache_prop_v,
+                    index + 1,
+                    rep_idx,
+                    diag,
+                );
+            }
+            layout.elems.push(item.item);
+        }
+    }
+    layout_element.borrow_mut().children = layout_children;
+    let span = layout_element.borrow().to_source_location();
+    layout_cache_prop_h.element().borrow_mut().bindings.insert(
+        layout_cache_prop_h.name().into(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlexboxLayout(layout.clone()), Orientation::Horizontal),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_cache_prop_v.element().borrow_mut().bindings.insert(
+        layout_cache_prop_v.name().into(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlexboxLayout(layout.clone()), Orientation::Vertical),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_h.element().borrow_mut().bindings.insert(
+        layout_info_prop_h.name().into(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(
+                Layout::FlexboxLayout(layout.clone()),
+                Orientation::Horizontal,
+            ),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_v.element().borrow_mut().bindings.insert(
+        layout_info_prop_v.name().into(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(Layout::FlexboxLayout(layout), Orientation::Vertical),
+            span,
+        )
+        .into(),
+    );
+    layout_element.borrow_mut().layout_info_prop = Some((layout_info_prop_h, layout_info_prop_v));
+}
+
 fn lower_dialog_layout(
     dialog_element: &ElementRc,
     style_metrics: &Option<Rc<Component>>,
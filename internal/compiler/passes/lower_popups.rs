@@ -7,7 +7,7 @@
 use crate::expression_tree::{Expression, NamedReference};
 use crate::langtype::{ElementType, Type};
 use crate::object_tree::*;
-use crate::typeregister::TypeRegister;
+use crate::typeregister::{TypeRegister, BUILTIN_ENUMS};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -87,6 +87,24 @@ fn lower_popup_window(
             }
         };
 
+    let placement =
+        match popup_window_element.borrow_mut().bindings.remove("placement").map_or_else(
+            || Ok(BUILTIN_ENUMS.with(|e| e.PopupPlacement.clone().default_value())),
+            |binding| match &binding.borrow().expression {
+                Expression::EnumerationValue(value) => Ok(value.clone()),
+                _ => Err(binding.borrow().span.clone()),
+            },
+        ) {
+            Ok(placement) => placement,
+            Err(location) => {
+                diag.push_error(
+                    "The placement property only supports constants at the moment".into(),
+                    &location,
+                );
+                return;
+            }
+        };
+
     let popup_comp = Rc::new(Component {
         root_element: popup_window_element.clone(),
         parent_element: Rc::downgrade(parent_element),
@@ -124,6 +142,7 @@ fn lower_popup_window(
         x: coord_x,
         y: coord_y,
         close_on_click,
+        placement,
         parent_element: parent_element.clone(),
     });
 }
@@ -6,14 +6,14 @@
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{BindingExpression, Expression, NamedReference};
-use crate::langtype::Type;
+use crate::langtype::{ElementType, Type};
 use crate::object_tree::{self, Component, Element, ElementRc};
 use crate::typeregister::TypeRegister;
 use std::rc::Rc;
 
-/// If any element in `component` declares a binding to `property_name`, then a new
-/// element of type `element_name` is created, injected as a parent to the element and bindings
-/// to property_name and all properties in  extra_properties are mapped.
+/// If any element in `component` declares a binding to `property_name` or to one of the
+/// `extra_properties`, then a new element of type `element_name` is created, injected as a parent
+/// to the element and bindings to property_name and all properties in  extra_properties are mapped.
 /// Default value for the property extra_properties is queried with the `default_value_for_extra_properties`
 pub(crate) fn lower_property_to_element(
     component: &Rc<Component>,
@@ -24,14 +24,19 @@ pub(crate) fn lower_property_to_element(
     type_register: &TypeRegister,
     diag: &mut BuildDiagnostics,
 ) {
-    if let Some(b) = component.root_element.borrow().bindings.get(property_name) {
-        diag.push_warning(
-            format!(
-                "The {} property cannot be used on the root element, it will not be applied",
-                property_name
-            ),
-            &*b.borrow(),
-        );
+    for property_name in core::iter::once(property_name)
+        .chain(extra_properties.clone())
+        .filter(|name| is_builtin_property(&component.root_element, name))
+    {
+        if let Some(b) = component.root_element.borrow().bindings.get(property_name) {
+            diag.push_warning(
+                format!(
+                    "The {} property cannot be used on the root element, it will not be applied",
+                    property_name
+                ),
+                &*b.borrow(),
+            );
+        }
     }
 
     object_tree::recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
@@ -47,12 +52,17 @@ pub(crate) fn lower_property_to_element(
 
         let has_property_binding = |e: &ElementRc| {
             e.borrow().base_type.lookup_property(property_name).property_type != Type::Invalid
-                && (e.borrow().bindings.contains_key(property_name)
-                    || e.borrow()
-                        .property_analysis
-                        .borrow()
-                        .get(property_name)
-                        .map_or(false, |a| a.is_set))
+                && core::iter::once(property_name)
+                    .chain(extra_properties.clone())
+                    .filter(|name| is_builtin_property(e, name))
+                    .any(|name| {
+                        e.borrow().bindings.contains_key(name)
+                            || e.borrow()
+                                .property_analysis
+                                .borrow()
+                                .get(name)
+                                .map_or(false, |a| a.is_set)
+                    })
         };
 
         for mut child in old_children {
@@ -90,6 +100,14 @@ pub(crate) fn lower_property_to_element(
     });
 }
 
+/// Returns true if `name` is a property of the builtin element that `e` is based on (including
+/// the reserved properties), and not a property of the same name declared in a component.
+fn is_builtin_property(e: &ElementRc, name: &str) -> bool {
+    e.borrow().builtin_type().map_or(false, |b| {
+        ElementType::Builtin(b).lookup_property(name).property_type != Type::Invalid
+    })
+}
+
 fn create_property_element(
     child: &ElementRc,
     property_name: &'static str,
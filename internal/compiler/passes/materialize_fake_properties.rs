@@ -100,7 +100,12 @@ fn should_materialize(
     };
 
     if !has_declared_property {
-        let ty = crate::typeregister::reserved_property(prop).property_type;
+        // Look up through the base type to also find the properties that are only reserved
+        // for some native classes
+        let ty = match base_type.lookup_property(prop).property_type {
+            Type::Invalid => crate::typeregister::reserved_property(prop).property_type,
+            ty => ty,
+        };
         if ty != Type::Invalid {
             return Some(ty);
         }
@@ -133,6 +138,8 @@ pub fn initialize(elem: &ElementRc, name: &str) -> Option<Expression> {
         "preferred-width" => layout_constraint_prop(elem, "preferred", Orientation::Horizontal),
         "horizontal-stretch" => layout_constraint_prop(elem, "stretch", Orientation::Horizontal),
         "vertical-stretch" => layout_constraint_prop(elem, "stretch", Orientation::Vertical),
+        "flex-shrink" => Expression::NumberLiteral(1., Unit::None),
+        "flex-basis" => Expression::NumberLiteral(-1., Unit::Px),
         "opacity" => Expression::NumberLiteral(1., Unit::None),
         "visible" => Expression::BoolLiteral(true),
         _ => return None,
@@ -15,19 +15,23 @@ pub fn purity_check(doc: &crate::object_tree::Document, diag: &mut BuildDiagnost
                     true => crate::diagnostics::DiagnosticLevel::Warning,
                     false => crate::diagnostics::DiagnosticLevel::Error,
                 };
-                crate::object_tree::visit_element_expressions(elem, |expr, name, _| {
-                    if let Some(name) = name {
-                        let lookup = elem.borrow().lookup_property(name);
-                        if lookup.declared_pure.unwrap_or(false)
-                            || lookup.property_type.is_property_type()
-                        {
+                // `changed` callbacks may have side effects, like other callbacks
+                crate::object_tree::visit_element_expressions_except_change_callbacks(
+                    elem,
+                    |expr, name, _| {
+                        if let Some(name) = name {
+                            let lookup = elem.borrow().lookup_property(name);
+                            if lookup.declared_pure.unwrap_or(false)
+                                || lookup.property_type.is_property_type()
+                            {
+                                ensure_pure(expr, Some(diag), level);
+                            }
+                        } else {
+                            // model expression must be pure
                             ensure_pure(expr, Some(diag), level);
-                        }
-                    } else {
-                        // model expression must be pure
-                        ensure_pure(expr, Some(diag), level);
-                    };
-                })
+                        };
+                    },
+                );
             },
         )
     }
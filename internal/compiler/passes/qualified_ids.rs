@@ -0,0 +1,18 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Pass that records the ids of the elements as written in the source, before they get inlined
+//! and renamed, so that the testing API can find elements by id.
+
+use crate::object_tree::{recurse_elem, Component};
+use std::rc::Rc;
+
+pub fn record_qualified_ids(component: &Rc<Component>) {
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        let mut elem = elem.borrow_mut();
+        if !elem.id.is_empty() {
+            let qualified_id = format!("{}::{}", component.id, elem.id);
+            elem.qualified_ids.push(qualified_id);
+        }
+    });
+}
@@ -37,9 +37,11 @@ fn create_repeater_components(component: &Rc<Component>) {
                 repeated: None,
                 is_component_placeholder: false,
                 node: elem.node.clone(),
+                qualified_ids: std::mem::take(&mut elem.qualified_ids),
                 enclosing_component: Default::default(),
                 states: std::mem::take(&mut elem.states),
                 transitions: std::mem::take(&mut elem.transitions),
+                change_callbacks: std::mem::take(&mut elem.change_callbacks),
                 child_of_layout: elem.child_of_layout || is_listview.is_some(),
                 layout_info_prop: elem.layout_info_prop.take(),
                 default_fill_parent: elem.default_fill_parent,
@@ -50,6 +50,11 @@ fn resolve_expression(
                 Expression::from_callback_connection(node.clone().into(), &mut lookup_ctx)
             }
             SyntaxKind::Function => Expression::from_function(node.clone().into(), &mut lookup_ctx),
+            SyntaxKind::PropertyChangedCallback => Expression::from_codeblock_node(
+                syntax_nodes::PropertyChangedCallback::from(node.clone()).CodeBlock(),
+                &mut lookup_ctx,
+            )
+            .maybe_convert_to(Type::Void, node, diag),
             SyntaxKind::Expression => {
                 //FIXME again: this happen for non-binding expression (i.e: model)
                 Expression::from_expression_node(node.clone().into(), &mut lookup_ctx)
@@ -843,8 +848,16 @@ fn from_function_call_node(
                 .unwrap_or_else(|| Self::from_expression_node(n, ctx))
         });
 
-        let sub_expr = sub_expr.map(|n| {
-            (Self::from_expression_node(n.clone(), ctx), Some(NodeOrToken::from((*n).clone())))
+        let is_steps =
+            matches!(function, Expression::BuiltinMacroReference(BuiltinMacroFunction::Steps, _));
+        let sub_expr = sub_expr.enumerate().map(|(i, n)| {
+            // The `start` or `end` keyword of `steps(n, start|end)` must not be looked up
+            let expr = if is_steps && i == 1 && n.QualifiedName().is_some() {
+                Expression::Invalid
+            } else {
+                Self::from_expression_node(n.clone(), ctx)
+            };
+            (expr, Some(NodeOrToken::from((*n).clone())))
         });
 
         let function = match function {
@@ -0,0 +1,315 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Bundled translations: the `.po` files are read at compile time, so that the translated
+//! strings can be embedded in the generated code instead of being looked up with gettext.
+
+use crate::diagnostics::{BuildDiagnostics, SourceFileInner, SourceLocation, Span};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use i_slint_common::plural_rule::plural_rule_from_header;
+pub use i_slint_common::plural_rule::PluralRule;
+
+/// The translations of all the strings, in all the languages found in the bundle directory.
+#[derive(Debug, Default)]
+pub struct Translations {
+    /// The language codes (for example `de` or `pt_BR`), in the order used by the other fields
+    pub languages: Vec<String>,
+    /// The rule that selects the plural form, for each language
+    pub plural_rules: Vec<PluralRule>,
+    /// Map the context and the original string to the translations in each language.
+    /// Each translation is the list of the plural forms (only one if the string has no plural),
+    /// or an empty list if the string is not translated in that language.
+    pub messages: BTreeMap<(String, String), Vec<Vec<String>>>,
+    /// The `.po` files that were read
+    pub files: Vec<PathBuf>,
+}
+
+impl Translations {
+    /// Returns the translations, in each language, of the string `msgid` in the given `context`
+    pub fn lookup(&self, context: &str, msgid: &str) -> Option<&[Vec<String>]> {
+        self.messages.get(&(context.to_owned(), msgid.to_owned())).map(|v| v.as_slice())
+    }
+
+    /// Iterate over all the translated strings
+    pub fn all_strings(&self) -> impl Iterator<Item = &str> {
+        self.messages.values().flatten().flatten().map(|s| s.as_str())
+    }
+}
+
+/// Load the translations from the `.po` files in the `path` directory.
+///
+/// The files are expected to follow the gettext layout: `<path>/<language>/LC_MESSAGES/<domain>.po`
+pub fn load_translations(path: &Path, domain: &str, diag: &mut BuildDiagnostics) -> Translations {
+    let mut result = Translations::default();
+    let dir = match std::fs::read_dir(path) {
+        Ok(dir) => dir,
+        Err(err) => {
+            diag.push_error_with_span(
+                format!("Cannot read the translations directory {}: {err}", path.display()),
+                SourceLocation {
+                    source_file: Some(SourceFileInner::from_path_only(path.to_owned())),
+                    span: Span::default(),
+                },
+            );
+            return result;
+        }
+    };
+    let mut languages = dir
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let language = entry.file_name().to_str()?.to_owned();
+            let file = entry.path().join("LC_MESSAGES").join(format!("{domain}.po"));
+            file.is_file().then_some((language, file))
+        })
+        .collect::<Vec<_>>();
+    languages.sort();
+
+    let count = languages.len();
+    for (language, file) in languages {
+        let index = result.languages.len();
+        let source = match std::fs::read_to_string(&file) {
+            Ok(source) => source,
+            Err(err) => {
+                diag.push_error_with_span(
+                    format!("Cannot read {}: {err}", file.display()),
+                    SourceLocation {
+                        source_file: Some(SourceFileInner::from_path_only(file.clone())),
+                        span: Span::default(),
+                    },
+                );
+                continue;
+            }
+        };
+        let source_file = Rc::new(SourceFileInner::new(file.clone(), source.clone()));
+        let mut error = |message: String, offset: usize| {
+            diag.push_error_with_span(
+                message,
+                SourceLocation { source_file: Some(source_file.clone()), span: Span::new(offset) },
+            )
+        };
+
+        let entries = match parse_po(&source) {
+            Ok(entries) => entries,
+            Err((message, offset)) => {
+                error(message, offset);
+                continue;
+            }
+        };
+
+        let mut plural_rule = None;
+        for entry in entries {
+            if entry.msgid.is_empty() {
+                // The header entry
+                if let Some(rule) = entry.msgstr.first().and_then(|h| plural_rule_from_header(h)) {
+                    match PluralRule::parse(rule) {
+                        Ok(rule) => plural_rule = Some(rule),
+                        Err(message) => {
+                            error(format!("Invalid plural rule '{rule}': {message}"), entry.offset)
+                        }
+                    }
+                }
+                continue;
+            }
+            if entry.fuzzy || entry.msgstr.iter().all(|s| s.is_empty()) {
+                continue;
+            }
+            let translations = result
+                .messages
+                .entry((entry.context.unwrap_or_default(), entry.msgid))
+                .or_insert_with(|| vec![Vec::new(); count]);
+            translations[index] = entry.msgstr;
+        }
+
+        result.languages.push(language);
+        // The default rule of gettext, for languages with one singular and one plural form
+        result.plural_rules.push(plural_rule.unwrap_or(PluralRule::Binary(
+            Box::new(PluralRule::N),
+            '!',
+            Box::new(PluralRule::Constant(1)),
+        )));
+        result.files.push(file);
+    }
+
+    // Remove the slots of the languages whose file could not be loaded
+    if result.languages.len() != count {
+        for translations in result.messages.values_mut() {
+            translations.truncate(result.languages.len());
+        }
+    }
+    result
+}
+
+/// An entry of a `.po` file
+#[derive(Debug, Default, PartialEq)]
+struct PoEntry {
+    context: Option<String>,
+    msgid: String,
+    /// The translation, or one translation per plural form
+    msgstr: Vec<String>,
+    fuzzy: bool,
+    /// The offset of the entry in the file
+    offset: usize,
+}
+
+/// Parse the content of a `.po` file.
+///
+/// Returns an error message and the offset of the line where the error occurred.
+fn parse_po(source: &str) -> Result<Vec<PoEntry>, (String, usize)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        None,
+        Context,
+        Id,
+        IdPlural,
+        Str(usize),
+    }
+
+    let mut entries = Vec::new();
+    let mut current = PoEntry::default();
+    let mut field = Field::None;
+    let mut offset = 0;
+
+    fn finish(entries: &mut Vec<PoEntry>, current: &mut PoEntry, field: &mut Field) {
+        if matches!(*field, Field::Str(_)) {
+            entries.push(core::mem::take(current));
+        }
+        *field = Field::None;
+    }
+
+    for line in source.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            // Comments come before an entry, so they end the previous one
+            finish(&mut entries, &mut current, &mut field);
+            if let Some(flags) = comment.strip_prefix(',') {
+                current.fuzzy |= flags.split(',').any(|f| f.trim() == "fuzzy");
+            }
+            continue;
+        }
+
+        let (keyword, string) = match line.find('"') {
+            Some(pos) => (line[..pos].trim(), &line[pos..]),
+            None => return Err((format!("Invalid line in .po file: {line}"), line_offset)),
+        };
+        let string = unescape_po_string(string)
+            .ok_or_else(|| (format!("Invalid string in .po file: {string}"), line_offset))?;
+
+        let new_field = match keyword {
+            "" if field != Field::None => field,
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Id,
+            "msgid_plural" if field == Field::Id => Field::IdPlural,
+            "msgstr" if matches!(field, Field::Id | Field::IdPlural) => Field::Str(0),
+            _ => {
+                if let Some(index) =
+                    keyword.strip_prefix("msgstr[").and_then(|k| k.strip_suffix(']'))
+                {
+                    match (index.parse::<usize>(), field) {
+                        (Ok(index), Field::IdPlural | Field::Str(_)) => Field::Str(index),
+                        _ => {
+                            return Err((
+                                format!("Unexpected '{keyword}' in .po file"),
+                                line_offset,
+                            ))
+                        }
+                    }
+                } else {
+                    return Err((format!("Unexpected '{keyword}' in .po file"), line_offset));
+                }
+            }
+        };
+        if keyword == "msgctxt" || (keyword == "msgid" && field != Field::Context) {
+            finish(&mut entries, &mut current, &mut field);
+            current.offset = line_offset;
+        }
+        field = new_field;
+
+        match field {
+            Field::None => unreachable!(),
+            Field::Context => current.context.get_or_insert_with(String::new).push_str(&string),
+            Field::Id => current.msgid.push_str(&string),
+            // The plural of the original string is not needed, the lookup is done with `msgid`
+            Field::IdPlural => (),
+            Field::Str(index) => {
+                if current.msgstr.len() <= index {
+                    current.msgstr.resize(index + 1, String::new());
+                }
+                current.msgstr[index].push_str(&string);
+            }
+        }
+    }
+    finish(&mut entries, &mut current, &mut field);
+    Ok(entries)
+}
+
+/// Unescape a quoted string from a `.po` file
+fn unescape_po_string(string: &str) -> Option<String> {
+    let string = string.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+#[test]
+fn test_parse_po() {
+    let source = r#"
+# Translation of the example
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+#: main.slint:3
+msgctxt "Main"
+msgid "Hello"
+msgstr "Bonjour"
+
+#, fuzzy
+msgid "Fuzzy"
+msgstr "Flou"
+
+msgid "One file"
+msgid_plural "{n} files"
+msgstr[0] "Un fichier"
+msgstr[1] "{n} "
+"fichiers"
+
+msgid "Quote \"\\\n"
+msgstr "Guillemet «\\\n"
+"#;
+    let entries = parse_po(source).unwrap();
+    assert_eq!(entries.len(), 5);
+    assert_eq!(plural_rule_from_header(&entries[0].msgstr[0]), Some("(n > 1)"), "{:?}", entries[0]);
+    assert_eq!(entries[1].context.as_deref(), Some("Main"));
+    assert_eq!(entries[1].msgid, "Hello");
+    assert_eq!(entries[1].msgstr, vec!["Bonjour".to_owned()]);
+    assert!(entries[2].fuzzy);
+    assert!(!entries[3].fuzzy);
+    assert_eq!(entries[3].msgid, "One file");
+    assert_eq!(entries[3].msgstr, vec!["Un fichier".to_owned(), "{n} fichiers".to_owned()]);
+    assert_eq!(entries[4].msgid, "Quote \"\\\n");
+    assert_eq!(entries[4].msgstr, vec!["Guillemet «\\\n".to_owned()]);
+
+    assert!(parse_po("msgid \"x\"\nmsgstr[0] \"y\"\n").is_err());
+    assert!(parse_po("msgid \"x\nmsgstr \"y\"\n").is_err());
+}
@@ -39,6 +39,9 @@
     ("row", Type::Int32),
     ("colspan", Type::Int32),
     ("rowspan", Type::Int32),
+    ("flex-grow", Type::Float32),
+    ("flex-shrink", Type::Float32),
+    ("flex-basis", Type::LogicalLength),
 ];
 
 macro_rules! declare_enums {
@@ -164,6 +167,16 @@ fn fill_register(&self, register: &mut TypeRegister) {
     ("rotation-origin-y", Type::LogicalLength),
 ];
 
+/// The scale properties are lowered to the same element as the rotation properties, but they are
+/// only reserved in the [`TRANSFORMABLE_CLASSES`], so that they don't conflict with the properties
+/// of the same name declared in other components.
+pub const RESERVED_SCALE_PROPERTIES: &[(&str, Type)] =
+    &[("scale-x", Type::Float32), ("scale-y", Type::Float32)];
+
+/// The native classes of the elements that can be rotated and scaled
+pub const TRANSFORMABLE_CLASSES: &[&str] =
+    &["ClippedImage", "Rectangle", "BorderRectangle", "Text"];
+
 pub const RESERVED_ACCESSIBILITY_PROPERTIES: &[(&str, Type)] = &[
     //("accessible-role", ...)
     ("accessible-checkable", Type::Bool),
@@ -203,6 +216,35 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type)> {
         .chain(std::iter::once(("init", Type::Callback { return_type: None, args: vec![] })))
 }
 
+/// list of reserved property injected in the items of the native class `class_name`.
+/// This is [`reserved_properties`] and the properties only reserved for some classes.
+pub fn reserved_properties_for_class(
+    class_name: &str,
+) -> impl Iterator<Item = (&'static str, Type)> {
+    let scale_properties =
+        if TRANSFORMABLE_CLASSES.contains(&class_name) { RESERVED_SCALE_PROPERTIES } else { &[] };
+    reserved_properties().chain(scale_properties.iter().map(|(k, v)| (*k, v.clone())))
+}
+
+/// lookup reserved property injected in the items of the native class `class_name`
+pub fn reserved_property_for_class<'a>(
+    class_name: &str,
+    name: &'a str,
+) -> PropertyLookupResult<'a> {
+    if TRANSFORMABLE_CLASSES.contains(&class_name) {
+        if let Some((_, ty)) = RESERVED_SCALE_PROPERTIES.iter().find(|(p, _)| *p == name) {
+            return PropertyLookupResult {
+                property_type: ty.clone(),
+                resolved_name: name.into(),
+                is_local_to_component: false,
+                property_visibility: crate::object_tree::PropertyVisibility::InOut,
+                declared_pure: None,
+            };
+        }
+    }
+    reserved_property(name)
+}
+
 /// lookup reserved property injected in every item
 pub fn reserved_property(name: &str) -> PropertyLookupResult {
     for (p, t) in reserved_properties() {
@@ -6,6 +6,8 @@
 
 use alloc::boxed::Box;
 use core::cell::Cell;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 mod cubic_bezier {
     //! This is a copy from lyon_algorithms::geom::cubic_bezier implementation
@@ -145,6 +147,37 @@ pub enum EasingCurve {
     Linear,
     /// A Cubic bezier curve, with its 4 parameter
     CubicBezier([f32; 4]),
+    /// An elastic curve that oscillates with growing amplitude before reaching the target
+    EaseInElastic,
+    /// An elastic curve that overshoots the target and oscillates around it
+    EaseOutElastic,
+    /// Combination of [`Self::EaseInElastic`] and [`Self::EaseOutElastic`]
+    EaseInOutElastic,
+    /// A curve that bounces off the start value with growing amplitude
+    EaseInBounce,
+    /// A curve that bounces off the target value like a ball
+    EaseOutBounce,
+    /// Combination of [`Self::EaseInBounce`] and [`Self::EaseOutBounce`]
+    EaseInOutBounce,
+    /// A staircase curve that jumps in `count` equal steps
+    Steps {
+        /// The number of steps
+        count: u32,
+        /// If true, the first jump happens at the start of the animation (like `jump-start` in CSS),
+        /// otherwise the last jump happens at the end (like `jump-end` in CSS)
+        jump_start: bool,
+    },
+    /// The motion of a damped spring released from the start value and pulled to the target value.
+    ///
+    /// The motion is scaled so that the spring settles at the end of the animation's duration.
+    Spring {
+        /// The mass attached to the spring
+        mass: f32,
+        /// The stiffness of the spring
+        stiffness: f32,
+        /// The damping, which slows down the motion
+        damping: f32,
+    },
     //Custom(Box<dyn Fn(f32) -> f32>),
 }
 
@@ -293,9 +326,106 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
             };
             curve.y(curve.solve_t_for_x(value, 0.0..1.0, 0.01))
         }
+        EasingCurve::EaseInElastic => ease_in_elastic(value),
+        EasingCurve::EaseOutElastic => 1. - ease_in_elastic(1. - value),
+        EasingCurve::EaseInOutElastic => {
+            if value < 0.5 {
+                ease_in_elastic(value * 2.) / 2.
+            } else {
+                1. - ease_in_elastic(2. - value * 2.) / 2.
+            }
+        }
+        EasingCurve::EaseInBounce => 1. - ease_out_bounce(1. - value),
+        EasingCurve::EaseOutBounce => ease_out_bounce(value),
+        EasingCurve::EaseInOutBounce => {
+            if value < 0.5 {
+                (1. - ease_out_bounce(1. - value * 2.)) / 2.
+            } else {
+                (1. + ease_out_bounce(value * 2. - 1.)) / 2.
+            }
+        }
+        EasingCurve::Steps { count, jump_start } => {
+            let count = (*count).max(1) as f32;
+            let step = (value * count).floor() + if *jump_start { 1. } else { 0. };
+            step.clamp(0., count) / count
+        }
+        EasingCurve::Spring { mass, stiffness, damping } => {
+            spring(*mass, *stiffness, *damping, value)
+        }
+    }
+}
+
+fn ease_in_elastic(value: f32) -> f32 {
+    if value <= 0. {
+        0.
+    } else if value >= 1. {
+        1.
+    } else {
+        let c4 = 2. * core::f32::consts::PI / 3.;
+        -(2.0f32.powf(10. * value - 10.)) * ((value * 10. - 10.75) * c4).sin()
+    }
+}
+
+fn ease_out_bounce(value: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if value < 1. / D1 {
+        N1 * value * value
+    } else if value < 2. / D1 {
+        let value = value - 1.5 / D1;
+        N1 * value * value + 0.75
+    } else if value < 2.5 / D1 {
+        let value = value - 2.25 / D1;
+        N1 * value * value + 0.9375
+    } else {
+        let value = value - 2.625 / D1;
+        N1 * value * value + 0.984375
     }
 }
 
+/// The position of a damped spring with no initial velocity, going from 0 to 1.
+///
+/// The time is scaled so that `value == 1` is the time where the oscillations are below 0.1% of
+/// the distance. The small remaining offset is blended out so that the curve ends exactly at 1.
+fn spring(mass: f32, stiffness: f32, damping: f32, value: f32) -> f32 {
+    if value >= 1. {
+        return 1.;
+    }
+    if !(mass > 0. && stiffness > 0. && damping > 0.) {
+        return value;
+    }
+    let omega0 = (stiffness / mass).sqrt();
+    let zeta = damping / (2. * (stiffness * mass).sqrt());
+    let critically_damped = (zeta - 1.).abs() < 1e-3;
+    // The displacement from the target at the time t
+    let displacement = |t: f32| {
+        if critically_damped {
+            (1. + omega0 * t) * (-omega0 * t).exp()
+        } else if zeta < 1. {
+            // under damped: oscillates around the target
+            let omega_d = omega0 * (1. - zeta * zeta).sqrt();
+            (-zeta * omega0 * t).exp()
+                * ((omega_d * t).cos() + zeta * omega0 / omega_d * (omega_d * t).sin())
+        } else {
+            // over damped: slowly converges without oscillation
+            let s = (zeta * zeta - 1.).sqrt();
+            let (r1, r2) = (-omega0 * (zeta - s), -omega0 * (zeta + s));
+            (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r2 - r1)
+        }
+    };
+    // The rate at which the envelope of the displacement decays
+    let decay = if critically_damped {
+        omega0
+    } else if zeta < 1. {
+        zeta * omega0
+    } else {
+        omega0 * (zeta - (zeta * zeta - 1.).sqrt())
+    };
+    // ln(1000): the time it takes for the envelope to decay to 0.1%
+    let duration = 6.9077554 / decay;
+    1. - displacement(value * duration) + displacement(duration) * value
+}
+
 /*
 #[test]
 fn easing_test() {
@@ -327,6 +457,56 @@ fn test_curve(name: &str, curve: &EasingCurve) {
 }
 */
 
+#[test]
+fn easing_curve_end_points() {
+    let curves = [
+        EasingCurve::Linear,
+        EasingCurve::CubicBezier([0.42, 0.0, 0.58, 1.0]),
+        EasingCurve::EaseInElastic,
+        EasingCurve::EaseOutElastic,
+        EasingCurve::EaseInOutElastic,
+        EasingCurve::EaseInBounce,
+        EasingCurve::EaseOutBounce,
+        EasingCurve::EaseInOutBounce,
+        EasingCurve::Steps { count: 3, jump_start: false },
+        EasingCurve::Spring { mass: 1., stiffness: 100., damping: 10. },
+        EasingCurve::Spring { mass: 1., stiffness: 100., damping: 20. },
+        EasingCurve::Spring { mass: 1., stiffness: 100., damping: 50. },
+    ];
+    for curve in curves {
+        assert!(easing_curve(&curve, 0.).abs() < 0.001, "{curve:?}");
+        assert!((easing_curve(&curve, 1.) - 1.).abs() < 0.001, "{curve:?}");
+    }
+}
+
+#[test]
+fn easing_curve_values() {
+    assert!((easing_curve(&EasingCurve::EaseOutBounce, 0.5) - 0.765625).abs() < 0.0001);
+    assert!((easing_curve(&EasingCurve::EaseInBounce, 0.5) - 0.234375).abs() < 0.0001);
+    assert_eq!(easing_curve(&EasingCurve::EaseInOutBounce, 0.5), 0.5);
+    assert_eq!(easing_curve(&EasingCurve::EaseInOutElastic, 0.5), 0.5);
+    // The elastic curves go beyond the range
+    assert!(easing_curve(&EasingCurve::EaseInElastic, 0.9) < 0.);
+    assert!(easing_curve(&EasingCurve::EaseOutElastic, 0.1) > 1.);
+
+    let steps_end = EasingCurve::Steps { count: 4, jump_start: false };
+    let steps_start = EasingCurve::Steps { count: 4, jump_start: true };
+    assert_eq!(easing_curve(&steps_end, 0.), 0.);
+    assert_eq!(easing_curve(&steps_end, 0.3), 0.25);
+    assert_eq!(easing_curve(&steps_end, 0.99), 0.75);
+    assert_eq!(easing_curve(&steps_start, 0.), 0.25);
+    assert_eq!(easing_curve(&steps_start, 0.3), 0.5);
+    assert_eq!(easing_curve(&steps_start, 0.99), 1.);
+    assert_eq!(easing_curve(&steps_start, 1.), 1.);
+
+    // An under-damped spring overshoots the target, an over-damped one doesn't
+    let bouncy = EasingCurve::Spring { mass: 1., stiffness: 100., damping: 5. };
+    assert!((0..100).map(|x| easing_curve(&bouncy, x as f32 / 100.)).any(|v| v > 1.));
+    let stiff = EasingCurve::Spring { mass: 1., stiffness: 100., damping: 40. };
+    let values: Vec<_> = (0..=100).map(|x| easing_curve(&stiff, x as f32 / 100.)).collect();
+    assert!(values.windows(2).all(|w| w[0] <= w[1] && w[1] <= 1.));
+}
+
 /// Update the global animation time to the current time
 pub fn update_animations() {
     CURRENT_ANIMATION_DRIVER.with(|driver| {
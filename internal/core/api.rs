@@ -11,6 +11,7 @@
 #[cfg(target_has_atomic = "ptr")]
 pub use crate::future::*;
 use crate::input::{KeyEventType, KeyInputEvent, MouseEvent};
+use crate::items::PointerEventKind;
 use crate::window::{WindowAdapter, WindowInner};
 use alloc::boxed::Box;
 use alloc::string::String;
@@ -480,6 +481,18 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::TouchPressed { id, position } => {
+                self.0.process_touch_input(id, PointerEventKind::Down, position.to_euclid().cast());
+            }
+            crate::platform::WindowEvent::TouchMoved { id, position } => {
+                self.0.process_touch_input(id, PointerEventKind::Move, position.to_euclid().cast());
+            }
+            crate::platform::WindowEvent::TouchReleased { id, position } => {
+                self.0.process_touch_input(id, PointerEventKind::Up, position.to_euclid().cast());
+            }
+            crate::platform::WindowEvent::TouchCancelled { id } => {
+                self.0.process_touch_input(id, PointerEventKind::Cancel, Default::default());
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(KeyInputEvent {
@@ -800,6 +813,30 @@ pub fn quit_event_loop() -> Result<(), EventLoopError> {
         .quit_event_loop()
 }
 
+/// Selects the language used to translate the strings marked with `@tr(...)` in .slint files.
+///
+/// All the properties whose bindings use `@tr(...)` are marked as dirty and are re-evaluated
+/// with the new language, so this can be used to switch the language of a running application.
+/// The language is a code such as `"de"` or `"pt_BR"`. An empty string selects the default
+/// language of the system again.
+///
+/// When using the `gettext` feature on Unix, the translations of the selected language are
+/// loaded from the `.mo` files in the directory bound to the translation domain. The
+/// environment and the locale of the process are not changed.
+///
+/// This function must be called from the thread that runs the event loop.
+///
+/// ```rust
+/// slint::slint! { export component MyApp inherits Window { Text { text: @tr("Hello"); } } }
+/// # i_slint_backend_testing::init();
+/// let handle = MyApp::new().unwrap();
+/// // ... later, when the user changed the language in the settings
+/// slint::select_language("de");
+/// ```
+pub fn select_language(language: &str) {
+    crate::translations::select_language(language)
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[non_exhaustive]
 /// Error returned from the [`invoke_from_event_loop()`] and [`quit_event_loop()`] function
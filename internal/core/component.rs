@@ -115,6 +115,14 @@ pub struct ComponentVTable {
         result: &mut SharedString,
     ),
 
+    /// Returns the `Component::id` of the elements the item was created from, separated by
+    /// newlines. Returns false if the component was compiled without the debug info.
+    pub item_element_infos: extern "C" fn(
+        core::pin::Pin<VRef<ComponentVTable>>,
+        item_index: u32,
+        result: &mut SharedString,
+    ) -> bool,
+
     /// Returns a Window, creating a fresh one if `do_create` is true.
     pub window_adapter: extern "C" fn(
         core::pin::Pin<VRef<ComponentVTable>>,
@@ -50,7 +50,7 @@
 #[cfg(feature = "std")]
 pub mod rendering_metrics_collector;
 
-#[cfg(feature = "box-shadow-cache")]
+#[cfg(any(feature = "box-shadow-cache", all(feature = "software-renderer", feature = "std")))]
 pub mod boxshadowcache;
 
 /// CachedGraphicsData allows the graphics backend to store an arbitrary piece of data associated with
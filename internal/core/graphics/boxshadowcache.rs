@@ -111,4 +111,9 @@ pub fn get_box_shadow(
                 .into()
         })
     }
+
+    /// Only keep the box shadow textures for which `f` returns true.
+    pub fn retain(&self, f: impl FnMut(&BoxShadowOptions, &mut ImageType) -> bool) {
+        self.0.borrow_mut().retain(f)
+    }
 }
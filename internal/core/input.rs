@@ -7,7 +7,7 @@
 
 use crate::item_tree::{ItemRc, ItemWeak, VisitChildrenResult};
 pub use crate::items::PointerEventButton;
-use crate::items::{ItemRef, TextCursorDirection};
+use crate::items::{ItemRef, MultiTouchArea, PointerEventKind, TextCursorDirection};
 pub use crate::items::{KeyEvent, KeyboardModifiers};
 use crate::lengths::{LogicalPoint, LogicalVector};
 use crate::timers::Timer;
@@ -551,6 +551,69 @@ pub struct MouseInputState {
     delayed_exit_items: Vec<ItemWeak>,
 }
 
+impl MouseInputState {
+    /// The innermost `MultiTouchArea` in the stack of items under the mouse cursor
+    pub(crate) fn multi_touch_area(&self) -> Option<ItemRc> {
+        self.item_stack
+            .iter()
+            .rev()
+            .filter_map(|(item, _)| item.upgrade())
+            .find(|item| item.downcast::<MultiTouchArea>().is_some())
+    }
+}
+
+/// The state which a window should hold for the touch input
+#[derive(Default)]
+pub struct TouchInputState {
+    /// The fingers touching the window: the id from the platform, the id reported to the items,
+    /// and the position in window coordinates
+    points: Vec<(u64, i32, LogicalPoint)>,
+    /// The finger that is also dispatched as mouse events
+    pub(crate) primary: Option<u64>,
+    /// The `MultiTouchArea` that receives the touch events until all the fingers are lifted
+    pub(crate) target: Option<ItemWeak>,
+}
+
+impl TouchInputState {
+    /// Records the touch event of the finger `id` from the platform, and returns the id
+    /// reported to the items with the position of the finger, or None if the event is for an
+    /// unknown finger. The position of a cancelled finger is its last known position.
+    pub(crate) fn update(
+        &mut self,
+        id: u64,
+        kind: PointerEventKind,
+        position: LogicalPoint,
+    ) -> Option<(i32, LogicalPoint)> {
+        let index = self.points.iter().position(|p| p.0 == id);
+        match (kind, index) {
+            (PointerEventKind::Down, None) => {
+                if self.points.is_empty() {
+                    self.primary = Some(id);
+                }
+                let touch_id = (0..).find(|i| self.points.iter().all(|p| p.1 != *i)).unwrap();
+                self.points.push((id, touch_id, position));
+                Some((touch_id, position))
+            }
+            (PointerEventKind::Move, Some(index)) => {
+                self.points[index].2 = position;
+                Some((self.points[index].1, position))
+            }
+            (PointerEventKind::Up, Some(index)) => Some((self.points.remove(index).1, position)),
+            (PointerEventKind::Cancel, Some(index)) => {
+                let (_, touch_id, last_position) = self.points.remove(index);
+                Some((touch_id, last_position))
+            }
+            // A finger that is already down, or an unknown finger
+            _ => None,
+        }
+    }
+
+    /// The fingers touching the window, with the id reported to the items and their position
+    pub(crate) fn points(&self) -> impl Iterator<Item = (i32, LogicalPoint)> + '_ {
+        self.points.iter().map(|p| (p.1, p.2))
+    }
+}
+
 /// Try to handle the mouse grabber. Return None if the event has been handled, otherwise
 /// return the event that must be handled
 fn handle_mouse_grab(
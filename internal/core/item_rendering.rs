@@ -19,6 +19,8 @@
 use alloc::boxed::Box;
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
+#[allow(unused)]
+use num_traits::Float;
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 use vtable::VRc;
@@ -175,6 +177,36 @@ pub fn release(&self, item_rc: &ItemRc) {
     }
 }
 
+/// Return the area covered by the item with the given geometry when it is drawn.
+///
+/// This is the geometry itself, except for the BoxShadow whose shadow is drawn outside of it.
+fn visual_rect(item: Pin<ItemRef>, geometry: LogicalRect) -> LogicalRect {
+    if let Some(shadow) = ItemRef::downcast_pin::<BoxShadow>(item) {
+        let blur = shadow.blur().get().max(0 as Coord);
+        return geometry.union(
+            &geometry
+                .translate(LogicalVector::from_lengths(shadow.offset_x(), shadow.offset_y()))
+                .inflate(blur, blur),
+        );
+    }
+    if let Some(rotate) = ItemRef::downcast_pin::<Rotate>(item) {
+        // Whatever the angle, the rotated children stay within the circle around the origin of
+        // the rotation that passes by the farthest corner, scaled by the largest scale factor.
+        let rect = geometry.cast::<f32>();
+        let origin = rect.origin
+            + LogicalVector::from_lengths(rotate.rotation_origin_x(), rotate.rotation_origin_y())
+                .cast();
+        let dx = (origin.x - rect.min_x()).abs().max((rect.max_x() - origin.x).abs());
+        let dy = (origin.y - rect.min_y()).abs().max((rect.max_y() - origin.y).abs());
+        let scale = rotate.scale_x().abs().max(rotate.scale_y().abs());
+        let radius = euclid::default::Vector2D::new(dx, dy).length() * scale;
+        return euclid::rect(origin.x - radius, origin.y - radius, 2. * radius, 2. * radius)
+            .round_out()
+            .cast();
+    }
+    geometry
+}
+
 /// Return true if the item might be a clipping item
 pub(crate) fn is_clipping_item(item: Pin<ItemRef>) -> bool {
     //(FIXME: there should be some flag in the vtable instead of down-casting)
@@ -378,6 +410,8 @@ fn combine_clip(
 
     fn translate(&mut self, distance: LogicalVector);
     fn rotate(&mut self, angle_in_degrees: f32);
+    /// Scale the coordinate system by the given factors, which can be negative to mirror it.
+    fn scale(&mut self, x_factor: f32, y_factor: f32);
     /// Apply the opacity (between 0 and 1) for all following items until the next call to restore_state.
     fn apply_opacity(&mut self, opacity: f32);
 
@@ -458,6 +492,44 @@ struct ComputeDirtyRegionState {
             old_offset: euclid::Vector2D<Coord, LogicalPx>,
             clipped: LogicalRect,
             must_refresh_children: bool,
+            /// The bounds, in window coordinates, of the rotation that applies to the children, if any
+            transformed_bounds: Option<LogicalRect>,
+        }
+
+        impl ComputeDirtyRegionState {
+            /// Returns the rect in window coordinates where an item with the given `rect`,
+            /// relative to `offset`, may be drawn.
+            fn window_rect(
+                &self,
+                rect: LogicalRect,
+                offset: euclid::Vector2D<Coord, LogicalPx>,
+            ) -> LogicalRect {
+                match self.transformed_bounds {
+                    // A transformed item can be drawn anywhere within the bounds of its transformation
+                    Some(bounds) if !rect.is_empty() => bounds,
+                    _ => rect.translate(offset),
+                }
+            }
+
+            /// Set the bounds of the transformation for the children of a `Rotate` item
+            fn enter_transform(
+                &mut self,
+                item: Pin<ItemRef>,
+                old_geom: LogicalRect,
+                geom: LogicalRect,
+                old_offset: euclid::Vector2D<Coord, LogicalPx>,
+                offset: euclid::Vector2D<Coord, LogicalPx>,
+            ) {
+                if self.transformed_bounds.is_none()
+                    && ItemRef::downcast_pin::<Rotate>(item).is_some()
+                {
+                    self.transformed_bounds = Some(
+                        visual_rect(item, old_geom)
+                            .translate(old_offset)
+                            .union(&visual_rect(item, geom).translate(offset)),
+                    );
+                }
+            }
         }
 
         crate::item_tree::visit_items(
@@ -479,11 +551,29 @@ struct ComputeDirtyRegionState {
                             let geom =
                                 crate::properties::evaluate_no_tracking(|| item_rc.geometry());
 
-                            self.mark_dirty_rect(old_geom, state.old_offset, &state.clipped);
-                            self.mark_dirty_rect(geom, state.offset, &state.clipped);
+                            let (old_rect, rect) = crate::properties::evaluate_no_tracking(|| {
+                                (visual_rect(item, old_geom), visual_rect(item, geom))
+                            });
+                            self.mark_dirty_rect(
+                                state.window_rect(old_rect, state.old_offset),
+                                &state.clipped,
+                            );
+                            self.mark_dirty_rect(
+                                state.window_rect(rect, state.offset),
+                                &state.clipped,
+                            );
 
                             new_state.offset += geom.origin.to_vector();
                             new_state.old_offset += old_geom.origin.to_vector();
+                            crate::properties::evaluate_no_tracking(|| {
+                                new_state.enter_transform(
+                                    item,
+                                    old_geom,
+                                    geom,
+                                    state.old_offset,
+                                    state.offset,
+                                )
+                            });
                             if ItemRef::downcast_pin::<Clip>(item).is_some()
                                 || ItemRef::downcast_pin::<Opacity>(item).is_some()
                             {
@@ -499,16 +589,30 @@ struct ComputeDirtyRegionState {
                             if state.must_refresh_children
                                 || new_state.offset != new_state.old_offset
                             {
+                                let rect = crate::properties::evaluate_no_tracking(|| {
+                                    visual_rect(item, *cached_geom)
+                                });
                                 self.mark_dirty_rect(
-                                    *cached_geom,
-                                    state.old_offset,
+                                    state.window_rect(rect, state.old_offset),
+                                    &state.clipped,
+                                );
+                                self.mark_dirty_rect(
+                                    state.window_rect(rect, state.offset),
                                     &state.clipped,
                                 );
-                                self.mark_dirty_rect(*cached_geom, state.offset, &state.clipped);
                             }
 
                             new_state.offset += cached_geom.origin.to_vector();
                             new_state.old_offset += cached_geom.origin.to_vector();
+                            crate::properties::evaluate_no_tracking(|| {
+                                new_state.enter_transform(
+                                    item,
+                                    *cached_geom,
+                                    *cached_geom,
+                                    state.old_offset,
+                                    state.offset,
+                                )
+                            });
                             if crate::properties::evaluate_no_tracking(|| is_clipping_item(item)) {
                                 new_state.clipped = new_state
                                     .clipped
@@ -534,9 +638,16 @@ struct ComputeDirtyRegionState {
                                     .intersection(&geom.translate(state.offset))
                                     .unwrap_or_default();
                             }
-                            geom
+                            new_state.enter_transform(
+                                item,
+                                geom,
+                                geom,
+                                state.old_offset,
+                                state.offset,
+                            );
+                            visual_rect(item, geom)
                         });
-                        self.mark_dirty_rect(geom, state.offset, &state.clipped);
+                        self.mark_dirty_rect(state.window_rect(geom, state.offset), &state.clipped);
                         ItemVisitorResult::Continue(new_state)
                     }
                 }
@@ -546,18 +657,14 @@ struct ComputeDirtyRegionState {
                 old_offset: origin.to_vector(),
                 clipped: euclid::rect(0 as Coord, 0 as Coord, Coord::MAX, Coord::MAX),
                 must_refresh_children: false,
+                transformed_bounds: None,
             },
         );
     }
 
-    fn mark_dirty_rect(
-        &mut self,
-        rect: LogicalRect,
-        offset: euclid::Vector2D<Coord, LogicalPx>,
-        clip_rect: &LogicalRect,
-    ) {
+    fn mark_dirty_rect(&mut self, rect: LogicalRect, clip_rect: &LogicalRect) {
         if !rect.is_empty() {
-            if let Some(rect) = rect.translate(offset).intersection(clip_rect) {
+            if let Some(rect) = rect.intersection(clip_rect) {
                 self.dirty_region = self.dirty_region.union(&rect.to_box2d());
             }
         }
@@ -608,6 +715,13 @@ fn filter_item(&mut self, item_rc: &ItemRc) -> (bool, LogicalRect) {
             if let Some(clip) = ItemRef::downcast_pin::<Clip>(item) {
                 // Make sure we register a dependency on the clip
                 clip.clip();
+            } else if let Some(rotate) = ItemRef::downcast_pin::<Rotate>(item) {
+                // And on the rotation and scale, which change how the children are drawn
+                rotate.rotation_angle();
+                rotate.rotation_origin_x();
+                rotate.rotation_origin_y();
+                rotate.scale_x();
+                rotate.scale_y();
             }
             item_rc.geometry()
         };
@@ -672,6 +786,10 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         self.actual_renderer.rotate(angle_in_degrees)
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.actual_renderer.scale(x_factor, y_factor)
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         self.actual_renderer.apply_opacity(opacity)
     }
@@ -178,6 +178,14 @@ pub fn accessible_string_property(
         result
     }
 
+    /// Returns the `Component::id` of the elements this item was created from, separated by newlines,
+    /// or None if the component was compiled without the debug info.
+    pub fn element_infos(&self) -> Option<SharedString> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
+        let mut result = Default::default();
+        comp_ref_pin.as_ref().item_element_infos(self.index, &mut result).then_some(result)
+    }
+
     pub fn geometry(&self) -> LogicalRect {
         let comp_ref_pin = vtable::VRc::borrow_pin(&self.component);
         comp_ref_pin.as_ref().item_geometry(self.index)
@@ -934,6 +942,10 @@ fn accessible_string_property(
         ) {
         }
 
+        fn item_element_infos(self: Pin<&Self>, _: u32, _: &mut SharedString) -> bool {
+            false
+        }
+
         fn window_adapter(
             self: Pin<&Self>,
             _do_create: bool,
@@ -46,6 +46,8 @@
 pub use self::component_container::*;
 mod flickable;
 pub use flickable::*;
+mod multi_touch_area;
+pub use multi_touch_area::*;
 mod text;
 pub use text::*;
 mod image;
@@ -976,6 +978,8 @@ pub struct Rotate {
     pub rotation_angle: Property<f32>,
     pub rotation_origin_x: Property<LogicalLength>,
     pub rotation_origin_y: Property<LogicalLength>,
+    pub scale_x: Property<f32>,
+    pub scale_y: Property<f32>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -1036,6 +1040,7 @@ fn render(
             LogicalVector::from_lengths(self.rotation_origin_x(), self.rotation_origin_y());
         (*backend).translate(origin);
         (*backend).rotate(self.rotation_angle());
+        (*backend).scale(self.scale_x(), self.scale_y());
         (*backend).translate(-origin);
         RenderingResult::ContinueRenderingChildren
     }
@@ -1056,6 +1061,10 @@ fn slint_get_RotateVTable() -> RotateVTable for Rotate
     fn slint_get_FlickableVTable() -> FlickableVTable for Flickable
 }
 
+declare_item_vtable! {
+    fn slint_get_MultiTouchAreaVTable() -> MultiTouchAreaVTable for MultiTouchArea
+}
+
 /// The implementation of the `PropertyAnimation` element
 #[repr(C)]
 #[derive(FieldOffsets, SlintElement, Clone, Debug)]
@@ -0,0 +1,308 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The `MultiTouchArea` item, and the recognition of the pinch and rotate gestures
+
+use super::{
+    Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventKind, RenderingResult,
+    TouchEvent, VoidArg,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalSize, LogicalVector};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use const_field_offset::FieldOffsets;
+use core::cell::{Cell, RefCell};
+use core::pin::Pin;
+use i_slint_core_macros::*;
+#[cfg(not(feature = "std"))]
+#[allow(unused)]
+use num_traits::Float;
+
+type TouchEventArg = (TouchEvent,);
+
+/// The implementation of the `MultiTouchArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct MultiTouchArea {
+    pub enabled: Property<bool>,
+    pub touch_count: Property<i32>,
+    pub gesture_active: Property<bool>,
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub gesture_center_x: Property<LogicalLength>,
+    pub gesture_center_y: Property<LogicalLength>,
+    pub touch_event: Callback<TouchEventArg>,
+    pub gesture_started: Callback<VoidArg>,
+    pub gesture_ended: Callback<VoidArg>,
+    data: MultiTouchAreaDataBox,
+
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for MultiTouchArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            InputEventFilterResult::ForwardAndIgnore
+        } else if self.data.intercepting.get() {
+            // Take the mouse grab away from the children once a pinch or rotate gesture started,
+            // so that lifting the fingers doesn't click
+            InputEventFilterResult::Intercept
+        } else {
+            InputEventFilterResult::ForwardAndInterceptGrab
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !self.enabled() {
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            // Accept the press of a finger so that the window reports the other fingers to this
+            // item. The presses of the mouse go to the items below.
+            MouseEvent::Pressed { .. }
+                if WindowInner::from_pub(window_adapter.window()).is_dispatching_touch() =>
+            {
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { .. } | MouseEvent::Wheel { .. } if self.data.intercepting.get() => {
+                InputEventResult::GrabMouse
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for MultiTouchArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl MultiTouchArea {
+    /// Called by the window for every touch event while this item is the target of the touch
+    /// events. The position of the event is relative to this item.
+    pub(crate) fn touch_event(self: Pin<&Self>, event: TouchEvent) {
+        if !self.enabled() {
+            return;
+        }
+        let position = LogicalPoint::new(event.x, event.y);
+        let touch_count = {
+            let mut points = self.data.points.borrow_mut();
+            match event.kind {
+                PointerEventKind::Down => points.push((event.id, position)),
+                PointerEventKind::Move => {
+                    let Some(point) = points.iter_mut().find(|p| p.0 == event.id) else {
+                        return;
+                    };
+                    point.1 = position;
+                }
+                PointerEventKind::Up | PointerEventKind::Cancel => {
+                    points.retain(|p| p.0 != event.id)
+                }
+            }
+            points.len()
+        };
+        Self::FIELD_OFFSETS.touch_count.apply_pin(self).set(touch_count as i32);
+        Self::FIELD_OFFSETS.touch_event.apply_pin(self).call(&(event,));
+        self.update_gesture();
+        if touch_count == 0 {
+            self.data.intercepting.set(false);
+        }
+    }
+
+    /// Starts, updates or ends the pinch and rotate gesture made by the first two fingers
+    fn update_gesture(self: Pin<&Self>) {
+        let points = self.data.points.borrow().clone();
+        let position = |id: i32| points.iter().find(|p| p.0 == id).map(|p| p.1);
+
+        if let Some(gesture) = self.data.gesture.get() {
+            if gesture.ids.iter().any(|id| position(*id).is_none()) {
+                self.data.gesture.set(None);
+                Self::FIELD_OFFSETS.gesture_active.apply_pin(self).set(false);
+                // The callback can still read the final scale and rotation
+                Self::FIELD_OFFSETS.gesture_ended.apply_pin(self).call(&());
+                Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+                Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+            }
+        }
+
+        let gesture = match self.data.gesture.get() {
+            Some(gesture) => gesture,
+            None if points.len() >= 2 => {
+                let gesture = Gesture {
+                    ids: [points[0].0, points[1].0],
+                    start_vector: points[1].1 - points[0].1,
+                };
+                self.data.gesture.set(Some(gesture));
+                self.data.intercepting.set(true);
+                self.update_gesture_properties(gesture, points[0].1, points[1].1);
+                Self::FIELD_OFFSETS.gesture_active.apply_pin(self).set(true);
+                Self::FIELD_OFFSETS.gesture_started.apply_pin(self).call(&());
+                return;
+            }
+            None => return,
+        };
+        if let (Some(p0), Some(p1)) = (position(gesture.ids[0]), position(gesture.ids[1])) {
+            self.update_gesture_properties(gesture, p0, p1);
+        }
+    }
+
+    fn update_gesture_properties(
+        self: Pin<&Self>,
+        gesture: Gesture,
+        p0: LogicalPoint,
+        p1: LogicalPoint,
+    ) {
+        let (scale, rotation) = gesture.scale_and_rotation(p1 - p0);
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+        let center = p0.lerp(p1, 0.5);
+        Self::FIELD_OFFSETS.gesture_center_x.apply_pin(self).set(LogicalLength::new(center.x));
+        Self::FIELD_OFFSETS.gesture_center_y.apply_pin(self).set(LogicalLength::new(center.y));
+    }
+}
+
+/// A pinch and rotate gesture made with two fingers
+#[derive(Clone, Copy, Debug)]
+struct Gesture {
+    /// The id of the two fingers
+    ids: [i32; 2],
+    /// The vector from the first finger to the second when the gesture started
+    start_vector: LogicalVector,
+}
+
+impl Gesture {
+    /// Returns the scale factor and the rotation in degrees for the current vector between the
+    /// two fingers
+    fn scale_and_rotation(&self, vector: LogicalVector) -> (f32, f32) {
+        let start_length = self.start_vector.length();
+        let scale = if start_length > 0. { vector.length() / start_length } else { 1. };
+        let angle = |v: LogicalVector| v.y.atan2(v.x);
+        let mut rotation = (angle(vector) - angle(self.start_vector)).to_degrees();
+        if rotation > 180. {
+            rotation -= 360.;
+        } else if rotation <= -180. {
+            rotation += 360.;
+        }
+        (scale, rotation)
+    }
+}
+
+#[repr(C)]
+/// Wraps the internal data structure for the MultiTouchArea
+pub struct MultiTouchAreaDataBox(core::ptr::NonNull<MultiTouchAreaData>);
+
+impl Default for MultiTouchAreaDataBox {
+    fn default() -> Self {
+        MultiTouchAreaDataBox(Box::leak(Box::<MultiTouchAreaData>::default()).into())
+    }
+}
+impl Drop for MultiTouchAreaDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in MultiTouchAreaDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for MultiTouchAreaDataBox {
+    type Target = MultiTouchAreaData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in MultiTouchAreaDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct MultiTouchAreaData {
+    /// The fingers touching the item, with their position relative to the item
+    points: RefCell<Vec<(i32, LogicalPoint)>>,
+    gesture: Cell<Option<Gesture>>,
+    /// Set when a gesture starts, until all the fingers are lifted
+    intercepting: Cell<bool>,
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a MultiTouchAreaDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_multi_touch_area_data_init(data: *mut MultiTouchAreaDataBox) {
+    core::ptr::write(data, MultiTouchAreaDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized MultiTouchAreaDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_multi_touch_area_data_free(data: *mut MultiTouchAreaDataBox) {
+    core::ptr::drop_in_place(data);
+}
+
+#[test]
+fn gesture_scale_and_rotation() {
+    let gesture = Gesture { ids: [0, 1], start_vector: LogicalVector::new(100., 0.) };
+    let (scale, rotation) = gesture.scale_and_rotation(LogicalVector::new(0., 200.));
+    assert!((scale - 2.).abs() < 0.001);
+    assert!((rotation - 90.).abs() < 0.001);
+    let (scale, rotation) = gesture.scale_and_rotation(LogicalVector::new(-50., -1.));
+    assert!((scale - 0.5).abs() < 0.01);
+    assert!((rotation + 178.85).abs() < 0.1);
+}
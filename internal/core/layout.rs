@@ -5,7 +5,7 @@
 
 // cspell:ignore coord
 
-use crate::items::{DialogButtonRole, LayoutAlignment};
+use crate::items::{DialogButtonRole, FlexDirection, FlexWrap, LayoutAlignment};
 use crate::{slice::Slice, Coord, SharedVector};
 use alloc::vec::Vec;
 
@@ -500,11 +500,8 @@ pub struct BoxLayoutCellData {
 
 /// Solve a BoxLayout
 pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> SharedVector<Coord> {
-    let mut result = SharedVector::<Coord>::default();
-    result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
-
     if data.cells.is_empty() {
-        return result;
+        return layout_cache_with_repeaters(&[], repeater_indexes);
     }
 
     let mut layout_data: Vec<_> = data
@@ -571,6 +568,21 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
         }
     }
 
+    layout_cache_with_repeaters(&layout_data, repeater_indexes)
+}
+
+/// Build the layout cache from the position and size of each cell.
+///
+/// The cache contains, for each cell, the position followed by the size. The cells of a repeater
+/// are placed at the end of the cache, and the slot of the repeater contains the offset of its
+/// first cell (see `repeater_indexes` in [`solve_box_layout`])
+fn layout_cache_with_repeaters(
+    layout_data: &[grid_internal::LayoutData],
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let mut result = SharedVector::<Coord>::default();
+    result.resize(layout_data.len() * 2 + repeater_indexes.len(), 0 as _);
+
     let res = result.make_mut_slice();
 
     // The index/2 in result in which we should add the next repeated item
@@ -651,6 +663,440 @@ pub fn box_layout_info_ortho(cells: Slice<BoxLayoutCellData>, padding: &Padding)
     fold
 }
 
+mod flexbox_internal {
+    use super::grid_internal::LayoutData;
+    use super::*;
+    use core::ops::Range;
+
+    fn min_max(constraint: &LayoutInfo, size: Option<Coord>) -> (Coord, Coord) {
+        match size {
+            Some(size) => (
+                constraint.min.max(constraint.min_percent * size / 100 as Coord),
+                constraint.max.min(constraint.max_percent * size / 100 as Coord),
+            ),
+            None => (constraint.min, constraint.max),
+        }
+    }
+
+    /// The LayoutData of the items along the main axis, where the preferred size is the flex basis
+    pub fn main_layout_data(
+        cells: &[FlexboxLayoutCellData],
+        size: Option<Coord>,
+    ) -> Vec<LayoutData> {
+        cells
+            .iter()
+            .map(|c| {
+                let (min, max) = min_max(&c.constraint, size);
+                let basis = if c.basis >= 0 as Coord { c.basis } else { c.constraint.preferred };
+                LayoutData {
+                    min,
+                    max,
+                    pref: basis.min(max).max(min),
+                    stretch: c.grow,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    /// The LayoutData of the items along the cross axis
+    pub fn cross_layout_data(cells: &[BoxLayoutCellData], size: Option<Coord>) -> Vec<LayoutData> {
+        cells
+            .iter()
+            .map(|c| {
+                let (min, max) = min_max(&c.constraint, size);
+                LayoutData {
+                    min,
+                    max,
+                    pref: c.constraint.preferred.min(max).max(min),
+                    stretch: c.constraint.stretch,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    /// Split the items in lines so that the preferred size of each line fits in `available`.
+    /// A line always contains at least one item.
+    pub fn break_lines(
+        items: &[LayoutData],
+        wrap: FlexWrap,
+        available: Coord,
+        spacing: Coord,
+    ) -> Vec<Range<usize>> {
+        let mut lines = Vec::new();
+        let Some(first) = items.first() else { return lines };
+        if wrap == FlexWrap::NoWrap {
+            lines.push(0..items.len());
+            return lines;
+        }
+        let mut start = 0;
+        let mut used = first.pref;
+        for (idx, it) in items.iter().enumerate().skip(1) {
+            if used + spacing + it.pref > available {
+                lines.push(start..idx);
+                start = idx;
+                used = it.pref;
+            } else {
+                used += spacing + it.pref;
+            }
+        }
+        lines.push(start..items.len());
+        lines
+    }
+
+    /// The LayoutData of each line along the cross axis: the minimum and preferred size of a line
+    /// is the biggest minimum and preferred size of its items.
+    pub fn lines_layout_data(
+        lines: &[Range<usize>],
+        cross_items: &[LayoutData],
+    ) -> Vec<LayoutData> {
+        lines
+            .iter()
+            .map(|line| {
+                let items = cross_items.get(line.clone()).unwrap_or_default();
+                let min = items.iter().map(|it| it.min).fold(0 as Coord, |a, b| a.max(b));
+                let pref = items.iter().map(|it| it.pref).fold(min, |a, b| a.max(b));
+                LayoutData { min, pref, stretch: 1., ..Default::default() }
+            })
+            .collect()
+    }
+
+    /// Set the position and the size of the items within `size`.
+    ///
+    /// The items are grown (if `grow` is true) or shrunk (if they don't fit) according to their
+    /// stretch factor, then the remaining space is distributed according to the alignment.
+    fn place_items(
+        items: &mut [LayoutData],
+        alignment: LayoutAlignment,
+        grow: bool,
+        start: Coord,
+        size: Coord,
+        spacing: Coord,
+    ) {
+        if items.is_empty() {
+            return;
+        }
+        let count = items.len();
+        let spacings = spacing * (count - 1) as Coord;
+        let pref_size = items.iter().map(|it| it.pref).sum::<Coord>();
+        if grow || pref_size + spacings > size {
+            grid_internal::layout_items(items, start, size, spacing);
+        } else {
+            for it in items.iter_mut() {
+                it.size = it.pref;
+            }
+        }
+
+        let free = size - items.iter().map(|it| it.size).sum::<Coord>() - spacings;
+        let (mut pos, spacing) = if free <= 0 as Coord {
+            (start, spacing)
+        } else {
+            match alignment {
+                LayoutAlignment::Stretch | LayoutAlignment::Start => (start, spacing),
+                LayoutAlignment::Center => (start + free / 2 as Coord, spacing),
+                LayoutAlignment::End => (start + free, spacing),
+                LayoutAlignment::SpaceBetween if count > 1 => {
+                    (start, spacing + free / (count - 1) as Coord)
+                }
+                LayoutAlignment::SpaceBetween => (start, spacing),
+                LayoutAlignment::SpaceAround => {
+                    let extra = free / count as Coord;
+                    (start + extra / 2 as Coord, spacing + extra)
+                }
+            }
+        };
+        for it in items.iter_mut() {
+            it.pos = pos;
+            pos += it.size + spacing;
+        }
+    }
+
+    /// Mirror the position of the items within `size`
+    fn reverse(items: &mut [LayoutData], start: Coord, size: Coord) {
+        for it in items.iter_mut() {
+            it.pos = start + start + size - it.pos - it.size;
+        }
+    }
+
+    pub fn solve_main_axis(data: &FlexboxLayoutData) -> Vec<LayoutData> {
+        let cells = data.cells.as_slice();
+        let mut items = main_layout_data(cells, Some(data.main_size));
+        let size = data.main_size - data.main_padding.begin - data.main_padding.end;
+        for line in break_lines(&items, data.wrap, size, data.spacing) {
+            let line_items = &mut items[line.clone()];
+            let line_cells = &cells[line];
+            let pref_size = line_items.iter().map(|it| it.pref).sum::<Coord>()
+                + data.spacing * (line_items.len() - 1) as Coord;
+            let any_grow = line_cells.iter().any(|c| c.grow > 0.);
+            if pref_size > size {
+                for (it, c) in line_items.iter_mut().zip(line_cells) {
+                    it.stretch = c.shrink;
+                    if c.shrink <= 0. {
+                        it.min = it.pref;
+                    }
+                }
+            } else if any_grow {
+                for (it, c) in line_items.iter_mut().zip(line_cells) {
+                    it.stretch = c.grow;
+                    if c.grow <= 0. {
+                        it.max = it.pref;
+                    }
+                }
+            } else {
+                // With the stretch alignment, and no item that grows, all the items share the remaining space
+                for it in line_items.iter_mut() {
+                    it.stretch = 1.;
+                }
+            }
+            place_items(
+                line_items,
+                data.justify_content,
+                any_grow || data.justify_content == LayoutAlignment::Stretch,
+                data.main_padding.begin,
+                size,
+                data.spacing,
+            );
+        }
+        if matches!(data.direction, FlexDirection::RowReverse | FlexDirection::ColumnReverse) {
+            reverse(&mut items, data.main_padding.begin, size);
+        }
+        items
+    }
+
+    pub fn solve_cross_axis(data: &FlexboxLayoutData) -> Vec<LayoutData> {
+        let main_items = main_layout_data(data.cells.as_slice(), Some(data.main_size));
+        let main_size = data.main_size - data.main_padding.begin - data.main_padding.end;
+        let lines = break_lines(&main_items, data.wrap, main_size, data.spacing);
+
+        let mut items = cross_layout_data(data.cross_cells.as_slice(), Some(data.cross_size));
+        let size = data.cross_size - data.cross_padding.begin - data.cross_padding.end;
+        let mut line_data = lines_layout_data(&lines, &items);
+        if data.wrap == FlexWrap::NoWrap {
+            // A single line takes all the space
+            for l in line_data.iter_mut() {
+                l.pos = data.cross_padding.begin;
+                l.size = size;
+            }
+        } else {
+            place_items(
+                &mut line_data,
+                data.align_content,
+                data.align_content == LayoutAlignment::Stretch,
+                data.cross_padding.begin,
+                size,
+                data.spacing,
+            );
+            if data.wrap == FlexWrap::WrapReverse {
+                reverse(&mut line_data, data.cross_padding.begin, size);
+            }
+        }
+
+        for (line, l) in lines.into_iter().zip(line_data.iter()) {
+            for it in items.get_mut(line).unwrap_or_default() {
+                it.pos = l.pos;
+                it.size = l.size.min(it.max).max(it.min);
+            }
+        }
+        items
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // We want bit-wise equality here
+    fn test_flexbox_wrap() {
+        let cell = |preferred: Coord, grow: f32| FlexboxLayoutCellData {
+            constraint: LayoutInfo { preferred, stretch: 1., ..Default::default() },
+            grow,
+            ..Default::default()
+        };
+        let cross_cell =
+            BoxLayoutCellData { constraint: LayoutInfo { preferred: 50., ..Default::default() } };
+        let cells = [cell(100., 0.), cell(100., 0.), cell(100., 0.)];
+        let cross_cells = [cross_cell.clone(), cross_cell.clone(), cross_cell];
+        let mut data = FlexboxLayoutData {
+            main_size: 250.,
+            cross_size: 200.,
+            spacing: 0.,
+            main_padding: Default::default(),
+            cross_padding: Default::default(),
+            direction: FlexDirection::Row,
+            wrap: FlexWrap::Wrap,
+            justify_content: LayoutAlignment::Start,
+            align_content: LayoutAlignment::Start,
+            cells: Slice::from_slice(&cells),
+            cross_cells: Slice::from_slice(&cross_cells),
+        };
+
+        let main = solve_main_axis(&data);
+        assert_eq!(
+            main.iter().map(|it| (it.pos, it.size)).collect::<Vec<_>>(),
+            [(0., 100.), (100., 100.), (0., 100.)]
+        );
+        let cross = solve_cross_axis(&data);
+        assert_eq!(
+            cross.iter().map(|it| (it.pos, it.size)).collect::<Vec<_>>(),
+            [(0., 50.), (0., 50.), (50., 50.)]
+        );
+
+        // The lines are stretched to fill the layout
+        data.align_content = LayoutAlignment::Stretch;
+        let cross = solve_cross_axis(&data);
+        assert_eq!(
+            cross.iter().map(|it| (it.pos, it.size)).collect::<Vec<_>>(),
+            [(0., 100.), (0., 100.), (100., 100.)]
+        );
+
+        // Only the items with a flex-grow take the remaining space of the line
+        let cells = [cell(100., 0.), cell(100., 1.), cell(100., 0.)];
+        data.cells = Slice::from_slice(&cells);
+        data.justify_content = LayoutAlignment::End;
+        let main = solve_main_axis(&data);
+        assert_eq!(
+            main.iter().map(|it| (it.pos, it.size)).collect::<Vec<_>>(),
+            [(0., 100.), (100., 150.), (150., 100.)]
+        );
+
+        // The items shrink when they don't fit on a single line
+        data.main_size = 270.;
+        data.direction = FlexDirection::RowReverse;
+        data.wrap = FlexWrap::NoWrap;
+        let main = solve_main_axis(&data);
+        assert_eq!(
+            main.iter().map(|it| (it.pos, it.size)).collect::<Vec<_>>(),
+            [(180., 90.), (90., 90.), (0., 90.)]
+        );
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+/// The FlexboxLayoutData is used to represent a FlexboxLayout.
+/// The main axis is the horizontal axis for the `row` and `row-reverse` directions, and the
+/// vertical axis otherwise. The cross axis is the other one.
+pub struct FlexboxLayoutData<'a> {
+    /// The size of the layout along the main axis
+    pub main_size: Coord,
+    /// The size of the layout along the cross axis
+    pub cross_size: Coord,
+    pub spacing: Coord,
+    pub main_padding: Padding,
+    pub cross_padding: Padding,
+    pub direction: FlexDirection,
+    pub wrap: FlexWrap,
+    pub justify_content: LayoutAlignment,
+    pub align_content: LayoutAlignment,
+    /// The constraint along the main axis and the flex properties of each item
+    pub cells: Slice<'a, FlexboxLayoutCellData>,
+    /// The constraint along the cross axis of each item. This is only needed to solve the layout
+    /// or to compute its LayoutInfo along the cross axis, and is empty otherwise.
+    pub cross_cells: Slice<'a, BoxLayoutCellData>,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct FlexboxLayoutCellData {
+    /// The constraint along the main axis
+    pub constraint: LayoutInfo,
+    /// The `flex-grow` factor
+    pub grow: f32,
+    /// The `flex-shrink` factor
+    pub shrink: f32,
+    /// The `flex-basis`, or a negative value to use the preferred size
+    pub basis: Coord,
+}
+
+impl Default for FlexboxLayoutCellData {
+    fn default() -> Self {
+        FlexboxLayoutCellData {
+            constraint: Default::default(),
+            grow: 0.,
+            shrink: 1.,
+            basis: -1 as _,
+        }
+    }
+}
+
+/// Solve a FlexboxLayout along its main axis.
+///
+/// The result contains the position and size of each item along the main axis, and is laid out
+/// like the result of [`solve_box_layout`]. The `cross_cells` are not used.
+pub fn solve_flexbox_layout(
+    data: &FlexboxLayoutData,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    layout_cache_with_repeaters(&flexbox_internal::solve_main_axis(data), repeater_indexes)
+}
+
+/// Solve a FlexboxLayout along its cross axis.
+///
+/// The result contains the position and size of each item along the cross axis, and is laid out
+/// like the result of [`solve_box_layout`].
+pub fn solve_flexbox_layout_cross(
+    data: &FlexboxLayoutData,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    layout_cache_with_repeaters(&flexbox_internal::solve_cross_axis(data), repeater_indexes)
+}
+
+/// Return the LayoutInfo for a FlexboxLayout along its main axis. The `cross_cells` are not used.
+pub fn flexbox_layout_info(data: &FlexboxLayoutData) -> LayoutInfo {
+    let count = data.cells.len();
+    if count < 1 {
+        return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+    };
+    let items = flexbox_internal::main_layout_data(data.cells.as_slice(), None);
+    let spacings = data.spacing * (count - 1) as Coord;
+    let extra_w = data.main_padding.begin + data.main_padding.end;
+    let min = if data.wrap == FlexWrap::NoWrap {
+        items.iter().map(|it| it.min).sum::<Coord>() + spacings
+    } else {
+        items.iter().map(|it| it.min).fold(0 as Coord, |a, b| a.max(b))
+    } + extra_w;
+    let preferred = items.iter().map(|it| it.pref).sum::<Coord>() + spacings + extra_w;
+    let stretch = data.cells.iter().map(|c| c.constraint.stretch).sum::<f32>();
+    LayoutInfo {
+        min,
+        max: Coord::MAX,
+        min_percent: 0 as _,
+        max_percent: 100 as _,
+        preferred,
+        stretch,
+    }
+}
+
+/// Return the LayoutInfo for a FlexboxLayout along its cross axis.
+///
+/// This depends on the `main_size`, which decides how the items wrap.
+pub fn flexbox_layout_info_cross(data: &FlexboxLayoutData) -> LayoutInfo {
+    if data.cells.is_empty() {
+        return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+    };
+    let main_items =
+        flexbox_internal::main_layout_data(data.cells.as_slice(), Some(data.main_size));
+    let lines = flexbox_internal::break_lines(
+        &main_items,
+        data.wrap,
+        data.main_size - data.main_padding.begin - data.main_padding.end,
+        data.spacing,
+    );
+    let items = flexbox_internal::cross_layout_data(data.cross_cells.as_slice(), None);
+    let line_data = flexbox_internal::lines_layout_data(&lines, &items);
+    let extra_w = data.cross_padding.begin
+        + data.cross_padding.end
+        + data.spacing * (line_data.len() - 1) as Coord;
+    let min = line_data.iter().map(|l| l.min).sum::<Coord>() + extra_w;
+    let preferred = line_data.iter().map(|l| l.pref).sum::<Coord>() + extra_w;
+    let stretch = data.cross_cells.iter().map(|c| c.constraint.stretch).fold(f32::MAX, f32::min);
+    LayoutInfo {
+        min,
+        max: Coord::MAX,
+        min_percent: 0 as _,
+        max_percent: 100 as _,
+        preferred,
+        stretch,
+    }
+}
+
 /// Given the cells of a layout of a Dialog, re-order the button according to the platform
 ///
 /// This function assume that the `roles` contains the roles of the button which are the first `cells`
@@ -772,6 +1218,36 @@ pub extern "C" fn slint_box_layout_info_ortho(
         super::box_layout_info_ortho(cells, padding)
     }
 
+    #[no_mangle]
+    pub extern "C" fn slint_solve_flexbox_layout(
+        data: &FlexboxLayoutData,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flexbox_layout(data, repeater_indexes)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_solve_flexbox_layout_cross(
+        data: &FlexboxLayoutData,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flexbox_layout_cross(data, repeater_indexes)
+    }
+
+    #[no_mangle]
+    /// Return the LayoutInfo for a FlexboxLayout along its main axis.
+    pub extern "C" fn slint_flexbox_layout_info(data: &FlexboxLayoutData) -> LayoutInfo {
+        super::flexbox_layout_info(data)
+    }
+
+    #[no_mangle]
+    /// Return the LayoutInfo for a FlexboxLayout along its cross axis.
+    pub extern "C" fn slint_flexbox_layout_info_cross(data: &FlexboxLayoutData) -> LayoutInfo {
+        super::flexbox_layout_info_cross(data)
+    }
+
     /// Calls [`reorder_dialog_button_layout`].
     ///
     /// Safety: `cells` must be a pointer to a mutable array of cell data, the array must have at
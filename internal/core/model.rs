@@ -7,11 +7,14 @@
 
 use crate::component::ComponentVTable;
 use crate::item_tree::TraversalOrder;
-pub use crate::items::{StandardListViewItem, TableColumn};
+pub use crate::items::{StandardListViewItem, TableColumn, TreeViewItem};
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
-pub use adapters::{FilterModel, MapModel, ReverseModel, SortModel};
+pub use adapters::{
+    ConcatModel, FilterModel, FlattenModel, GroupByModel, MapModel, ModelGroup, ReverseModel,
+    SkipModel, SortModel, TakeModel, ZipModel,
+};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
@@ -20,12 +23,19 @@
 use euclid::num::Zero;
 #[allow(unused)]
 use euclid::num::{Ceil, Floor};
+pub use lazy::{LazyModel, LazyPageRequest};
 pub use model_peer::*;
 use once_cell::unsync::OnceCell;
 use pin_project::pin_project;
+pub use tree::{
+    FlattenedTreeModel, FlattenedTreeRow, TreeModel, TreeModelChangeListener, TreeModelNotify,
+    TreeModelTracker, TreeNode, VecTreeModel,
+};
 
 mod adapters;
+mod lazy;
 mod model_peer;
+mod tree;
 
 type ComponentRc<C> = vtable::VRc<crate::component::ComponentVTable, C>;
 
@@ -245,6 +255,65 @@ fn reverse(self) -> ReverseModel<Self>
     {
         ReverseModel::new(self)
     }
+
+    /// Returns a new Model with the elements of this model followed by the elements of `other`.
+    /// This is a shortcut for [`ConcatModel::new()`].
+    fn concat<M>(self, other: M) -> ConcatModel<Self::Data>
+    where
+        Self: Sized + 'static,
+        M: Model<Data = Self::Data> + 'static,
+    {
+        ConcatModel::new([ModelRc::new(self), ModelRc::new(other)])
+    }
+
+    /// Returns a new Model with the elements of all the models that are the elements of this model.
+    /// This is a shortcut for [`FlattenModel::new()`].
+    fn flatten(self) -> FlattenModel<Self>
+    where
+        Self: Sized + 'static,
+        Self::Data: Model + 'static,
+    {
+        FlattenModel::new(self)
+    }
+
+    /// Returns a new Model where the elements are grouped by the key returned by `key_function`.
+    /// This is a shortcut for [`GroupByModel::new()`].
+    fn group_by<F, K>(self, key_function: F) -> GroupByModel<Self, F, K>
+    where
+        Self: Sized + 'static,
+        F: Fn(&Self::Data) -> K + 'static,
+        K: Ord + Clone + 'static,
+    {
+        GroupByModel::new(self, key_function)
+    }
+
+    /// Returns a new Model with at most the first `count` elements.
+    /// This is a shortcut for [`TakeModel::new()`].
+    fn take(self, count: usize) -> TakeModel<Self>
+    where
+        Self: Sized + 'static,
+    {
+        TakeModel::new(self, count)
+    }
+
+    /// Returns a new Model without the first `count` elements.
+    /// This is a shortcut for [`SkipModel::new()`].
+    fn skip(self, count: usize) -> SkipModel<Self>
+    where
+        Self: Sized + 'static,
+    {
+        SkipModel::new(self, count)
+    }
+
+    /// Returns a new Model where the elements are the pairs of the elements of this model and of `other`.
+    /// This is a shortcut for [`ZipModel::new()`].
+    fn zip<M>(self, other: M) -> ZipModel<Self, M>
+    where
+        Self: Sized + 'static,
+        M: Model + 'static,
+    {
+        ZipModel::new(self, other)
+    }
 }
 
 impl<T: Model> ModelExt for T {}
@@ -715,6 +784,14 @@ fn box_layout_data(
     ) -> crate::layout::BoxLayoutCellData {
         crate::layout::BoxLayoutCellData::default()
     }
+
+    /// Returns what's needed to perform the layout if this component is in a flexbox layout
+    fn flexbox_layout_data(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+    ) -> crate::layout::FlexboxLayoutCellData {
+        crate::layout::FlexboxLayoutCellData::default()
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -1213,6 +1290,43 @@ fn from(value: &str) -> Self {
     }
 }
 
+/// A [`ModelChangeListener`] that records the notifications, for the tests of the models
+#[cfg(test)]
+#[derive(Default)]
+struct TestView {
+    changed_rows: RefCell<Vec<usize>>,
+    added_rows: RefCell<Vec<(usize, usize)>>,
+    removed_rows: RefCell<Vec<(usize, usize)>>,
+    reset: RefCell<usize>,
+}
+
+#[cfg(test)]
+impl TestView {
+    fn clear(&self) {
+        self.changed_rows.borrow_mut().clear();
+        self.added_rows.borrow_mut().clear();
+        self.removed_rows.borrow_mut().clear();
+    }
+}
+
+#[cfg(test)]
+impl ModelChangeListener for TestView {
+    fn row_changed(&self, row: usize) {
+        self.changed_rows.borrow_mut().push(row);
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        self.added_rows.borrow_mut().push((index, count));
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        self.removed_rows.borrow_mut().push((index, count));
+    }
+    fn reset(&self) {
+        *self.reset.borrow_mut() += 1;
+    }
+}
+
 #[test]
 fn test_tracking_model_handle() {
     let model: Rc<VecModel<u8>> = Rc::new(Default::default());
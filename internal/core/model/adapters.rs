@@ -4,45 +4,7 @@
 //! This module contains adapter models.
 
 use super::*;
-
-#[cfg(test)]
-#[derive(Default)]
-struct TestView {
-    // Track the parameters reported by the model (row counts, indices, etc.).
-    // The last field in the tuple is the row size the model reports at the time
-    // of callback
-    changed_rows: RefCell<Vec<usize>>,
-    added_rows: RefCell<Vec<(usize, usize)>>,
-    removed_rows: RefCell<Vec<(usize, usize)>>,
-    reset: RefCell<usize>,
-}
-
-#[cfg(test)]
-impl TestView {
-    fn clear(&self) {
-        self.changed_rows.borrow_mut().clear();
-        self.added_rows.borrow_mut().clear();
-        self.removed_rows.borrow_mut().clear();
-    }
-}
-
-#[cfg(test)]
-impl ModelChangeListener for TestView {
-    fn row_changed(&self, row: usize) {
-        self.changed_rows.borrow_mut().push(row);
-    }
-
-    fn row_added(&self, index: usize, count: usize) {
-        self.added_rows.borrow_mut().push((index, count));
-    }
-
-    fn row_removed(&self, index: usize, count: usize) {
-        self.removed_rows.borrow_mut().push((index, count));
-    }
-    fn reset(&self) {
-        *self.reset.borrow_mut() += 1;
-    }
-}
+use alloc::rc::Weak;
 
 /// Provides rows that are generated by a map function based on the rows of another Model
 ///
@@ -1188,3 +1150,1556 @@ fn test_reversed_model_changed() {
         }
     }
 }
+
+/// Notifies `notify` that the `old_count` rows starting at `offset` were replaced by `new_count` rows.
+fn notify_rows_replaced(notify: &ModelNotify, offset: usize, old_count: usize, new_count: usize) {
+    for row in offset..offset + old_count.min(new_count) {
+        notify.row_changed(row);
+    }
+    if new_count > old_count {
+        notify.row_added(offset + old_count, new_count - old_count);
+    } else if old_count > new_count {
+        notify.row_removed(offset + new_count, old_count - new_count);
+    }
+}
+
+/// Like [`ModelChangeListener`], for adapters that are built from several models.
+/// `source` identifies the model that sent the notification.
+trait MultiModelChangeListener {
+    fn row_changed(&self, source: usize, row: usize);
+    fn row_added(&self, source: usize, index: usize, count: usize);
+    fn row_removed(&self, source: usize, index: usize, count: usize);
+    fn reset(&self, source: usize);
+}
+
+/// Forwards the notifications of one of the models of an adapter to that adapter.
+struct SourceListener<A> {
+    adapter: Weak<A>,
+    source: Cell<usize>,
+}
+
+impl<A: MultiModelChangeListener> ModelChangeListener for SourceListener<A> {
+    fn row_changed(&self, row: usize) {
+        if let Some(adapter) = self.adapter.upgrade() {
+            adapter.row_changed(self.source.get(), row);
+        }
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        if let Some(adapter) = self.adapter.upgrade() {
+            adapter.row_added(self.source.get(), index, count);
+        }
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        if let Some(adapter) = self.adapter.upgrade() {
+            adapter.row_removed(self.source.get(), index, count);
+        }
+    }
+
+    fn reset(&self) {
+        if let Some(adapter) = self.adapter.upgrade() {
+            adapter.reset(self.source.get());
+        }
+    }
+}
+
+type SourcePeer<A> = Pin<Box<ModelChangeListenerContainer<SourceListener<A>>>>;
+
+fn source_peer<A: MultiModelChangeListener + 'static>(
+    adapter: &Weak<A>,
+    source: usize,
+) -> SourcePeer<A> {
+    Box::pin(ModelChangeListenerContainer::new(SourceListener {
+        adapter: adapter.clone(),
+        source: Cell::new(source),
+    }))
+}
+
+struct FlattenSubModel<M>
+where
+    M: Model + 'static,
+    M::Data: Model + 'static,
+{
+    model: M::Data,
+    // The row count of `model` as of the last notification we got from it
+    row_count: usize,
+    peer: SourcePeer<FlattenModelInner<M>>,
+}
+
+struct FlattenOuterListener<M>(Weak<FlattenModelInner<M>>)
+where
+    M: Model + 'static,
+    M::Data: Model + 'static;
+
+struct FlattenModelInner<M>
+where
+    M: Model + 'static,
+    M::Data: Model + 'static,
+{
+    wrapped_model: M,
+    outer_peer: Pin<Box<ModelChangeListenerContainer<FlattenOuterListener<M>>>>,
+    sub_models: RefCell<Vec<FlattenSubModel<M>>>,
+    notify: ModelNotify,
+}
+
+impl<M> FlattenModelInner<M>
+where
+    M: Model + 'static,
+    M::Data: Model + 'static,
+{
+    fn create_sub_model(&self, source: usize) -> FlattenSubModel<M> {
+        let model = self.wrapped_model.row_data(source).unwrap();
+        let peer = source_peer(&self.outer_peer.0, source);
+        model.model_tracker().attach_peer(peer.as_ref().model_peer());
+        FlattenSubModel { row_count: model.row_count(), model, peer }
+    }
+
+    fn build_sub_models(&self) {
+        let sub_models =
+            (0..self.wrapped_model.row_count()).map(|i| self.create_sub_model(i)).collect();
+        *self.sub_models.borrow_mut() = sub_models;
+    }
+
+    /// Returns the index of the first row of the sub model `source`
+    fn offset(sub_models: &[FlattenSubModel<M>], source: usize) -> usize {
+        sub_models[..source].iter().map(|sub_model| sub_model.row_count).sum()
+    }
+
+    /// Returns the index of the sub model that contains `row`, and the row within that sub model
+    fn locate(&self, mut row: usize) -> Option<(usize, usize)> {
+        for (source, sub_model) in self.sub_models.borrow().iter().enumerate() {
+            if row < sub_model.row_count {
+                return Some((source, row));
+            }
+            row -= sub_model.row_count;
+        }
+        None
+    }
+
+    fn update_sources(sub_models: &[FlattenSubModel<M>], from: usize) {
+        for (source, sub_model) in sub_models.iter().enumerate().skip(from) {
+            sub_model.peer.source.set(source);
+        }
+    }
+
+    fn outer_row_changed(&self, row: usize) {
+        let sub_model = self.create_sub_model(row);
+        let new_count = sub_model.row_count;
+        let mut sub_models = self.sub_models.borrow_mut();
+        let old_count = core::mem::replace(&mut sub_models[row], sub_model).row_count;
+        let offset = Self::offset(&sub_models, row);
+        drop(sub_models);
+        notify_rows_replaced(&self.notify, offset, old_count, new_count);
+    }
+
+    fn outer_row_added(&self, index: usize, count: usize) {
+        let inserted: Vec<_> = (index..index + count).map(|i| self.create_sub_model(i)).collect();
+        let added_rows: usize = inserted.iter().map(|sub_model| sub_model.row_count).sum();
+
+        let mut sub_models = self.sub_models.borrow_mut();
+        let offset = Self::offset(&sub_models, index);
+        sub_models.splice(index..index, inserted);
+        Self::update_sources(&sub_models, index + count);
+        drop(sub_models);
+
+        if added_rows > 0 {
+            self.notify.row_added(offset, added_rows);
+        }
+    }
+
+    fn outer_row_removed(&self, index: usize, count: usize) {
+        let mut sub_models = self.sub_models.borrow_mut();
+        let offset = Self::offset(&sub_models, index);
+        let removed: Vec<_> = sub_models.drain(index..index + count).collect();
+        Self::update_sources(&sub_models, index);
+        drop(sub_models);
+
+        let removed_rows: usize = removed.iter().map(|sub_model| sub_model.row_count).sum();
+        drop(removed);
+
+        if removed_rows > 0 {
+            self.notify.row_removed(offset, removed_rows);
+        }
+    }
+
+    fn outer_reset(&self) {
+        self.build_sub_models();
+        self.notify.reset();
+    }
+}
+
+impl<M> MultiModelChangeListener for FlattenModelInner<M>
+where
+    M: Model + 'static,
+    M::Data: Model + 'static,
+{
+    fn row_changed(&self, source: usize, row: usize) {
+        let offset = Self::offset(&self.sub_models.borrow(), source);
+        self.notify.row_changed(offset + row);
+    }
+
+    fn row_added(&self, source: usize, index: usize, count: usize) {
+        let mut sub_models = self.sub_models.borrow_mut();
+        sub_models[source].row_count += count;
+        let offset = Self::offset(&sub_models, source);
+        drop(sub_models);
+        self.notify.row_added(offset + index, count);
+    }
+
+    fn row_removed(&self, source: usize, index: usize, count: usize) {
+        let mut sub_models = self.sub_models.borrow_mut();
+        sub_models[source].row_count -= count;
+        let offset = Self::offset(&sub_models, source);
+        drop(sub_models);
+        self.notify.row_removed(offset + index, count);
+    }
+
+    fn reset(&self, source: usize) {
+        let mut sub_models = self.sub_models.borrow_mut();
+        let sub_model = &mut sub_models[source];
+        let old_count = sub_model.row_count;
+        let new_count = sub_model.model.row_count();
+        sub_model.row_count = new_count;
+        let offset = Self::offset(&sub_models, source);
+        drop(sub_models);
+        notify_rows_replaced(&self.notify, offset, old_count, new_count);
+    }
+}
+
+impl<M> ModelChangeListener for FlattenOuterListener<M>
+where
+    M: Model + 'static,
+    M::Data: Model + 'static,
+{
+    fn row_changed(&self, row: usize) {
+        if let Some(inner) = self.0.upgrade() {
+            inner.outer_row_changed(row);
+        }
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        if let Some(inner) = self.0.upgrade() {
+            inner.outer_row_added(index, count);
+        }
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        if let Some(inner) = self.0.upgrade() {
+            inner.outer_row_removed(index, count);
+        }
+    }
+
+    fn reset(&self) {
+        if let Some(inner) = self.0.upgrade() {
+            inner.outer_reset();
+        }
+    }
+}
+
+/// Provides the rows of all the models that are the rows of another Model, one after the other.
+///
+/// When the other Model, or any of the models it contains, is updated, the `FlattenModel` is
+/// updated accordingly.
+///
+/// Generic parameters:
+/// * `M` the type of the wrapped `Model`. Its rows are models themselves.
+///
+/// ## Example
+///
+/// Here we have a [`VecModel`] holding [`ModelRc`]s of [`crate::SharedString`]s.
+/// It is then flattened into a `FlattenModel`.
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{Model, ModelRc, VecModel, SharedString, FlattenModel};
+/// let fruits = Rc::new(VecModel::from(vec![SharedString::from("apple")]));
+/// let vegetables = Rc::new(VecModel::from(vec![
+///     SharedString::from("leek"),
+///     SharedString::from("carrot"),
+/// ]));
+///
+/// let model = FlattenModel::new(VecModel::from(vec![
+///     ModelRc::from(fruits.clone()),
+///     ModelRc::from(vegetables.clone()),
+/// ]));
+///
+/// assert_eq!(model.row_count(), 3);
+/// assert_eq!(model.row_data(0).unwrap(), SharedString::from("apple"));
+/// assert_eq!(model.row_data(2).unwrap(), SharedString::from("carrot"));
+///
+/// fruits.push(SharedString::from("pear"));
+///
+/// assert_eq!(model.row_data(1).unwrap(), SharedString::from("pear"));
+/// assert_eq!(model.row_data(2).unwrap(), SharedString::from("leek"));
+/// ```
+///
+/// Alternatively you can use the shortcut [`ModelExt::flatten`].
+pub struct FlattenModel<M>(Rc<FlattenModelInner<M>>)
+where
+    M: Model + 'static,
+    M::Data: Model + 'static;
+
+impl<M> FlattenModel<M>
+where
+    M: Model + 'static,
+    M::Data: Model + 'static,
+{
+    /// Creates a new FlattenModel based on the given `wrapped_model`.
+    /// Alternativly you can use [`ModelExt::flatten`] on your Model.
+    pub fn new(wrapped_model: M) -> Self {
+        let inner = Rc::new_cyclic(|weak| FlattenModelInner {
+            wrapped_model,
+            outer_peer: Box::pin(ModelChangeListenerContainer::new(FlattenOuterListener(
+                weak.clone(),
+            ))),
+            sub_models: Default::default(),
+            notify: Default::default(),
+        });
+
+        inner.build_sub_models();
+        inner.wrapped_model.model_tracker().attach_peer(inner.outer_peer.as_ref().model_peer());
+
+        Self(inner)
+    }
+}
+
+impl<M> Model for FlattenModel<M>
+where
+    M: Model + 'static,
+    M::Data: Model + 'static,
+{
+    type Data = <M::Data as Model>::Data;
+
+    fn row_count(&self) -> usize {
+        self.0.sub_models.borrow().iter().map(|sub_model| sub_model.row_count).sum()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let (source, row) = self.0.locate(row)?;
+        self.0.sub_models.borrow()[source].model.row_data(row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if let Some((source, row)) = self.0.locate(row) {
+            // Don't keep the sub models borrowed, setting the data notifies us
+            if let Some(sub_model) = self.0.wrapped_model.row_data(source) {
+                sub_model.set_row_data(row, data);
+            }
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+}
+
+/// Provides the rows of several models, one after the other.
+///
+/// When one of the models is updated, the `ConcatModel` is updated accordingly.
+///
+/// Generic parameters:
+/// * `T` the type of the rows.
+///
+/// ## Example
+///
+/// Here we have two [`VecModel`]s holding [`crate::SharedString`]s.
+/// They are then concatenated into a `ConcatModel`.
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{Model, ModelRc, VecModel, SharedString, ConcatModel};
+/// let pinned = Rc::new(VecModel::from(vec![SharedString::from("Lorem")]));
+/// let others = Rc::new(VecModel::from(vec![
+///     SharedString::from("ipsum"),
+///     SharedString::from("dolor"),
+/// ]));
+///
+/// let model = ConcatModel::new([ModelRc::from(pinned.clone()), ModelRc::from(others.clone())]);
+///
+/// assert_eq!(model.row_count(), 3);
+/// assert_eq!(model.row_data(0).unwrap(), SharedString::from("Lorem"));
+/// assert_eq!(model.row_data(1).unwrap(), SharedString::from("ipsum"));
+///
+/// others.insert(0, SharedString::from("sit"));
+///
+/// assert_eq!(model.row_data(1).unwrap(), SharedString::from("sit"));
+/// assert_eq!(model.row_data(3).unwrap(), SharedString::from("dolor"));
+/// ```
+///
+/// Alternatively you can use the shortcut [`ModelExt::concat`] to concatenate two models.
+/// ```
+/// # use slint::{Model, ModelExt, VecModel, SharedString};
+/// let model = VecModel::from(vec![SharedString::from("Lorem")])
+///     .concat(VecModel::from(vec![SharedString::from("ipsum")]));
+/// assert_eq!(model.row_data(0).unwrap(), SharedString::from("Lorem"));
+/// assert_eq!(model.row_data(1).unwrap(), SharedString::from("ipsum"));
+/// ```
+pub struct ConcatModel<T: 'static>(FlattenModel<VecModel<ModelRc<T>>>);
+
+impl<T: 'static> ConcatModel<T> {
+    /// Creates a new ConcatModel with the rows of all the `models`.
+    /// Alternativly you can use [`ModelExt::concat`] on your Model.
+    pub fn new(models: impl IntoIterator<Item = ModelRc<T>>) -> Self {
+        Self(FlattenModel::new(VecModel::from(models.into_iter().collect::<Vec<_>>())))
+    }
+}
+
+impl<T: 'static> Model for ConcatModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.0.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.0.row_data(row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        self.0.set_row_data(row, data);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        self.0.model_tracker()
+    }
+}
+
+#[cfg(test)]
+mod flatten_tests {
+    use super::*;
+
+    #[track_caller]
+    fn check_content(model: &impl Model<Data = i32>, expected: &[i32]) {
+        assert_eq!(model.row_count(), expected.len());
+        for (i, v) in expected.iter().enumerate() {
+            assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
+        }
+    }
+
+    #[test]
+    fn test_concat_model() {
+        let first = Rc::new(VecModel::from(vec![1, 2]));
+        let second = Rc::new(VecModel::from(vec![3, 4, 5]));
+        let model = ConcatModel::new([ModelRc::from(first.clone()), ModelRc::from(second.clone())]);
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        check_content(&model, &[1, 2, 3, 4, 5]);
+
+        second.insert(1, 10);
+        check_content(&model, &[1, 2, 3, 10, 4, 5]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(3, 1)]);
+
+        first.remove(0);
+        check_content(&model, &[2, 3, 10, 4, 5]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(0, 1)]);
+
+        second.set_row_data(0, 30);
+        check_content(&model, &[2, 30, 10, 4, 5]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[1]);
+
+        model.set_row_data(0, 20);
+        check_content(&model, &[20, 30, 10, 4, 5]);
+        assert_eq!(first.row_data(0), Some(20));
+
+        observer.clear();
+        first.set_vec(vec![7, 8, 9]);
+        check_content(&model, &[7, 8, 9, 30, 10, 4, 5]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[0]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(1, 2)]);
+        assert!(observer.removed_rows.borrow().is_empty());
+        assert_eq!(*observer.reset.borrow(), 0);
+    }
+
+    #[test]
+    fn test_flatten_model_outer_changes() {
+        let outer = Rc::new(VecModel::from(vec![
+            ModelRc::new(VecModel::from(vec![1, 2])),
+            ModelRc::new(VecModel::from(vec![3])),
+        ]));
+        let model = outer.clone().flatten();
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        check_content(&model, &[1, 2, 3]);
+
+        let inserted = Rc::new(VecModel::from(vec![4, 5]));
+        outer.insert(1, inserted.clone().into());
+        check_content(&model, &[1, 2, 4, 5, 3]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(2, 2)]);
+
+        // The model that moved still reports its changes at the right place
+        outer.row_data(2).unwrap().set_row_data(0, 30);
+        check_content(&model, &[1, 2, 4, 5, 30]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[4]);
+
+        outer.remove(0);
+        check_content(&model, &[4, 5, 30]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(0, 2)]);
+
+        observer.clear();
+        inserted.push(6);
+        check_content(&model, &[4, 5, 6, 30]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(2, 1)]);
+
+        observer.clear();
+        outer.set_row_data(0, ModelRc::new(VecModel::from(vec![7])));
+        check_content(&model, &[7, 30]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[0]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(1, 2)]);
+
+        // The replaced model is not tracked anymore
+        observer.clear();
+        inserted.push(8);
+        check_content(&model, &[7, 30]);
+        assert!(observer.added_rows.borrow().is_empty());
+
+        outer.insert(1, ModelRc::default());
+        check_content(&model, &[7, 30]);
+        assert!(observer.added_rows.borrow().is_empty());
+        assert_eq!(*observer.reset.borrow(), 0);
+
+        outer.set_vec(vec![ModelRc::new(VecModel::from(vec![1]))]);
+        check_content(&model, &[1]);
+        assert_eq!(*observer.reset.borrow(), 1);
+    }
+}
+
+struct TakeModelInner<M>
+where
+    M: Model + 'static,
+{
+    wrapped_model: M,
+    count: Cell<usize>,
+    notify: ModelNotify,
+}
+
+impl<M> ModelChangeListener for TakeModelInner<M>
+where
+    M: Model + 'static,
+{
+    fn row_changed(&self, row: usize) {
+        if row < self.count.get() {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        let take = self.count.get();
+        if index >= take || count == 0 {
+            return;
+        }
+        let old_visible = (self.wrapped_model.row_count() - count).min(take);
+        let inserted = count.min(take - index);
+        // The rows that are pushed past the end
+        let overflow = (old_visible + inserted).saturating_sub(take);
+        if overflow > 0 {
+            self.notify.row_removed(take - inserted, overflow);
+        }
+        self.notify.row_added(index, inserted);
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        let take = self.count.get();
+        if index >= take || count == 0 {
+            return;
+        }
+        let row_count = self.wrapped_model.row_count();
+        let old_visible = (row_count + count).min(take);
+        let removed = count.min(old_visible - index);
+        self.notify.row_removed(index, removed);
+        // The rows that were after the end and are now visible
+        let remaining = old_visible - removed;
+        let moved_in = row_count.min(take) - remaining;
+        if moved_in > 0 {
+            self.notify.row_added(remaining, moved_in);
+        }
+    }
+
+    fn reset(&self) {
+        self.notify.reset()
+    }
+}
+
+/// Provides at most the first `count` rows of another Model.
+///
+/// When the other Model is updated, the `TakeModel` is updated accordingly.
+/// Combined with a [`SkipModel`], this is a way to show a page of a model.
+///
+/// Generic parameters:
+/// * `M` the type of the wrapped `Model`.
+///
+/// ## Example
+///
+/// Here we have a [`VecModel`] holding [`crate::SharedString`]s.
+/// Only its first two rows are kept by a `TakeModel`.
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{Model, VecModel, SharedString, TakeModel};
+/// let model = Rc::new(VecModel::from(vec![
+///     SharedString::from("Lorem"),
+///     SharedString::from("ipsum"),
+///     SharedString::from("dolor"),
+/// ]));
+///
+/// let take_model = TakeModel::new(model.clone(), 2);
+///
+/// assert_eq!(take_model.row_count(), 2);
+/// assert_eq!(take_model.row_data(1).unwrap(), SharedString::from("ipsum"));
+///
+/// model.insert(0, SharedString::from("sit"));
+///
+/// assert_eq!(take_model.row_data(0).unwrap(), SharedString::from("sit"));
+/// assert_eq!(take_model.row_data(1).unwrap(), SharedString::from("Lorem"));
+/// ```
+///
+/// Alternatively you can use the shortcuts [`ModelExt::take`] and [`ModelExt::skip`].
+/// ```
+/// # use slint::{Model, ModelExt, VecModel};
+/// let page = VecModel::from((0..100).collect::<Vec<i32>>()).skip(20).take(10);
+/// assert_eq!(page.row_count(), 10);
+/// assert_eq!(page.row_data(0).unwrap(), 20);
+/// assert_eq!(page.row_data(9).unwrap(), 29);
+/// ```
+pub struct TakeModel<M>(Pin<Box<ModelChangeListenerContainer<TakeModelInner<M>>>>)
+where
+    M: Model + 'static;
+
+impl<M> TakeModel<M>
+where
+    M: Model + 'static,
+{
+    /// Creates a new TakeModel with at most the first `count` rows of the given `wrapped_model`.
+    /// Alternativly you can use [`ModelExt::take`] on your Model.
+    pub fn new(wrapped_model: M, count: usize) -> Self {
+        let inner =
+            TakeModelInner { wrapped_model, count: Cell::new(count), notify: Default::default() };
+        let container = Box::pin(ModelChangeListenerContainer::new(inner));
+        container.wrapped_model.model_tracker().attach_peer(container.as_ref().model_peer());
+        Self(container)
+    }
+
+    /// Returns the maximum number of rows
+    pub fn count(&self) -> usize {
+        self.0.count.get()
+    }
+
+    /// Changes the maximum number of rows
+    pub fn set_count(&self, count: usize) {
+        let row_count = self.0.wrapped_model.row_count();
+        let old_visible = row_count.min(self.0.count.replace(count));
+        let new_visible = row_count.min(count);
+        if new_visible > old_visible {
+            self.0.notify.row_added(old_visible, new_visible - old_visible);
+        } else if old_visible > new_visible {
+            self.0.notify.row_removed(new_visible, old_visible - new_visible);
+        }
+    }
+}
+
+impl<M> Model for TakeModel<M>
+where
+    M: Model + 'static,
+{
+    type Data = M::Data;
+
+    fn row_count(&self) -> usize {
+        self.0.wrapped_model.row_count().min(self.0.count.get())
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row < self.0.count.get() {
+            self.0.wrapped_model.row_data(row)
+        } else {
+            None
+        }
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        if row < self.0.count.get() {
+            self.0.wrapped_model.set_row_data(row, data);
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+}
+
+struct SkipModelInner<M>
+where
+    M: Model + 'static,
+{
+    wrapped_model: M,
+    count: Cell<usize>,
+    notify: ModelNotify,
+}
+
+impl<M> ModelChangeListener for SkipModelInner<M>
+where
+    M: Model + 'static,
+{
+    fn row_changed(&self, row: usize) {
+        if let Some(row) = row.checked_sub(self.count.get()) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        let skip = self.count.get();
+        if let Some(index) = index.checked_sub(skip) {
+            self.notify.row_added(index, count);
+        } else {
+            // The rows before the start are shifted into the model
+            let row_count = self.wrapped_model.row_count();
+            let added = row_count.saturating_sub(skip) - (row_count - count).saturating_sub(skip);
+            if added > 0 {
+                self.notify.row_added(0, added);
+            }
+        }
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        let skip = self.count.get();
+        if let Some(index) = index.checked_sub(skip) {
+            self.notify.row_removed(index, count);
+        } else {
+            // The first rows are shifted out of the model
+            let row_count = self.wrapped_model.row_count();
+            let removed = (row_count + count).saturating_sub(skip) - row_count.saturating_sub(skip);
+            if removed > 0 {
+                self.notify.row_removed(0, removed);
+            }
+        }
+    }
+
+    fn reset(&self) {
+        self.notify.reset()
+    }
+}
+
+/// Provides the rows of another Model, except for the first `count` ones.
+///
+/// When the other Model is updated, the `SkipModel` is updated accordingly.
+/// Combined with a [`TakeModel`], this is a way to show a page of a model.
+///
+/// Generic parameters:
+/// * `M` the type of the wrapped `Model`.
+///
+/// ## Example
+///
+/// Here we have a [`VecModel`] holding [`crate::SharedString`]s.
+/// Its first row is skipped by a `SkipModel`.
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{Model, VecModel, SharedString, SkipModel};
+/// let model = Rc::new(VecModel::from(vec![
+///     SharedString::from("Lorem"),
+///     SharedString::from("ipsum"),
+///     SharedString::from("dolor"),
+/// ]));
+///
+/// let skip_model = SkipModel::new(model.clone(), 1);
+///
+/// assert_eq!(skip_model.row_count(), 2);
+/// assert_eq!(skip_model.row_data(0).unwrap(), SharedString::from("ipsum"));
+///
+/// model.remove(0);
+///
+/// assert_eq!(skip_model.row_count(), 1);
+/// assert_eq!(skip_model.row_data(0).unwrap(), SharedString::from("dolor"));
+/// ```
+///
+/// Alternatively you can use the shortcut [`ModelExt::skip`].
+pub struct SkipModel<M>(Pin<Box<ModelChangeListenerContainer<SkipModelInner<M>>>>)
+where
+    M: Model + 'static;
+
+impl<M> SkipModel<M>
+where
+    M: Model + 'static,
+{
+    /// Creates a new SkipModel with the rows of the given `wrapped_model`, except the first `count` ones.
+    /// Alternativly you can use [`ModelExt::skip`] on your Model.
+    pub fn new(wrapped_model: M, count: usize) -> Self {
+        let inner =
+            SkipModelInner { wrapped_model, count: Cell::new(count), notify: Default::default() };
+        let container = Box::pin(ModelChangeListenerContainer::new(inner));
+        container.wrapped_model.model_tracker().attach_peer(container.as_ref().model_peer());
+        Self(container)
+    }
+
+    /// Returns the number of rows that are skipped
+    pub fn count(&self) -> usize {
+        self.0.count.get()
+    }
+
+    /// Changes the number of rows that are skipped
+    pub fn set_count(&self, count: usize) {
+        let old_count = self.0.count.replace(count);
+        if old_count != count {
+            // All the rows are shifted
+            let row_count = self.0.wrapped_model.row_count();
+            notify_rows_replaced(
+                &self.0.notify,
+                0,
+                row_count.saturating_sub(old_count),
+                row_count.saturating_sub(count),
+            );
+        }
+    }
+}
+
+impl<M> Model for SkipModel<M>
+where
+    M: Model + 'static,
+{
+    type Data = M::Data;
+
+    fn row_count(&self) -> usize {
+        self.0.wrapped_model.row_count().saturating_sub(self.0.count.get())
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.0.wrapped_model.row_data(row + self.0.count.get())
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        self.0.wrapped_model.set_row_data(row + self.0.count.get(), data);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+}
+
+#[cfg(test)]
+mod take_skip_tests {
+    use super::*;
+
+    #[track_caller]
+    fn check_content(model: &impl Model<Data = i32>, expected: &[i32]) {
+        assert_eq!(model.row_count(), expected.len());
+        for (i, v) in expected.iter().enumerate() {
+            assert_eq!(model.row_data(i), Some(*v), "Expected {} at index {}", v, i);
+        }
+    }
+
+    #[test]
+    fn test_take_model() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        let model = TakeModel::new(wrapped_rc.clone(), 3);
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        check_content(&model, &[1, 2, 3]);
+
+        wrapped_rc.insert(1, 10);
+        check_content(&model, &[1, 10, 2]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(2, 1)]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(1, 1)]);
+
+        observer.clear();
+        wrapped_rc.push(5);
+        wrapped_rc.set_row_data(4, 40);
+        assert!(observer.added_rows.borrow().is_empty());
+        assert!(observer.changed_rows.borrow().is_empty());
+
+        wrapped_rc.set_row_data(2, 20);
+        check_content(&model, &[1, 10, 20]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[2]);
+
+        wrapped_rc.remove(0);
+        check_content(&model, &[10, 20, 3]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(0, 1)]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(2, 1)]);
+
+        observer.clear();
+        model.set_count(10);
+        check_content(&model, &[10, 20, 3, 40, 5]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(3, 2)]);
+        assert!(observer.changed_rows.borrow().is_empty());
+
+        model.set_count(1);
+        check_content(&model, &[10]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(1, 4)]);
+
+        // Rows that are not visible through the model are not changed
+        model.set_row_data(2, 30);
+        assert_eq!(wrapped_rc.row_data(2), Some(3));
+        model.set_row_data(0, 11);
+        check_content(&model, &[11]);
+        assert_eq!(wrapped_rc.row_data(0), Some(11));
+
+        observer.clear();
+        wrapped_rc.set_vec(vec![1]);
+        wrapped_rc.remove(0);
+        check_content(&model, &[]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(0, 1)]);
+        assert!(observer.added_rows.borrow().is_empty());
+        assert_eq!(*observer.reset.borrow(), 1);
+    }
+
+    #[test]
+    fn test_skip_model() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![1, 2, 3, 4]));
+        let model = SkipModel::new(wrapped_rc.clone(), 2);
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        check_content(&model, &[3, 4]);
+
+        wrapped_rc.insert(3, 10);
+        check_content(&model, &[3, 10, 4]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(1, 1)]);
+
+        wrapped_rc.insert(0, 20);
+        check_content(&model, &[2, 3, 10, 4]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(1, 1), (0, 1)]);
+
+        wrapped_rc.set_row_data(0, 30);
+        wrapped_rc.set_row_data(2, 40);
+        check_content(&model, &[40, 3, 10, 4]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[0]);
+
+        wrapped_rc.remove(1);
+        check_content(&model, &[3, 10, 4]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(0, 1)]);
+
+        wrapped_rc.remove(4);
+        check_content(&model, &[3, 10]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(0, 1), (2, 1)]);
+
+        observer.clear();
+        model.set_count(0);
+        check_content(&model, &[30, 40, 3, 10]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[0, 1]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(2, 2)]);
+
+        observer.clear();
+        model.set_count(10);
+        check_content(&model, &[]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(0, 4)]);
+
+        observer.clear();
+        wrapped_rc.insert(0, 1);
+        assert!(observer.added_rows.borrow().is_empty());
+        assert_eq!(*observer.reset.borrow(), 0);
+    }
+}
+
+struct ZipModelInner<A, B>
+where
+    A: Model + 'static,
+    B: Model + 'static,
+{
+    first: A,
+    second: B,
+    peers: [SourcePeer<ZipModelInner<A, B>>; 2],
+    notify: ModelNotify,
+}
+
+impl<A, B> ZipModelInner<A, B>
+where
+    A: Model + 'static,
+    B: Model + 'static,
+{
+    /// Returns the row count of the model `source` and of the other model
+    fn row_counts(&self, source: usize) -> (usize, usize) {
+        let (first, second) = (self.first.row_count(), self.second.row_count());
+        if source == 0 {
+            (first, second)
+        } else {
+            (second, first)
+        }
+    }
+}
+
+impl<A, B> MultiModelChangeListener for ZipModelInner<A, B>
+where
+    A: Model + 'static,
+    B: Model + 'static,
+{
+    fn row_changed(&self, _source: usize, row: usize) {
+        if row < self.first.row_count().min(self.second.row_count()) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn row_added(&self, source: usize, index: usize, count: usize) {
+        // All the rows after `index` now pair different rows
+        let (row_count, other_row_count) = self.row_counts(source);
+        let old_visible = (row_count - count).min(other_row_count);
+        let new_visible = row_count.min(other_row_count);
+        notify_rows_replaced(
+            &self.notify,
+            index,
+            old_visible.saturating_sub(index),
+            new_visible.saturating_sub(index),
+        );
+    }
+
+    fn row_removed(&self, source: usize, index: usize, count: usize) {
+        let (row_count, other_row_count) = self.row_counts(source);
+        let old_visible = (row_count + count).min(other_row_count);
+        let new_visible = row_count.min(other_row_count);
+        notify_rows_replaced(
+            &self.notify,
+            index,
+            old_visible.saturating_sub(index),
+            new_visible.saturating_sub(index),
+        );
+    }
+
+    fn reset(&self, _source: usize) {
+        self.notify.reset()
+    }
+}
+
+/// Provides rows that pair the rows of two other Models, like [`Iterator::zip`].
+///
+/// The `ZipModel` has as many rows as the shortest of the two models. When one of them is
+/// updated, the `ZipModel` is updated accordingly. Setting the data of a row sets the data
+/// of that row in both models.
+///
+/// Generic parameters:
+/// * `A` the type of the first wrapped `Model`.
+/// * `B` the type of the second wrapped `Model`.
+///
+/// ## Example
+///
+/// Here we have a [`VecModel`] holding [`crate::SharedString`]s and another one holding
+/// numbers. They are then zipped into a `ZipModel`.
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{Model, VecModel, SharedString, ZipModel};
+/// let names = Rc::new(VecModel::from(vec![
+///     SharedString::from("Lorem"),
+///     SharedString::from("ipsum"),
+/// ]));
+/// let counts = Rc::new(VecModel::from(vec![1, 2, 3]));
+///
+/// let zip_model = ZipModel::new(names.clone(), counts.clone());
+///
+/// assert_eq!(zip_model.row_count(), 2);
+/// assert_eq!(zip_model.row_data(1).unwrap(), (SharedString::from("ipsum"), 2));
+///
+/// names.push(SharedString::from("dolor"));
+///
+/// assert_eq!(zip_model.row_data(2).unwrap(), (SharedString::from("dolor"), 3));
+/// ```
+///
+/// Alternatively you can use the shortcut [`ModelExt::zip`].
+pub struct ZipModel<A, B>(Rc<ZipModelInner<A, B>>)
+where
+    A: Model + 'static,
+    B: Model + 'static;
+
+impl<A, B> ZipModel<A, B>
+where
+    A: Model + 'static,
+    B: Model + 'static,
+{
+    /// Creates a new ZipModel that pairs the rows of `first` and `second`.
+    /// Alternativly you can use [`ModelExt::zip`] on your Model.
+    pub fn new(first: A, second: B) -> Self {
+        let inner = Rc::new_cyclic(|weak| ZipModelInner {
+            first,
+            second,
+            peers: [source_peer(weak, 0), source_peer(weak, 1)],
+            notify: Default::default(),
+        });
+
+        inner.first.model_tracker().attach_peer(inner.peers[0].as_ref().model_peer());
+        inner.second.model_tracker().attach_peer(inner.peers[1].as_ref().model_peer());
+
+        Self(inner)
+    }
+}
+
+impl<A, B> Model for ZipModel<A, B>
+where
+    A: Model + 'static,
+    B: Model + 'static,
+{
+    type Data = (A::Data, B::Data);
+
+    fn row_count(&self) -> usize {
+        self.0.first.row_count().min(self.0.second.row_count())
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        Some((self.0.first.row_data(row)?, self.0.second.row_data(row)?))
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        self.0.first.set_row_data(row, data.0);
+        self.0.second.set_row_data(row, data.1);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+}
+
+#[test]
+fn test_zip_model() {
+    let first = Rc::new(VecModel::from(vec![1, 2, 3]));
+    let second = Rc::new(VecModel::from(vec!['a', 'b']));
+    let model = first.clone().zip(second.clone());
+
+    let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+    model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+    assert_eq!(model.row_count(), 2);
+    assert_eq!(model.row_data(0), Some((1, 'a')));
+    assert_eq!(model.row_data(1), Some((2, 'b')));
+
+    second.push('c');
+    assert_eq!(model.row_count(), 3);
+    assert_eq!(model.row_data(2), Some((3, 'c')));
+    assert_eq!(&*observer.added_rows.borrow(), &[(2, 1)]);
+    assert!(observer.changed_rows.borrow().is_empty());
+
+    observer.clear();
+    first.insert(1, 10);
+    assert_eq!(model.row_count(), 3);
+    assert_eq!(model.row_data(1), Some((10, 'b')));
+    assert_eq!(model.row_data(2), Some((2, 'c')));
+    assert_eq!(&*observer.changed_rows.borrow(), &[1, 2]);
+    assert!(observer.added_rows.borrow().is_empty());
+
+    observer.clear();
+    second.remove(0);
+    assert_eq!(model.row_count(), 2);
+    assert_eq!(model.row_data(0), Some((1, 'b')));
+    assert_eq!(&*observer.changed_rows.borrow(), &[0, 1]);
+    assert_eq!(&*observer.removed_rows.borrow(), &[(2, 1)]);
+
+    observer.clear();
+    first.push(4);
+    assert!(observer.added_rows.borrow().is_empty());
+    assert!(observer.changed_rows.borrow().is_empty());
+
+    model.set_row_data(1, (20, 'z'));
+    assert_eq!(first.row_data(1), Some(20));
+    assert_eq!(second.row_data(1), Some('z'));
+    assert_eq!(&*observer.changed_rows.borrow(), &[1, 1]);
+    assert_eq!(*observer.reset.borrow(), 0);
+}
+
+/// A row of a [`GroupByModel`]: the rows of the wrapped model that have the same key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelGroup<K, T> {
+    /// The key of all the rows of this group
+    pub key: K,
+    /// The rows of this group, in the order of the wrapped model.
+    /// The same model is returned for a group as long as that group exists, and it is updated
+    /// when the rows of the group change.
+    pub rows: ModelRc<T>,
+}
+
+/// The rows of one group of a [`GroupByModel`]
+struct GroupRowsModel<M>
+where
+    M: Model + 'static,
+{
+    wrapped_model: Rc<M>,
+    // The sorted indices of the rows of the wrapped model in this group
+    rows: RefCell<Vec<usize>>,
+    notify: ModelNotify,
+}
+
+impl<M> Model for GroupRowsModel<M>
+where
+    M: Model + 'static,
+{
+    type Data = M::Data;
+
+    fn row_count(&self) -> usize {
+        self.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let wrapped_row = *self.rows.borrow().get(row)?;
+        self.wrapped_model.row_data(wrapped_row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let Some(&wrapped_row) = self.rows.borrow().get(row) else { return };
+        self.wrapped_model.set_row_data(wrapped_row, data);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+}
+
+struct GroupByModelInner<M, F, K>
+where
+    M: Model + 'static,
+    F: Fn(&M::Data) -> K + 'static,
+    K: Ord + Clone + 'static,
+{
+    wrapped_model: Rc<M>,
+    key_function: F,
+    // The groups, sorted by key
+    groups: RefCell<Vec<(K, Rc<GroupRowsModel<M>>)>>,
+    notify: ModelNotify,
+}
+
+impl<M, F, K> GroupByModelInner<M, F, K>
+where
+    M: Model + 'static,
+    F: Fn(&M::Data) -> K + 'static,
+    K: Ord + Clone + 'static,
+{
+    fn new_group(&self, row: usize) -> Rc<GroupRowsModel<M>> {
+        Rc::new(GroupRowsModel {
+            wrapped_model: self.wrapped_model.clone(),
+            rows: RefCell::new(alloc::vec![row]),
+            notify: Default::default(),
+        })
+    }
+
+    fn build_groups(&self) {
+        let mut groups: Vec<(K, Rc<GroupRowsModel<M>>)> = Vec::new();
+        for (row, data) in self.wrapped_model.iter().enumerate() {
+            let key = (self.key_function)(&data);
+            match groups.binary_search_by(|(k, _)| k.cmp(&key)) {
+                Ok(index) => groups[index].1.rows.borrow_mut().push(row),
+                Err(index) => groups.insert(index, (key, self.new_group(row))),
+            }
+        }
+
+        let old_groups = core::mem::replace(&mut *self.groups.borrow_mut(), groups);
+        for (_, group) in old_groups {
+            group.rows.borrow_mut().clear();
+            group.notify.reset();
+        }
+    }
+
+    /// Adds `row` to the group for `key`, creating the group if needed
+    fn insert_row(&self, row: usize, key: K) {
+        let mut groups = self.groups.borrow_mut();
+        match groups.binary_search_by(|(k, _)| k.cmp(&key)) {
+            Ok(index) => {
+                let group = groups[index].1.clone();
+                drop(groups);
+                let mut rows = group.rows.borrow_mut();
+                let position = rows.binary_search(&row).unwrap_or_else(|p| p);
+                rows.insert(position, row);
+                drop(rows);
+                group.notify.row_added(position, 1);
+            }
+            Err(index) => {
+                groups.insert(index, (key, self.new_group(row)));
+                drop(groups);
+                self.notify.row_added(index, 1);
+            }
+        }
+    }
+}
+
+impl<M, F, K> ModelChangeListener for GroupByModelInner<M, F, K>
+where
+    M: Model + 'static,
+    F: Fn(&M::Data) -> K + 'static,
+    K: Ord + Clone + 'static,
+{
+    fn row_changed(&self, row: usize) {
+        let key = (self.key_function)(&self.wrapped_model.row_data(row).unwrap());
+
+        let groups = self.groups.borrow();
+        let Some((index, position)) = groups.iter().enumerate().find_map(|(index, (_, group))| {
+            Some((index, group.rows.borrow().binary_search(&row).ok()?))
+        }) else {
+            return;
+        };
+        let (old_key, group) = groups[index].clone();
+        drop(groups);
+
+        if old_key == key {
+            group.notify.row_changed(position);
+            return;
+        }
+
+        let is_empty = {
+            let mut rows = group.rows.borrow_mut();
+            rows.remove(position);
+            rows.is_empty()
+        };
+        if is_empty {
+            self.groups.borrow_mut().remove(index);
+        }
+        group.notify.row_removed(position, 1);
+        if is_empty {
+            self.notify.row_removed(index, 1);
+        }
+
+        self.insert_row(row, key);
+    }
+
+    fn row_added(&self, index: usize, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        for (_, group) in self.groups.borrow().iter() {
+            group.rows.borrow_mut().iter_mut().filter(|row| **row >= index).for_each(|row| {
+                *row += count;
+            });
+        }
+
+        for row in index..index + count {
+            let key = (self.key_function)(&self.wrapped_model.row_data(row).unwrap());
+            self.insert_row(row, key);
+        }
+    }
+
+    fn row_removed(&self, index: usize, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        let mut changed_groups = Vec::new();
+        let mut removed_groups = Vec::new();
+
+        let mut groups = self.groups.borrow_mut();
+        // Iterate backwards so that the indices of the removed groups stay valid when notifying
+        for group_index in (0..groups.len()).rev() {
+            let group = groups[group_index].1.clone();
+            let mut rows = group.rows.borrow_mut();
+            let start = rows.binary_search(&index).unwrap_or_else(|s| s);
+            let end = rows.binary_search(&(index + count)).unwrap_or_else(|e| e);
+            rows.drain(start..end);
+            rows.iter_mut().skip(start).for_each(|row| *row -= count);
+
+            if rows.is_empty() {
+                groups.remove(group_index);
+                removed_groups.push(group_index);
+            }
+            drop(rows);
+            if start < end {
+                changed_groups.push((group, start, end - start));
+            }
+        }
+        drop(groups);
+
+        for (group, start, count) in changed_groups {
+            group.notify.row_removed(start, count);
+        }
+        for group_index in removed_groups {
+            self.notify.row_removed(group_index, 1);
+        }
+    }
+
+    fn reset(&self) {
+        self.build_groups();
+        self.notify.reset();
+    }
+}
+
+/// Provides the rows of another Model grouped by a key.
+///
+/// Each row of the `GroupByModel` is a [`ModelGroup`] with a key and the model of the rows
+/// that have this key, in the order of the wrapped model. The groups are sorted by key.
+/// When the other Model is updated, the `GroupByModel` and the models of its groups are
+/// updated accordingly.
+///
+/// Generic parameters:
+/// * `M` the type of the wrapped `Model`.
+/// * `F` the function that returns the key of a row.
+/// * `K` the type of the key.
+///
+/// ## Example
+///
+/// Here we have a [`VecModel`] holding [`crate::SharedString`]s.
+/// They are then grouped by their first letter into a `GroupByModel`.
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{Model, VecModel, SharedString, GroupByModel};
+/// let model = Rc::new(VecModel::from(vec![
+///     SharedString::from("banana"),
+///     SharedString::from("apple"),
+///     SharedString::from("blueberry"),
+/// ]));
+///
+/// let grouped_model = GroupByModel::new(model.clone(), |s| s.chars().next());
+///
+/// assert_eq!(grouped_model.row_count(), 2);
+/// let group = grouped_model.row_data(1).unwrap();
+/// assert_eq!(group.key, Some('b'));
+/// assert_eq!(group.rows.row_count(), 2);
+/// assert_eq!(group.rows.row_data(1).unwrap(), SharedString::from("blueberry"));
+///
+/// model.push(SharedString::from("cherry"));
+///
+/// assert_eq!(grouped_model.row_count(), 3);
+/// assert_eq!(grouped_model.row_data(2).unwrap().key, Some('c'));
+/// ```
+///
+/// Alternatively you can use the shortcut [`ModelExt::group_by`].
+/// ```
+/// # use slint::{Model, ModelExt, VecModel};
+/// let grouped_model = VecModel::from(vec![1, 2, 3, 4, 5]).group_by(|x| x % 2 == 0);
+/// let even = grouped_model.row_data(1).unwrap();
+/// assert!(even.key);
+/// assert_eq!(even.rows.row_count(), 2);
+/// assert_eq!(even.rows.row_data(0).unwrap(), 2);
+/// ```
+pub struct GroupByModel<M, F, K>(
+    Pin<Box<ModelChangeListenerContainer<GroupByModelInner<M, F, K>>>>,
+)
+where
+    M: Model + 'static,
+    F: Fn(&M::Data) -> K + 'static,
+    K: Ord + Clone + 'static;
+
+impl<M, F, K> GroupByModel<M, F, K>
+where
+    M: Model + 'static,
+    F: Fn(&M::Data) -> K + 'static,
+    K: Ord + Clone + 'static,
+{
+    /// Creates a new GroupByModel based on the given `wrapped_model`, where the rows are grouped
+    /// by the key returned by `key_function`.
+    /// Alternativly you can use [`ModelExt::group_by`] on your Model.
+    pub fn new(wrapped_model: M, key_function: F) -> Self {
+        let inner = GroupByModelInner {
+            wrapped_model: Rc::new(wrapped_model),
+            key_function,
+            groups: RefCell::new(Vec::new()),
+            notify: Default::default(),
+        };
+
+        inner.build_groups();
+
+        let container = Box::pin(ModelChangeListenerContainer::new(inner));
+
+        container.wrapped_model.model_tracker().attach_peer(container.as_ref().model_peer());
+
+        Self(container)
+    }
+
+    /// Manually regroup the rows. You need to run this e.g. if the key function depends on
+    /// mutable state and it has changed.
+    pub fn reset(&self) {
+        self.0.reset();
+    }
+}
+
+impl<M, F, K> Model for GroupByModel<M, F, K>
+where
+    M: Model + 'static,
+    F: Fn(&M::Data) -> K + 'static,
+    K: Ord + Clone + 'static,
+{
+    type Data = ModelGroup<K, M::Data>;
+
+    fn row_count(&self) -> usize {
+        self.0.groups.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        self.0
+            .groups
+            .borrow()
+            .get(row)
+            .map(|(key, rows)| ModelGroup { key: key.clone(), rows: rows.clone().into() })
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+}
+
+#[cfg(test)]
+mod group_by_tests {
+    use super::*;
+
+    #[track_caller]
+    fn check_content(
+        model: &GroupByModel<Rc<VecModel<i32>>, fn(&i32) -> i32, i32>,
+        expected: &[(i32, &[i32])],
+    ) {
+        assert_eq!(model.row_count(), expected.len());
+        for (i, (key, rows)) in expected.iter().enumerate() {
+            let group = model.row_data(i).unwrap();
+            assert_eq!(group.key, *key);
+            assert_eq!(group.rows.iter().collect::<Vec<_>>(), *rows, "Rows of the group {}", key);
+        }
+    }
+
+    #[test]
+    fn test_group_by_model() {
+        let wrapped_rc = Rc::new(VecModel::from(vec![11, 21, 12, 31, 22]));
+        let model = GroupByModel::new(wrapped_rc.clone(), (|x| x / 10) as fn(&i32) -> i32);
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        check_content(&model, &[(1, &[11, 12]), (2, &[21, 22]), (3, &[31])]);
+
+        let twenties = model.row_data(1).unwrap().rows;
+        assert_eq!(model.row_data(1).unwrap().rows, twenties);
+        let group_observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        twenties.model_tracker().attach_peer(Pin::as_ref(&group_observer).model_peer());
+
+        wrapped_rc.insert(1, 23);
+        check_content(&model, &[(1, &[11, 12]), (2, &[23, 21, 22]), (3, &[31])]);
+        assert_eq!(&*group_observer.added_rows.borrow(), &[(0, 1)]);
+        assert!(observer.added_rows.borrow().is_empty());
+
+        wrapped_rc.push(41);
+        check_content(&model, &[(1, &[11, 12]), (2, &[23, 21, 22]), (3, &[31]), (4, &[41])]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(3, 1)]);
+
+        wrapped_rc.set_row_data(2, 24);
+        check_content(&model, &[(1, &[11, 12]), (2, &[23, 24, 22]), (3, &[31]), (4, &[41])]);
+        assert_eq!(&*group_observer.changed_rows.borrow(), &[1]);
+
+        // Move a row to another group
+        group_observer.clear();
+        wrapped_rc.set_row_data(1, 32);
+        check_content(&model, &[(1, &[11, 12]), (2, &[24, 22]), (3, &[32, 31]), (4, &[41])]);
+        assert_eq!(&*group_observer.removed_rows.borrow(), &[(0, 1)]);
+        assert!(observer.changed_rows.borrow().is_empty());
+
+        // Move the only row of a group to a new group
+        observer.clear();
+        wrapped_rc.set_row_data(6, 51);
+        check_content(&model, &[(1, &[11, 12]), (2, &[24, 22]), (3, &[32, 31]), (5, &[51])]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(3, 1)]);
+        assert_eq!(&*observer.added_rows.borrow(), &[(3, 1)]);
+
+        observer.clear();
+        group_observer.clear();
+        wrapped_rc.remove(2);
+        wrapped_rc.remove(4);
+        check_content(&model, &[(1, &[11, 12]), (3, &[32, 31]), (5, &[51])]);
+        assert_eq!(&*group_observer.removed_rows.borrow(), &[(0, 1), (0, 1)]);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(1, 1)]);
+        assert_eq!(twenties.row_count(), 0);
+        // Writes past the end of a group are ignored
+        twenties.set_row_data(0, 25);
+        check_content(&model, &[(1, &[11, 12]), (3, &[32, 31]), (5, &[51])]);
+
+        model.row_data(0).unwrap().rows.set_row_data(1, 13);
+        assert_eq!(wrapped_rc.row_data(2), Some(13));
+        check_content(&model, &[(1, &[11, 13]), (3, &[32, 31]), (5, &[51])]);
+
+        wrapped_rc.set_vec(vec![1, 2]);
+        check_content(&model, &[(0, &[1, 2])]);
+        assert_eq!(*observer.reset.borrow(), 1);
+    }
+}
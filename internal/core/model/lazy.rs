@@ -0,0 +1,409 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module contains a model whose rows are loaded on demand, by pages.
+
+use super::*;
+use alloc::rc::Weak;
+use core::ops::Range;
+
+enum PageState<T> {
+    NotLoaded,
+    /// The page was requested from the loader with the given generation
+    Loading(usize),
+    /// The rows of the page. There may be less rows than the page size if the loader delivered less.
+    Loaded(Vec<T>),
+}
+
+struct LazyModelInner<T> {
+    row_count: Cell<usize>,
+    page_size: usize,
+    placeholder: T,
+    pages: RefCell<Vec<PageState<T>>>,
+    /// The pages that need to be requested from the loader
+    pending_pages: RefCell<Vec<usize>>,
+    /// Incremented on reset, so that the requests made before are ignored
+    generation: Cell<usize>,
+    load_page: Box<dyn Fn(LazyPageRequest<T>)>,
+    notify: ModelNotify,
+}
+
+impl<T: Clone + 'static> LazyModelInner<T> {
+    fn page_count(&self) -> usize {
+        (self.row_count.get() + self.page_size - 1) / self.page_size
+    }
+
+    fn page_range(&self, page: usize) -> Range<usize> {
+        let start = page * self.page_size;
+        start..(start + self.page_size).min(self.row_count.get())
+    }
+
+    fn request_page(self: &Rc<Self>, page: usize) {
+        let mut pending_pages = self.pending_pages.borrow_mut();
+        pending_pages.push(page);
+        if pending_pages.len() == 1 {
+            // Don't call the loader from row_data(), which is usually called while updating a
+            // repeater, but from the event loop. This also batches the requests.
+            let weak = Rc::downgrade(self);
+            crate::timers::Timer::single_shot(Default::default(), move || {
+                if let Some(inner) = weak.upgrade() {
+                    inner.load_pending_pages();
+                }
+            });
+        }
+    }
+
+    fn load_pending_pages(self: &Rc<Self>) {
+        let pending_pages = core::mem::take(&mut *self.pending_pages.borrow_mut());
+        let generation = self.generation.get();
+        for page in pending_pages {
+            let is_loading = matches!(
+                self.pages.borrow().get(page),
+                Some(PageState::Loading(g)) if *g == generation
+            );
+            if is_loading {
+                (self.load_page)(LazyPageRequest {
+                    model: Rc::downgrade(self),
+                    page,
+                    range: self.page_range(page),
+                    generation,
+                });
+            }
+        }
+    }
+
+    fn set_page(&self, page: usize, rows: Vec<T>) {
+        let range = self.page_range(page);
+        {
+            let mut pages = self.pages.borrow_mut();
+            let Some(state) = pages.get_mut(page) else { return };
+            let mut rows = rows;
+            rows.truncate(range.len());
+            *state = PageState::Loaded(rows);
+        }
+        for row in range {
+            self.notify.row_changed(row);
+        }
+    }
+}
+
+/// A handle passed to the loader of a [`LazyModel`] to request the rows of a page.
+///
+/// Deliver the rows with [`Self::deliver()`], immediately or later, for example once a database
+/// query or a network request has completed.
+pub struct LazyPageRequest<T> {
+    model: Weak<LazyModelInner<T>>,
+    page: usize,
+    range: Range<usize>,
+    generation: usize,
+}
+
+impl<T: Clone + 'static> LazyPageRequest<T> {
+    /// The index of the requested page
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// The indices of the rows of the requested page
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns true if the rows are not needed anymore, because the model was dropped or reset
+    /// since the request was made.
+    pub fn is_obsolete(&self) -> bool {
+        self.model.upgrade().map_or(true, |model| model.generation.get() != self.generation)
+    }
+
+    /// Sets the rows of the requested page. The rows of the page after the delivered ones keep
+    /// the placeholder data.
+    pub fn deliver(self, rows: impl IntoIterator<Item = T>) {
+        if let Some(model) = self.model.upgrade() {
+            if model.generation.get() == self.generation {
+                model.set_page(self.page, rows.into_iter().collect());
+            }
+        }
+    }
+}
+
+/// A model for large or slow data sets, whose rows are loaded by pages when they are first needed.
+///
+/// The `LazyModel` has a known number of rows, but doesn't hold their data initially. When the
+/// data of a row of a page that is not loaded is requested, the `LazyModel` returns a placeholder
+/// and asks the loader function for the rows of that page, with a [`LazyPageRequest`]. The loader
+/// is called from the event loop, not from [`Model::row_data`], and it doesn't need to provide the
+/// rows right away: it can for example spawn a future or a thread, and deliver the rows when they
+/// are available. The views that show the rows of the page are then updated.
+///
+/// A `ListView` only requests the data of the rows that are visible, so only the pages that
+/// are scrolled into view are loaded. Note that iterating over the model loads all the pages.
+///
+/// Loaded pages are kept until [`Self::reset()`] is called.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{Model, LazyModel, SharedString};
+/// let model = LazyModel::new(1000, 50, SharedString::from("Loading..."), |request| {
+///     // This could also be done asynchronously, by moving the request into a future
+///     let rows = request.range().map(|row| SharedString::from(format!("Row {row}")));
+///     request.deliver(rows);
+/// });
+///
+/// assert_eq!(model.row_count(), 1000);
+/// // The page is not loaded yet
+/// assert_eq!(model.row_data(120).unwrap(), SharedString::from("Loading..."));
+/// assert!(!model.is_row_loaded(120));
+/// ```
+pub struct LazyModel<T>(Rc<LazyModelInner<T>>);
+
+impl<T: Clone + 'static> LazyModel<T> {
+    /// Creates a new LazyModel with `row_count` rows, loaded by pages of `page_size` rows with
+    /// `load_page`. The rows have the `placeholder` data until they are loaded.
+    pub fn new(
+        row_count: usize,
+        page_size: usize,
+        placeholder: T,
+        load_page: impl Fn(LazyPageRequest<T>) + 'static,
+    ) -> Self {
+        assert!(page_size > 0, "The page size of a LazyModel must not be zero");
+        let inner = LazyModelInner {
+            row_count: Cell::new(row_count),
+            page_size,
+            placeholder,
+            pages: Default::default(),
+            pending_pages: Default::default(),
+            generation: Cell::new(0),
+            load_page: Box::new(load_page),
+            notify: Default::default(),
+        };
+        inner.pages.borrow_mut().resize_with(inner.page_count(), || PageState::NotLoaded);
+        Self(Rc::new(inner))
+    }
+
+    /// Returns the number of rows of a page
+    pub fn page_size(&self) -> usize {
+        self.0.page_size
+    }
+
+    /// Returns true if the data of the row was loaded
+    pub fn is_row_loaded(&self, row: usize) -> bool {
+        match self.0.pages.borrow().get(row / self.0.page_size) {
+            Some(PageState::Loaded(rows)) => row % self.0.page_size < rows.len(),
+            _ => false,
+        }
+    }
+
+    /// Sets the rows of a page, whether it was requested or not.
+    /// This is useful when the rows are delivered from another thread, where the
+    /// [`LazyPageRequest`] can't be sent.
+    pub fn set_page(&self, page: usize, rows: impl IntoIterator<Item = T>) {
+        self.0.set_page(page, rows.into_iter().collect());
+    }
+
+    /// Changes the number of rows. The pages that are loaded stay loaded, except the last one
+    /// if it gets more rows.
+    pub fn set_row_count(&self, row_count: usize) {
+        let old_row_count = self.0.row_count.replace(row_count);
+        let page_count = self.0.page_count();
+        {
+            let mut pages = self.0.pages.borrow_mut();
+            if row_count > old_row_count && old_row_count % self.0.page_size != 0 {
+                // The last page needs to be loaded again to get its new rows
+                if let Some(last_page) = pages.last_mut() {
+                    *last_page = PageState::NotLoaded;
+                }
+            }
+            pages.resize_with(page_count, || PageState::NotLoaded);
+            if let Some(PageState::Loaded(rows)) = pages.last_mut() {
+                rows.truncate(row_count - (page_count - 1) * self.0.page_size);
+            }
+        }
+
+        if row_count > old_row_count {
+            self.0.notify.row_added(old_row_count, row_count - old_row_count);
+        } else if old_row_count > row_count {
+            self.0.notify.row_removed(row_count, old_row_count - row_count);
+        }
+    }
+
+    /// Forgets all the loaded rows, so that they are loaded again when needed.
+    /// The requests that were made before are ignored.
+    pub fn reset(&self) {
+        self.0.generation.set(self.0.generation.get() + 1);
+        for page in self.0.pages.borrow_mut().iter_mut() {
+            *page = PageState::NotLoaded;
+        }
+        self.0.notify.reset();
+    }
+}
+
+impl<T: Clone + 'static> Model for LazyModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.0.row_count.get()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row >= self.0.row_count.get() {
+            return None;
+        }
+        let page = row / self.0.page_size;
+        let mut pages = self.0.pages.borrow_mut();
+        match &pages[page] {
+            PageState::Loaded(rows) => {
+                Some(rows.get(row % self.0.page_size).unwrap_or(&self.0.placeholder).clone())
+            }
+            PageState::Loading(_) => Some(self.0.placeholder.clone()),
+            PageState::NotLoaded => {
+                pages[page] = PageState::Loading(self.0.generation.get());
+                drop(pages);
+                self.0.request_page(page);
+                Some(self.0.placeholder.clone())
+            }
+        }
+    }
+
+    /// Sets the data of a row of a loaded page. The data of the rows that are not loaded can't be set.
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let mut pages = self.0.pages.borrow_mut();
+        if let Some(PageState::Loaded(rows)) = pages.get_mut(row / self.0.page_size) {
+            if let Some(r) = rows.get_mut(row % self.0.page_size) {
+                *r = data;
+                drop(pages);
+                self.0.notify.row_changed(row);
+            }
+        }
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_timers() {
+        crate::timers::TimerList::maybe_activate_timers(crate::animations::current_tick());
+    }
+
+    #[test]
+    fn test_lazy_model_loads_requested_pages() {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let model = LazyModel::new(25, 10, -1, {
+            let requests = requests.clone();
+            move |request: LazyPageRequest<i32>| requests.borrow_mut().push(request)
+        });
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        assert_eq!(model.row_count(), 25);
+        assert_eq!(model.row_data(12), Some(-1));
+        assert_eq!(model.row_data(15), Some(-1));
+        assert_eq!(model.row_data(21), Some(-1));
+        assert_eq!(model.row_data(25), None);
+        // The loader is not called from row_data
+        assert!(requests.borrow().is_empty());
+
+        run_timers();
+        let mut pending: Vec<_> = core::mem::take(&mut *requests.borrow_mut());
+        assert_eq!(pending.iter().map(|r| r.range()).collect::<Vec<_>>(), [10..20, 20..25]);
+
+        // Still loading: no new request
+        assert_eq!(model.row_data(13), Some(-1));
+        run_timers();
+        assert!(requests.borrow().is_empty());
+
+        let last = pending.pop().unwrap();
+        last.deliver([20, 21, 22, 23, 24, 25]);
+        assert_eq!(&*observer.changed_rows.borrow(), &[20, 21, 22, 23, 24]);
+        assert_eq!(model.row_data(24), Some(24));
+        assert!(model.is_row_loaded(20));
+        assert!(!model.is_row_loaded(10));
+
+        let middle = pending.pop().unwrap();
+        assert_eq!(middle.page(), 1);
+        middle.deliver([10, 11, 12]);
+        assert_eq!(model.row_data(12), Some(12));
+        assert_eq!(model.row_data(13), Some(-1));
+        assert!(!model.is_row_loaded(13));
+        assert!(requests.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_lazy_model_reset() {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let model = LazyModel::new(20, 10, -1, {
+            let requests = requests.clone();
+            move |request: LazyPageRequest<i32>| requests.borrow_mut().push(request)
+        });
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        model.row_data(0);
+        run_timers();
+        let request = requests.borrow_mut().pop().unwrap();
+        assert!(!request.is_obsolete());
+
+        model.reset();
+        assert_eq!(*observer.reset.borrow(), 1);
+        assert!(request.is_obsolete());
+        request.deliver([1, 2, 3]);
+        assert_eq!(model.row_data(0), Some(-1));
+        assert!(observer.changed_rows.borrow().is_empty());
+
+        run_timers();
+        let request = requests.borrow_mut().pop().unwrap();
+        assert_eq!(request.range(), 0..10);
+        request.deliver([1, 2, 3]);
+        assert_eq!(model.row_data(0), Some(1));
+
+        model.set_page(1, [10, 11]);
+        assert_eq!(model.row_data(11), Some(11));
+        model.set_row_data(11, 42);
+        assert_eq!(model.row_data(11), Some(42));
+        model.set_row_data(15, 42);
+        assert_eq!(model.row_data(15), Some(-1));
+    }
+
+    #[test]
+    fn test_lazy_model_row_count() {
+        let model = LazyModel::new(15, 10, -1, |request: LazyPageRequest<i32>| {
+            let rows: Vec<_> = request.range().map(|r| r as i32).collect();
+            request.deliver(rows)
+        });
+
+        let observer = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&observer).model_peer());
+
+        model.row_data(0);
+        model.row_data(10);
+        run_timers();
+        assert_eq!(model.row_data(14), Some(14));
+
+        model.set_row_count(12);
+        assert_eq!(&*observer.removed_rows.borrow(), &[(12, 3)]);
+        assert_eq!(model.row_data(11), Some(11));
+        assert_eq!(model.row_data(12), None);
+
+        model.set_row_count(30);
+        assert_eq!(&*observer.added_rows.borrow(), &[(12, 18)]);
+        assert!(model.is_row_loaded(5));
+        // The last page was partially loaded and is loaded again
+        assert_eq!(model.row_data(11), Some(-1));
+        assert_eq!(model.row_data(25), Some(-1));
+        run_timers();
+        assert_eq!(model.row_data(11), Some(11));
+        assert_eq!(model.row_data(19), Some(19));
+        assert_eq!(model.row_data(25), Some(25));
+    }
+}
@@ -0,0 +1,818 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! This module contains the [`TreeModel`] trait, and the adapter that shows the expanded
+//! part of a tree as a flat [`Model`].
+
+use super::*;
+use crate::items::TreeViewItem;
+use alloc::rc::Weak;
+
+/// The interface to receive the changes of a [`TreeModel`].
+///
+/// The indices are the path of the item in the tree: `&[2, 0]` is the first child of the
+/// third top level item. The parent of the top level items is the empty path `&[]`.
+pub trait TreeModelChangeListener {
+    /// The data of the item at `index` was changed
+    fn data_changed(&self, index: &[usize]);
+    /// `count` children were inserted in `parent` at the position `index`
+    fn children_added(&self, parent: &[usize], index: usize, count: usize);
+    /// `count` children were removed from `parent` at the position `index`
+    fn children_removed(&self, parent: &[usize], index: usize, count: usize);
+    /// The tree was changed in some way and everything needs to be reloaded
+    fn reset(&self);
+}
+
+/// This trait defines the interface that users of a tree model can use to track its changes.
+/// It is supplied via [`TreeModel::model_tracker`] and implementations usually return a
+/// reference to their field of [`TreeModelNotify`].
+pub trait TreeModelTracker {
+    /// Attach one listener. The listener will be notified when the tree changes, as long as it is alive
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>);
+}
+
+impl TreeModelTracker for () {
+    fn attach_listener(&self, _listener: Weak<dyn TreeModelChangeListener>) {}
+}
+
+/// Dispatch notifications from a [`TreeModel`] to its listeners.
+/// Typically, you would want to put this in the implementation of the TreeModel
+#[derive(Default)]
+pub struct TreeModelNotify {
+    listeners: RefCell<Vec<Weak<dyn TreeModelChangeListener>>>,
+}
+
+impl TreeModelNotify {
+    fn for_each_listener(&self, f: impl Fn(&dyn TreeModelChangeListener)) {
+        // Don't keep the borrow while notifying, a listener may attach another one
+        let listeners = {
+            let mut listeners = self.listeners.borrow_mut();
+            listeners.retain(|l| l.strong_count() > 0);
+            listeners.clone()
+        };
+        for listener in listeners.iter().filter_map(Weak::upgrade) {
+            f(&*listener)
+        }
+    }
+
+    /// Notify the listeners that the data of an item was changed
+    pub fn data_changed(&self, index: &[usize]) {
+        self.for_each_listener(|l| l.data_changed(index))
+    }
+    /// Notify the listeners that children were added to `parent`
+    pub fn children_added(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.children_added(parent, index, count))
+    }
+    /// Notify the listeners that children were removed from `parent`
+    pub fn children_removed(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.children_removed(parent, index, count))
+    }
+    /// Notify the listeners that the tree has been changed in some way and
+    /// everything needs to be reloaded
+    pub fn reset(&self) {
+        self.for_each_listener(|l| l.reset())
+    }
+}
+
+impl TreeModelTracker for TreeModelNotify {
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>) {
+        self.listeners.borrow_mut().push(listener)
+    }
+}
+
+/// A TreeModel is providing hierarchical data, for example the content of a file system,
+/// the sections of a document or a settings tree.
+///
+/// An item is identified by its index path from the top level: `&[2, 0]` is the first child
+/// of the third top level item. The top level items are the children of the empty path `&[]`.
+///
+/// A tree cannot be used directly in a `for` or a `ListView`: wrap it in a [`FlattenedTreeModel`],
+/// which is a [`Model`] of the items that are visible according to what is expanded.
+///
+/// If the tree can be changed, the type implementing the TreeModel trait should hold
+/// a [`TreeModelNotify`], and is responsible to call functions on it to let the views know
+/// that something has changed. See the implementation of [`VecTreeModel`].
+pub trait TreeModel {
+    /// The data of each item of the tree
+    type Data;
+    /// The amount of children of the item at `parent`, or the amount of top level
+    /// items if `parent` is empty
+    fn child_count(&self, parent: &[usize]) -> usize;
+    /// Returns the data of the item at `index`, or None if there is no such item
+    fn data(&self, index: &[usize]) -> Option<Self::Data>;
+    /// Sets the data of the item at `index`.
+    ///
+    /// If the model cannot support data changes, then it is ok to do nothing.
+    /// The default implementation will print a warning to stderr.
+    ///
+    /// If the model can update the data, it should also call [`TreeModelNotify::data_changed`]
+    /// on its internal [`TreeModelNotify`].
+    fn set_data(&self, _index: &[usize], _data: Self::Data) {
+        #[cfg(feature = "std")]
+        eprintln!(
+            "TreeModel::set_data called on a model of type {} which does not re-implement this method. \
+            This happens when trying to modify a read-only model",
+            core::any::type_name::<Self>(),
+        );
+    }
+
+    /// The implementation should return a reference to its [`TreeModelNotify`] field.
+    ///
+    /// You can return `&()` if your `TreeModel` is constant and does not have a TreeModelNotify field.
+    fn model_tracker(&self) -> &dyn TreeModelTracker;
+
+    /// Return something that can be downcast'ed (typically self)
+    fn as_any(&self) -> &dyn core::any::Any {
+        &()
+    }
+}
+
+impl<M: TreeModel> TreeModel for Rc<M> {
+    type Data = M::Data;
+
+    fn child_count(&self, parent: &[usize]) -> usize {
+        (**self).child_count(parent)
+    }
+
+    fn data(&self, index: &[usize]) -> Option<Self::Data> {
+        (**self).data(index)
+    }
+
+    fn set_data(&self, index: &[usize], data: Self::Data) {
+        (**self).set_data(index, data)
+    }
+
+    fn model_tracker(&self) -> &dyn TreeModelTracker {
+        (**self).model_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        (**self).as_any()
+    }
+}
+
+/// An item of a [`VecTreeModel`], with its children
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeNode<T> {
+    /// The data of the item
+    pub data: T,
+    /// The children of the item
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates an item without children
+    pub fn new(data: T) -> Self {
+        Self { data, children: Vec::new() }
+    }
+
+    /// Creates an item with the given children
+    pub fn with_children(data: T, children: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self { data, children: children.into_iter().collect() }
+    }
+
+    fn get<'a>(nodes: &'a [Self], index: &[usize]) -> Option<&'a Self> {
+        let (first, rest) = index.split_first()?;
+        let node = nodes.get(*first)?;
+        if rest.is_empty() {
+            Some(node)
+        } else {
+            Self::get(&node.children, rest)
+        }
+    }
+
+    fn get_mut<'a>(nodes: &'a mut [Self], index: &[usize]) -> Option<&'a mut Self> {
+        let (first, rest) = index.split_first()?;
+        let node = nodes.get_mut(*first)?;
+        if rest.is_empty() {
+            Some(node)
+        } else {
+            Self::get_mut(&mut node.children, rest)
+        }
+    }
+}
+
+/// A [`TreeModel`] backed by nested `Vec<TreeNode<T>>`
+///
+/// ## Example
+///
+/// ```
+/// # use i_slint_core::model::{TreeModel, TreeNode, VecTreeModel};
+/// let tree = VecTreeModel::from(vec![
+///     TreeNode::with_children("src", [TreeNode::new("lib.rs"), TreeNode::new("main.rs")]),
+///     TreeNode::new("Cargo.toml"),
+/// ]);
+/// tree.push(&[0], TreeNode::new("tests.rs"));
+/// assert_eq!(tree.child_count(&[]), 2);
+/// assert_eq!(tree.child_count(&[0]), 3);
+/// assert_eq!(tree.data(&[0, 2]), Some("tests.rs"));
+/// ```
+pub struct VecTreeModel<T> {
+    roots: RefCell<Vec<TreeNode<T>>>,
+    notify: TreeModelNotify,
+}
+
+impl<T> Default for VecTreeModel<T> {
+    fn default() -> Self {
+        Self { roots: Default::default(), notify: Default::default() }
+    }
+}
+
+impl<T> From<Vec<TreeNode<T>>> for VecTreeModel<T> {
+    fn from(roots: Vec<TreeNode<T>>) -> Self {
+        Self { roots: RefCell::new(roots), notify: Default::default() }
+    }
+}
+
+impl<T> FromIterator<TreeNode<T>> for VecTreeModel<T> {
+    fn from_iter<I: IntoIterator<Item = TreeNode<T>>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: 'static> VecTreeModel<T> {
+    fn with_children<R>(&self, parent: &[usize], f: impl FnOnce(&mut Vec<TreeNode<T>>) -> R) -> R {
+        let mut roots = self.roots.borrow_mut();
+        if parent.is_empty() {
+            f(&mut roots)
+        } else {
+            let node = TreeNode::get_mut(&mut roots, parent)
+                .unwrap_or_else(|| panic!("invalid tree index {parent:?}"));
+            f(&mut node.children)
+        }
+    }
+
+    /// Inserts `node` as the child of `parent` at the position `index`.
+    /// `parent` is empty to insert a top level item.
+    ///
+    /// Panics if `parent` is not a valid index, or if `index` is greater than its number of children
+    pub fn insert(&self, parent: &[usize], index: usize, node: TreeNode<T>) {
+        self.with_children(parent, |children| children.insert(index, node));
+        self.notify.children_added(parent, index, 1);
+    }
+
+    /// Adds `node` as the last child of `parent`.
+    /// `parent` is empty to add a top level item.
+    pub fn push(&self, parent: &[usize], node: TreeNode<T>) {
+        let index = self.with_children(parent, |children| {
+            children.push(node);
+            children.len() - 1
+        });
+        self.notify.children_added(parent, index, 1);
+    }
+
+    /// Removes the item at `index`, and returns it with its children.
+    ///
+    /// Panics if `index` is not a valid index
+    pub fn remove(&self, index: &[usize]) -> TreeNode<T> {
+        let (last, parent) = index.split_last().expect("cannot remove the root of the tree");
+        let node = self.with_children(parent, |children| children.remove(*last));
+        self.notify.children_removed(parent, *last, 1);
+        node
+    }
+
+    /// Replaces the children of `parent` by `children`.
+    /// `parent` is empty to replace all the top level items.
+    pub fn set_children(&self, parent: &[usize], children: Vec<TreeNode<T>>) {
+        let (old_count, new_count) = self.with_children(parent, |old| {
+            let count = children.len();
+            (core::mem::replace(old, children).len(), count)
+        });
+        if old_count > 0 {
+            self.notify.children_removed(parent, 0, old_count);
+        }
+        if new_count > 0 {
+            self.notify.children_added(parent, 0, new_count);
+        }
+    }
+
+    /// Replace the whole tree
+    pub fn set_vec(&self, roots: impl Into<Vec<TreeNode<T>>>) {
+        *self.roots.borrow_mut() = roots.into();
+        self.notify.reset();
+    }
+}
+
+impl<T: Clone + 'static> TreeModel for VecTreeModel<T> {
+    type Data = T;
+
+    fn child_count(&self, parent: &[usize]) -> usize {
+        let roots = self.roots.borrow();
+        if parent.is_empty() {
+            roots.len()
+        } else {
+            TreeNode::get(&roots, parent).map_or(0, |n| n.children.len())
+        }
+    }
+
+    fn data(&self, index: &[usize]) -> Option<Self::Data> {
+        TreeNode::get(&self.roots.borrow(), index).map(|n| n.data.clone())
+    }
+
+    fn set_data(&self, index: &[usize], data: Self::Data) {
+        if let Some(node) = TreeNode::get_mut(&mut self.roots.borrow_mut(), index) {
+            node.data = data;
+        } else {
+            return;
+        }
+        self.notify.data_changed(index);
+    }
+
+    fn model_tracker(&self) -> &dyn TreeModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+/// A row of a [`FlattenedTreeModel`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlattenedTreeRow<T> {
+    /// The data of the item, as provided by the [`TreeModel`]
+    pub data: T,
+    /// The index of the item in the tree
+    pub index: Vec<usize>,
+    /// Whether the item has children
+    pub has_children: bool,
+    /// Whether the children of the item are shown in the following rows
+    pub expanded: bool,
+}
+
+impl<T> FlattenedTreeRow<T> {
+    /// The depth of the item in the tree. The top level items have the level 0
+    pub fn level(&self) -> usize {
+        self.index.len().saturating_sub(1)
+    }
+}
+
+impl<T: Into<SharedString>> From<FlattenedTreeRow<T>> for TreeViewItem {
+    fn from(row: FlattenedTreeRow<T>) -> Self {
+        TreeViewItem {
+            level: row.level() as i32,
+            has_children: row.has_children,
+            expanded: row.expanded,
+            text: row.data.into(),
+        }
+    }
+}
+
+struct FlatRow {
+    index: Vec<usize>,
+    expanded: bool,
+}
+
+struct FlattenedTreeModelInner<M> {
+    tree: M,
+    // The visible items, in depth-first order, which is also the order of their index path.
+    // The children of an item are visible if the item and all its ancestors are expanded.
+    rows: RefCell<Vec<FlatRow>>,
+    notify: ModelNotify,
+}
+
+impl<M: TreeModel> FlattenedTreeModelInner<M> {
+    /// The visible rows of the children of `parent`, keeping expanded the items whose index
+    /// is in the sorted `expanded` list.
+    fn build_rows(
+        &self,
+        parent: &mut Vec<usize>,
+        expanded: &[Vec<usize>],
+        rows: &mut Vec<FlatRow>,
+    ) {
+        for i in 0..self.tree.child_count(parent) {
+            parent.push(i);
+            let is_expanded =
+                expanded.binary_search(parent).is_ok() && self.tree.child_count(parent) > 0;
+            rows.push(FlatRow { index: parent.clone(), expanded: is_expanded });
+            if is_expanded {
+                self.build_rows(parent, expanded, rows);
+            }
+            parent.pop();
+        }
+    }
+
+    /// The row of the item at `index`, or None if it is not visible
+    fn row_of(&self, index: &[usize]) -> Option<usize> {
+        self.rows.borrow().binary_search_by(|r| r.index.as_slice().cmp(index)).ok()
+    }
+
+    /// Whether the children of `parent` are visible, and the row of `parent` if it is not the root
+    fn visible_children(&self, parent: &[usize]) -> (bool, Option<usize>) {
+        if parent.is_empty() {
+            return (true, None);
+        }
+        match self.row_of(parent) {
+            Some(row) => (self.rows.borrow()[row].expanded, Some(row)),
+            None => (false, None),
+        }
+    }
+}
+
+/// Adds `delta` to the position of the children of `parent` that are at the position `from` or
+/// after, in the index of the rows that are within them.
+fn shift_indices(rows: &mut [FlatRow], parent: &[usize], from: usize, delta: isize) {
+    let level = parent.len();
+    for row in rows.iter_mut() {
+        if row.index.len() > level && row.index.starts_with(parent) && row.index[level] >= from {
+            row.index[level] = row.index[level].wrapping_add_signed(delta);
+        }
+    }
+}
+
+impl<M: TreeModel> TreeModelChangeListener for FlattenedTreeModelInner<M> {
+    fn data_changed(&self, index: &[usize]) {
+        if let Some(row) = self.row_of(index) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn children_added(&self, parent: &[usize], index: usize, count: usize) {
+        let (visible, parent_row) = self.visible_children(parent);
+        if visible && count > 0 {
+            let position = {
+                let mut rows = self.rows.borrow_mut();
+                shift_indices(&mut rows, parent, index, count as isize);
+                let mut first = parent.to_vec();
+                first.push(index);
+                let position = rows.partition_point(|r| r.index < first);
+                rows.splice(
+                    position..position,
+                    (index..index + count).map(|i| {
+                        let mut index = parent.to_vec();
+                        index.push(i);
+                        FlatRow { index, expanded: false }
+                    }),
+                );
+                position
+            };
+            self.notify.row_added(position, count);
+        }
+        // The parent may have got its first children
+        if let Some(parent_row) = parent_row {
+            self.notify.row_changed(parent_row);
+        }
+    }
+
+    fn children_removed(&self, parent: &[usize], index: usize, count: usize) {
+        let (visible, parent_row) = self.visible_children(parent);
+        if visible && count > 0 {
+            let (position, removed) = {
+                let mut rows = self.rows.borrow_mut();
+                let mut first = parent.to_vec();
+                first.push(index);
+                let start = rows.partition_point(|r| r.index < first);
+                *first.last_mut().unwrap() = index + count;
+                let end = rows.partition_point(|r| r.index < first);
+                rows.drain(start..end);
+                shift_indices(&mut rows, parent, index + count, -(count as isize));
+                (start, end - start)
+            };
+            if removed > 0 {
+                self.notify.row_removed(position, removed);
+            }
+        }
+        // The parent may have lost its last children
+        if let Some(parent_row) = parent_row {
+            if self.tree.child_count(parent) == 0 {
+                self.rows.borrow_mut()[parent_row].expanded = false;
+            }
+            self.notify.row_changed(parent_row);
+        }
+    }
+
+    fn reset(&self) {
+        // Keep expanded the items that still exist
+        let expanded = self
+            .rows
+            .borrow()
+            .iter()
+            .filter(|r| r.expanded)
+            .map(|r| r.index.clone())
+            .collect::<Vec<_>>();
+        let mut rows = Vec::new();
+        self.build_rows(&mut Vec::new(), &expanded, &mut rows);
+        *self.rows.borrow_mut() = rows;
+        self.notify.reset();
+    }
+}
+
+/// Provides the items of a [`TreeModel`] that are visible according to which items are
+/// expanded, as a flat [`Model`] that can be used in a `for` or a `ListView`.
+///
+/// Initially, only the top level items are visible. The children of an item are inserted
+/// in the following rows when the item is expanded with [`Self::expand`], [`Self::toggle`]
+/// or [`Self::set_expanded`], and are removed when it is collapsed.
+///
+/// When the tree is updated, the `FlattenedTreeModel` is updated accordingly.
+///
+/// The rows are [`FlattenedTreeRow`] that can be converted to the [`TreeViewItem`] expected
+/// by the `TreeView` widget.
+///
+/// ## Example
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use i_slint_core::model::*;
+/// let tree = Rc::new(VecTreeModel::from(vec![
+///     TreeNode::with_children("src", [TreeNode::new("lib.rs"), TreeNode::new("main.rs")]),
+///     TreeNode::new("Cargo.toml"),
+/// ]));
+/// let flattened = Rc::new(FlattenedTreeModel::new(tree.clone()));
+/// assert_eq!(flattened.row_count(), 2);
+///
+/// flattened.expand(0);
+/// let texts = flattened.iter().map(|row| row.data).collect::<Vec<_>>();
+/// assert_eq!(texts, ["src", "lib.rs", "main.rs", "Cargo.toml"]);
+/// assert_eq!(flattened.row_data(1).unwrap().level(), 1);
+///
+/// tree.remove(&[0, 0]);
+/// assert_eq!(flattened.row_count(), 3);
+///
+/// // The model to set on a TreeView
+/// let items = ModelRc::new(flattened.clone().map(TreeViewItem::from));
+/// assert_eq!(items.row_data(1).unwrap().text, "main.rs");
+/// ```
+pub struct FlattenedTreeModel<M>(Rc<FlattenedTreeModelInner<M>>);
+
+impl<M: TreeModel + 'static> FlattenedTreeModel<M> {
+    /// Creates a new FlattenedTreeModel showing the top level items of `tree`
+    pub fn new(tree: M) -> Self {
+        let inner = Rc::new(FlattenedTreeModelInner {
+            tree,
+            rows: Default::default(),
+            notify: Default::default(),
+        });
+        let mut rows = Vec::new();
+        inner.build_rows(&mut Vec::new(), &[], &mut rows);
+        *inner.rows.borrow_mut() = rows;
+        let listener: Rc<dyn TreeModelChangeListener> = inner.clone();
+        inner.tree.model_tracker().attach_listener(Rc::downgrade(&listener));
+        Self(inner)
+    }
+
+    /// Returns a reference to the tree
+    pub fn tree(&self) -> &M {
+        &self.0.tree
+    }
+
+    /// Returns the index in the tree of the item shown at `row`
+    pub fn index_of_row(&self, row: usize) -> Option<Vec<usize>> {
+        self.0.rows.borrow().get(row).map(|r| r.index.clone())
+    }
+
+    /// Returns the row of the item at `index` in the tree, or None if the item is not visible
+    pub fn row_of_index(&self, index: &[usize]) -> Option<usize> {
+        self.0.row_of(index)
+    }
+
+    /// Returns whether the item at `row` is expanded
+    pub fn is_expanded(&self, row: usize) -> bool {
+        self.0.rows.borrow().get(row).map_or(false, |r| r.expanded)
+    }
+
+    /// Expands or collapses the item at `row`.
+    ///
+    /// Collapsing an item also collapses all its descendants.
+    pub fn set_expanded(&self, row: usize, expanded: bool) {
+        if expanded {
+            self.expand(row)
+        } else {
+            self.collapse(row)
+        }
+    }
+
+    /// Shows the children of the item at `row` in the following rows.
+    ///
+    /// This does nothing if the item has no children.
+    pub fn expand(&self, row: usize) {
+        let added = {
+            let mut rows = self.0.rows.borrow_mut();
+            let Some(r) = rows.get_mut(row).filter(|r| !r.expanded) else { return };
+            let mut index = r.index.clone();
+            let count = self.0.tree.child_count(&index);
+            if count == 0 {
+                return;
+            }
+            r.expanded = true;
+            rows.splice(
+                row + 1..row + 1,
+                (0..count).map(|i| {
+                    index.push(i);
+                    let r = FlatRow { index: index.clone(), expanded: false };
+                    index.pop();
+                    r
+                }),
+            );
+            count
+        };
+        self.0.notify.row_changed(row);
+        self.0.notify.row_added(row + 1, added);
+    }
+
+    /// Hides the descendants of the item at `row`
+    pub fn collapse(&self, row: usize) {
+        let removed = {
+            let mut rows = self.0.rows.borrow_mut();
+            let Some(r) = rows.get_mut(row).filter(|r| r.expanded) else { return };
+            r.expanded = false;
+            let level = r.index.len();
+            let count = rows[row + 1..].iter().take_while(|r| r.index.len() > level).count();
+            rows.drain(row + 1..row + 1 + count);
+            count
+        };
+        self.0.notify.row_changed(row);
+        if removed > 0 {
+            self.0.notify.row_removed(row + 1, removed);
+        }
+    }
+
+    /// Expands the item at `row` if it is collapsed, and collapses it otherwise
+    pub fn toggle(&self, row: usize) {
+        self.set_expanded(row, !self.is_expanded(row))
+    }
+}
+
+impl<M: TreeModel + 'static> Model for FlattenedTreeModel<M> {
+    type Data = FlattenedTreeRow<M::Data>;
+
+    fn row_count(&self) -> usize {
+        self.0.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let (index, expanded) = {
+            let rows = self.0.rows.borrow();
+            let r = rows.get(row)?;
+            (r.index.clone(), r.expanded)
+        };
+        Some(FlattenedTreeRow {
+            data: self.0.tree.data(&index)?,
+            has_children: self.0.tree.child_count(&index) > 0,
+            expanded,
+            index,
+        })
+    }
+
+    /// Sets the data of the item in the tree, and expands or collapses it
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let Some(index) = self.index_of_row(row) else { return };
+        self.set_expanded(row, data.expanded);
+        self.0.tree.set_data(&index, data.data);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts<M: Model<Data = FlattenedTreeRow<&'static str>>>(model: &M) -> Vec<String> {
+        model.iter().map(|r| alloc::format!("{}{}", " ".repeat(r.level()), r.data)).collect()
+    }
+
+    fn test_tree() -> Rc<VecTreeModel<&'static str>> {
+        Rc::new(VecTreeModel::from(vec![
+            TreeNode::with_children(
+                "a",
+                [TreeNode::with_children("a0", [TreeNode::new("a00")]), TreeNode::new("a1")],
+            ),
+            TreeNode::with_children("b", [TreeNode::new("b0")]),
+            TreeNode::new("c"),
+        ]))
+    }
+
+    #[test]
+    fn test_expand_collapse() {
+        let tree = test_tree();
+        let model = FlattenedTreeModel::new(tree.clone());
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+
+        assert_eq!(texts(&model), ["a", "b", "c"]);
+        assert!(model.row_data(0).unwrap().has_children);
+        assert!(!model.row_data(2).unwrap().has_children);
+
+        model.expand(0);
+        assert_eq!(texts(&model), ["a", " a0", " a1", "b", "c"]);
+        assert_eq!(&*view.changed_rows.borrow(), &[0]);
+        assert_eq!(&*view.added_rows.borrow(), &[(1, 2)]);
+        view.clear();
+
+        model.toggle(1);
+        model.expand(4);
+        assert_eq!(texts(&model), ["a", " a0", "  a00", " a1", "b", " b0", "c"]);
+        assert_eq!(model.row_data(2).unwrap().index, [0, 0, 0]);
+        assert_eq!(model.row_of_index(&[1, 0]), Some(5));
+        assert!(model.is_expanded(1));
+        view.clear();
+
+        // Collapsing forgets the expanded state of the descendants
+        model.collapse(0);
+        assert_eq!(texts(&model), ["a", "b", " b0", "c"]);
+        assert_eq!(&*view.removed_rows.borrow(), &[(1, 3)]);
+        model.expand(0);
+        assert_eq!(texts(&model), ["a", " a0", " a1", "b", " b0", "c"]);
+        view.clear();
+
+        // An item without children cannot be expanded
+        model.set_expanded(5, true);
+        assert!(!model.is_expanded(5));
+        assert!(view.changed_rows.borrow().is_empty());
+        assert!(view.added_rows.borrow().is_empty());
+        assert_eq!(*view.reset.borrow(), 0);
+
+        // Removing the last child of an item collapses it
+        tree.remove(&[1, 0]);
+        assert_eq!(texts(&model), ["a", " a0", " a1", "b", "c"]);
+        assert!(!model.is_expanded(3));
+        tree.push(&[1], TreeNode::new("b1"));
+        assert_eq!(texts(&model), ["a", " a0", " a1", "b", "c"]);
+    }
+
+    #[test]
+    fn test_tree_changes() {
+        let tree = test_tree();
+        let model = FlattenedTreeModel::new(tree.clone());
+        model.expand(0);
+        model.expand(1);
+        assert_eq!(texts(&model), ["a", " a0", "  a00", " a1", "b", "c"]);
+        let view = Box::pin(ModelChangeListenerContainer::<TestView>::default());
+        model.model_tracker().attach_peer(Pin::as_ref(&view).model_peer());
+
+        tree.insert(&[0], 0, TreeNode::new("new"));
+        assert_eq!(texts(&model), ["a", " new", " a0", "  a00", " a1", "b", "c"]);
+        assert_eq!(model.row_data(3).unwrap().index, [0, 1, 0]);
+        assert!(model.is_expanded(2));
+        assert_eq!(&*view.added_rows.borrow(), &[(1, 1)]);
+        assert_eq!(&*view.changed_rows.borrow(), &[0]);
+        view.clear();
+
+        // Children of a collapsed item are not shown
+        tree.push(&[1], TreeNode::new("b1"));
+        assert_eq!(texts(&model), ["a", " new", " a0", "  a00", " a1", "b", "c"]);
+        assert!(view.added_rows.borrow().is_empty());
+        assert_eq!(&*view.changed_rows.borrow(), &[5]);
+        view.clear();
+
+        tree.push(&[], TreeNode::new("d"));
+        assert_eq!(texts(&model), ["a", " new", " a0", "  a00", " a1", "b", "c", "d"]);
+        assert_eq!(&*view.added_rows.borrow(), &[(7, 1)]);
+        view.clear();
+
+        assert_eq!(tree.remove(&[0, 1]).children.len(), 1);
+        assert_eq!(texts(&model), ["a", " new", " a1", "b", "c", "d"]);
+        assert_eq!(&*view.removed_rows.borrow(), &[(2, 2)]);
+        assert_eq!(model.row_data(2).unwrap().index, [0, 1]);
+        view.clear();
+
+        tree.set_data(&[0, 1], "a1'");
+        assert_eq!(&*view.changed_rows.borrow(), &[2]);
+        tree.set_data(&[1, 0], "b0'");
+        assert_eq!(&*view.changed_rows.borrow(), &[2]);
+
+        tree.remove(&[0]);
+        assert_eq!(texts(&model), ["b", "c", "d"]);
+        assert_eq!(model.row_data(0).unwrap().index, [0]);
+    }
+
+    #[test]
+    fn test_reset_keeps_expanded() {
+        let tree = test_tree();
+        let model = FlattenedTreeModel::new(tree.clone());
+        model.expand(0);
+        model.expand(3);
+        assert_eq!(texts(&model), ["a", " a0", " a1", "b", " b0", "c"]);
+
+        tree.set_vec(vec![TreeNode::with_children("x", [TreeNode::new("x0")]), TreeNode::new("y")]);
+        assert_eq!(texts(&model), ["x", " x0", "y"]);
+        assert!(model.is_expanded(0));
+        assert!(!model.is_expanded(2));
+    }
+
+    #[test]
+    fn test_tree_view_item() {
+        let tree = test_tree();
+        let model = Rc::new(FlattenedTreeModel::new(tree));
+        let items = ModelRc::new(model.clone().map(TreeViewItem::from));
+        model.expand(0);
+        assert_eq!(
+            items.row_data(1),
+            Some(TreeViewItem { text: "a0".into(), level: 1, has_children: true, expanded: false })
+        );
+
+        // Setting the row data of the flattened model changes the expanded state
+        let mut row = model.row_data(1).unwrap();
+        row.expanded = true;
+        row.data = "A0";
+        model.set_row_data(1, row);
+        assert_eq!(texts(&*model), ["a", " A0", "  a00", " a1", "b", "c"]);
+    }
+}
@@ -187,18 +187,23 @@ pub fn set_platform(platform: Box<dyn Platform + 'static>) -> Result<(), SetPlat
 pub fn update_timers_and_animations() {
     crate::animations::update_animations();
     crate::timers::TimerList::maybe_activate_timers(crate::animations::Instant::now());
+    crate::properties::ChangeTracker::run_change_handlers();
 }
 
 /// Returns the duration before the next timer is expected to be activated. This is the
 /// largest amount of time that you can wait before calling [`update_timers_and_animations()`].
 ///
-/// `None` is returned if there is no active timer.
+/// `None` is returned if there is no active timer. A zero duration is returned if some
+/// `changed` handlers still need to be run.
 ///
 /// Call this in your own event loop implementation to know how long the current thread can
 /// go to sleep. Note that this does not take currently activate animations into account.
 /// Only go to sleep if [`Window::has_active_animations()`](crate::api::Window::has_active_animations())
 /// returns false.
 pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
+    if crate::properties::ChangeTracker::has_pending_change_handlers() {
+        return Some(core::time::Duration::ZERO);
+    }
     crate::timers::TimerList::next_timeout().map(|timeout| {
         let duration_since_start = crate::platform::PLATFORM_INSTANCE
             .with(|p| p.get().map(|p| p.duration_since_start()))
@@ -251,6 +256,33 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger touched the screen.
+    ///
+    /// The first finger touching the screen is also dispatched to the items as a left mouse
+    /// button press, so backends must not send a separate [`WindowEvent::PointerPressed`] for it.
+    TouchPressed {
+        /// Identifies the finger until it is released, as given by the windowing system.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger touching the screen moved.
+    TouchMoved {
+        /// The id of the finger, as given in [`WindowEvent::TouchPressed`].
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger was lifted from the screen.
+    TouchReleased {
+        /// The id of the finger, as given in [`WindowEvent::TouchPressed`].
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// The windowing system cancelled the touch of a finger, for example because it recognized
+    /// a system gesture.
+    TouchCancelled {
+        /// The id of the finger, as given in [`WindowEvent::TouchPressed`].
+        id: u64,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -313,6 +345,9 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
             _ => None,
         }
     }
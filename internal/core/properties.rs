@@ -1146,6 +1146,9 @@ fn property_two_ways_recurse_from_binding() {
 pub use crate::items::StateInfo;
 pub use properties_animations::*;
 
+mod change_tracker;
+pub use change_tracker::*;
+
 struct StateInfoBinding<F> {
     dirty_time: Cell<Option<crate::animations::Instant>>,
     binding: F,
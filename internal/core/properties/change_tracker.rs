@@ -0,0 +1,215 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The [`ChangeTracker`] is used to implement the `changed <property> => { ... }` handlers.
+
+use super::{PropertyDirtyHandler, PropertyTracker};
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::pin::Pin;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+thread_local!(
+    /// The trackers for which one of the dependencies became dirty, and that need to be re-evaluated
+    static PENDING_CHANGE_TRACKERS: RefCell<Vec<Weak<ChangeTrackerInner>>> = RefCell::default()
+);
+
+/// How many times `run_change_handlers` re-evaluates the trackers made dirty by the handlers
+/// themselves before giving up. This avoids looping forever when a handler changes the property
+/// it is tracking.
+const MAX_ITERATIONS: usize = 10;
+
+pub(crate) trait ChangeTrackerHandler {
+    /// Evaluate the tracked value and return true if it is different from the previous time
+    /// this function was called. The first call must return false.
+    fn evaluate(&self) -> bool;
+    /// Called after `evaluate` returned true
+    fn notify(&self);
+}
+
+struct QueueOnDirty(Weak<ChangeTrackerInner>);
+
+impl PropertyDirtyHandler for QueueOnDirty {
+    fn notify(&self) {
+        let weak = self.0.clone();
+        PENDING_CHANGE_TRACKERS.with(|pending| pending.borrow_mut().push(weak));
+    }
+}
+
+struct ChangeTrackerInner {
+    tracker: PropertyTracker<QueueOnDirty>,
+    handler: Box<dyn ChangeTrackerHandler>,
+}
+
+impl ChangeTrackerInner {
+    /// Re-evaluate the value and return true if it changed
+    fn evaluate(&self) -> bool {
+        // Safety: ChangeTrackerInner is only ever accessed through a Rc, so it is never moved
+        let tracker = unsafe { Pin::new_unchecked(&self.tracker) };
+        tracker.evaluate_as_dependency_root(|| self.handler.evaluate())
+    }
+}
+
+/// Calls a function when the value computed by another function changes.
+///
+/// The value is computed within a [`PropertyTracker`]. When one of the properties it depends
+/// on is changed, the tracker is queued and the value is re-computed the next time
+/// [`ChangeTracker::run_change_handlers()`] is called, which is done by
+/// [`crate::platform::update_timers_and_animations()`].
+/// The notify function is only called if the new value is different from the previous one.
+#[derive(Default)]
+pub struct ChangeTracker {
+    inner: Cell<Option<Rc<ChangeTrackerInner>>>,
+}
+
+impl ChangeTracker {
+    /// Start tracking the value returned by `eval_fn` and call `notify_fn` with the new value
+    /// when it changes.
+    ///
+    /// `eval_fn` is called once immediately to record the initial value, `notify_fn` is not
+    /// called for that initial value.
+    pub fn init<Data: 'static, T: PartialEq + 'static>(
+        &self,
+        data: Data,
+        eval_fn: impl Fn(&Data) -> T + 'static,
+        notify_fn: impl Fn(&Data, &T) + 'static,
+    ) {
+        struct Handler<Data, T, EvalFn, NotifyFn> {
+            data: Data,
+            value: RefCell<Option<T>>,
+            eval_fn: EvalFn,
+            notify_fn: NotifyFn,
+        }
+
+        impl<Data, T: PartialEq, EvalFn: Fn(&Data) -> T, NotifyFn: Fn(&Data, &T)>
+            ChangeTrackerHandler for Handler<Data, T, EvalFn, NotifyFn>
+        {
+            fn evaluate(&self) -> bool {
+                let new_value = (self.eval_fn)(&self.data);
+                let mut value = self.value.borrow_mut();
+                let changed = value.as_ref().map_or(false, |v| *v != new_value);
+                *value = Some(new_value);
+                changed
+            }
+
+            fn notify(&self) {
+                if let Some(value) = self.value.borrow().as_ref() {
+                    (self.notify_fn)(&self.data, value)
+                }
+            }
+        }
+
+        self.init_with_handler(Box::new(Handler {
+            data,
+            value: RefCell::new(None),
+            eval_fn,
+            notify_fn,
+        }))
+    }
+
+    pub(crate) fn init_with_handler(&self, handler: Box<dyn ChangeTrackerHandler>) {
+        let inner = Rc::new_cyclic(|weak| ChangeTrackerInner {
+            tracker: PropertyTracker::new_with_dirty_handler(QueueOnDirty(weak.clone())),
+            handler,
+        });
+        inner.evaluate();
+        self.inner.set(Some(inner));
+    }
+
+    /// Re-evaluate all the trackers whose dependencies changed since the last call, and call
+    /// the notify function of the ones whose value changed.
+    pub fn run_change_handlers() {
+        for _ in 0..MAX_ITERATIONS {
+            let pending =
+                PENDING_CHANGE_TRACKERS.with(|pending| core::mem::take(&mut *pending.borrow_mut()));
+            if pending.is_empty() {
+                return;
+            }
+            for inner in pending.iter().filter_map(Weak::upgrade) {
+                if inner.evaluate() {
+                    inner.handler.notify();
+                }
+            }
+        }
+        let pending =
+            PENDING_CHANGE_TRACKERS.with(|pending| core::mem::take(&mut *pending.borrow_mut()));
+        if pending.is_empty() {
+            return;
+        }
+        crate::debug_log!(
+            "Slint: the changed callbacks are still changing their own properties after {MAX_ITERATIONS} iterations. Probable binding loop"
+        );
+        // Evaluate the trackers without calling their handlers, so that they only run again
+        // on the next change of one of their dependencies instead of being kept pending forever.
+        for inner in pending.iter().filter_map(Weak::upgrade) {
+            inner.evaluate();
+        }
+    }
+
+    /// Returns true if some trackers need to be re-evaluated by [`Self::run_change_handlers()`]
+    pub fn has_pending_change_handlers() -> bool {
+        PENDING_CHANGE_TRACKERS.with(|pending| !pending.borrow().is_empty())
+    }
+}
+
+#[test]
+fn test_change_tracker() {
+    use super::Property;
+    let prop = Rc::pin(Property::new(42));
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        (prop.clone(), received.clone()),
+        |(prop, _)| prop.as_ref().get() / 2,
+        |(_, received), value| received.borrow_mut().push(*value),
+    );
+    ChangeTracker::run_change_handlers();
+    assert!(received.borrow().is_empty());
+    prop.as_ref().set(43);
+    ChangeTracker::run_change_handlers();
+    // Same value
+    assert!(received.borrow().is_empty());
+    prop.as_ref().set(50);
+    prop.as_ref().set(60);
+    assert!(received.borrow().is_empty());
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*received.borrow(), [30]);
+    drop(tracker);
+    prop.as_ref().set(70);
+    ChangeTracker::run_change_handlers();
+    assert_eq!(*received.borrow(), [30]);
+}
+
+#[test]
+fn test_change_tracker_self_modifying() {
+    use super::Property;
+    let prop = Rc::pin(Property::new(0));
+    let count = Rc::new(Cell::new(0));
+    let tracker = ChangeTracker::default();
+    // The handler changes the property it tracks, so it would run forever
+    tracker.init(
+        (prop.clone(), count.clone()),
+        |(prop, _)| prop.as_ref().get(),
+        |(prop, count), value| {
+            count.set(count.get() + 1);
+            prop.as_ref().set(value + 1);
+        },
+    );
+    prop.as_ref().set(1);
+    assert!(ChangeTracker::has_pending_change_handlers());
+    ChangeTracker::run_change_handlers();
+    assert_eq!(count.get(), MAX_ITERATIONS);
+    assert!(!ChangeTracker::has_pending_change_handlers());
+    ChangeTracker::run_change_handlers();
+    assert_eq!(count.get(), MAX_ITERATIONS);
+    // The tracker still reacts to the next change
+    prop.as_ref().set(100);
+    assert!(ChangeTracker::has_pending_change_handlers());
+    ChangeTracker::run_change_handlers();
+    assert_eq!(count.get(), 2 * MAX_ITERATIONS);
+    assert!(!ChangeTracker::has_pending_change_handlers());
+}
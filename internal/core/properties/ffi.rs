@@ -451,6 +451,68 @@ pub struct PropertyTrackerOpaque {
     core::ptr::drop_in_place(handle as *mut PropertyTracker);
 }
 
+#[repr(C)]
+/// Opaque type representing the ChangeTracker
+pub struct ChangeTrackerOpaque {
+    inner: usize,
+}
+
+static_assertions::assert_eq_align!(ChangeTrackerOpaque, ChangeTracker);
+static_assertions::assert_eq_size!(ChangeTrackerOpaque, ChangeTracker);
+
+/// Initialize the first pointer of the ChangeTracker.
+/// `out` is assumed to be uninitialized
+/// slint_change_tracker_drop need to be called after that
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_construct(out: *mut ChangeTrackerOpaque) {
+    core::ptr::write(out as *mut ChangeTracker, ChangeTracker::default());
+}
+
+/// Start tracking the value computed by `eval_fn`.
+/// `eval_fn` must return true if the value is different from the previous call (and false the
+/// first time it is called), in which case `notify_fn` is called.
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_init(
+    handle: *const ChangeTrackerOpaque,
+    user_data: *mut c_void,
+    drop_user_data: extern "C" fn(user_data: *mut c_void),
+    eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+    notify_fn: extern "C" fn(user_data: *mut c_void),
+) {
+    struct CHandler {
+        user_data: *mut c_void,
+        drop_user_data: extern "C" fn(user_data: *mut c_void),
+        eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+        notify_fn: extern "C" fn(user_data: *mut c_void),
+    }
+    impl Drop for CHandler {
+        fn drop(&mut self) {
+            (self.drop_user_data)(self.user_data)
+        }
+    }
+    impl super::change_tracker::ChangeTrackerHandler for CHandler {
+        fn evaluate(&self) -> bool {
+            (self.eval_fn)(self.user_data)
+        }
+        fn notify(&self) {
+            (self.notify_fn)(self.user_data)
+        }
+    }
+
+    (*(handle as *const ChangeTracker)).init_with_handler(Box::new(CHandler {
+        user_data,
+        drop_user_data,
+        eval_fn,
+        notify_fn,
+    }))
+}
+
+/// Destroy the change tracker
+#[no_mangle]
+pub unsafe extern "C" fn slint_change_tracker_drop(handle: *mut ChangeTrackerOpaque) {
+    core::ptr::drop_in_place(handle as *mut ChangeTracker);
+}
+
 /// return the current animation tick for the `animation-tick` function
 #[no_mangle]
 pub extern "C" fn slint_animation_tick() -> u64 {
@@ -37,11 +37,13 @@ macro_rules! declare_ValueType_2 {
             crate::PathData,
             crate::animations::EasingCurve,
             crate::model::StandardListViewItem,
+            crate::model::TreeViewItem,
             crate::model::TableColumn,
             crate::input::KeyEvent,
             crate::Brush,
             crate::graphics::Point,
             crate::items::PointerEvent,
+            crate::items::TouchEvent,
             crate::lengths::LogicalLength,
             crate::component_factory::ComponentFactory,
             $(crate::items::$Name,)*
@@ -7,8 +7,15 @@
 
 #![warn(missing_docs)]
 
+mod box_shadow;
+mod clip;
 mod draw_functions;
 mod fonts;
+mod gradient;
+mod monochrome;
+#[cfg(feature = "std")]
+mod path;
+mod transform;
 
 use crate::api::Window;
 use crate::graphics::{IntRect, PixelFormat, SharedImageBuffer, SharedPixelBuffer};
@@ -31,7 +38,11 @@
 #[allow(unused)]
 use num_traits::Float;
 
-pub use draw_functions::{PremultipliedRgbaColor, Rgb565Pixel, TargetPixel};
+pub use draw_functions::{
+    Argb8888Pixel, Bgr565Pixel, Bgr8Pixel, Gray8Pixel, PremultipliedRgbaColor, Rgb565Pixel,
+    TargetPixel, Xrgb8888Pixel,
+};
+pub use monochrome::{Dithering, MonochromeFrameBuffer};
 
 use self::fonts::GlyphRenderer;
 
@@ -125,6 +136,17 @@ pub struct SoftwareRenderer {
     /// Only used if repaint_buffer_type == RepaintBufferType::SwappedBuffers
     prev_frame_dirty: Cell<DirtyRegion>,
     maybe_window_adapter: RefCell<Option<Weak<dyn crate::window::WindowAdapter>>>,
+    /// The rendered shadows, shared by the `BoxShadow` items that have the same shadow
+    #[cfg(feature = "std")]
+    box_shadow_cache: crate::graphics::boxshadowcache::BoxShadowCache<box_shadow::ShadowAlphaMap>,
+    /// The rendered shadow of each `BoxShadow` item
+    #[cfg(feature = "std")]
+    box_shadow_item_cache: crate::item_rendering::ItemCache<Option<box_shadow::ShadowAlphaMap>>,
+    /// The buffers in which the rotated or scaled items are rendered, kept to be reused
+    transformed_buffers: RefCell<Vec<Rc<[PremultipliedRgbaColor]>>>,
+    /// The region drawn by the post render callback in the previous frame, which must be drawn
+    /// again to erase it
+    post_render_region: Cell<crate::item_rendering::DirtyRegion>,
 }
 
 impl SoftwareRenderer {
@@ -219,7 +241,8 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
             size,
             factor,
             window_inner,
-            RenderToBuffer { buffer, stride: pixel_stride },
+            RenderToBuffer { buffer, stride: pixel_stride, clip_mask: None, gradient: None },
+            self,
         );
         let mut renderer = crate::item_rendering::PartialRenderer::new(
             &self.partial_cache,
@@ -248,14 +271,16 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 );
 
                 let mut bg = TargetPixel::background();
-                // TODO: gradient background
-                TargetPixel::blend(&mut bg, background.color().into());
+                if let Brush::SolidColor(color) = background {
+                    TargetPixel::blend(&mut bg, color.into());
+                }
                 for line in to_draw.min_y()..to_draw.max_y() {
                     let begin = line as usize * pixel_stride + to_draw.origin.x as usize;
                     renderer.actual_renderer.processor.buffer
                         [begin..begin + to_draw.width() as usize]
                         .fill(bg);
                 }
+                renderer.actual_renderer.draw_background_gradient(&background, size, to_draw);
 
                 for (component, origin) in components {
                     crate::item_rendering::render_component_items(
@@ -270,6 +295,47 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
             .unwrap_or_default()
     }
 
+    /// Same as [`Self::render`], and then `post_render_callback` is called with an item renderer
+    /// to draw on top of the whole window, for example a mouse cursor.
+    #[doc(hidden)]
+    pub fn render_with_post_callback(
+        &self,
+        buffer: &mut [impl TargetPixel],
+        pixel_stride: usize,
+        post_render_callback: &dyn Fn(&mut dyn ItemRenderer),
+    ) -> PhysicalRegion {
+        // Erase what the callback drew in the previous frame
+        self.mark_dirty_region(self.post_render_region.take());
+        let region = self.render(buffer, pixel_stride);
+
+        let Some(window) = self.maybe_window_adapter.borrow().as_ref().and_then(|w| w.upgrade())
+        else {
+            return region;
+        };
+        let window_inner = WindowInner::from_pub(window.window());
+        let factor = ScaleFactor::new(window_inner.scale_factor());
+        let size = euclid::size2(pixel_stride as _, (buffer.len() / pixel_stride) as _);
+        let mut builder = SceneBuilder::new(
+            size,
+            factor,
+            window_inner,
+            TrackDrawnRegion {
+                inner: RenderToBuffer {
+                    buffer,
+                    stride: pixel_stride,
+                    clip_mask: None,
+                    gradient: None,
+                },
+                region: Default::default(),
+            },
+            self,
+        );
+        post_render_callback(&mut builder);
+        self.post_render_region
+            .set((builder.processor.region.cast() / factor).round_out().cast().to_box2d());
+        region
+    }
+
     /// Render the window, line by line, into the line buffer provided by the [`LineBufferProvider`].
     ///
     /// The renderer uses a cache internally and will only render the part of the window
@@ -476,6 +542,8 @@ fn free_graphics_resources(
         for item in items {
             item.cached_rendering_data_offset().release(&mut self.partial_cache.borrow_mut());
         }
+        #[cfg(feature = "std")]
+        self.box_shadow_item_cache.component_destroyed(_component);
         // We don't have a way to determine the screen region of the delete items, what's in the cache is relative. So
         // as a last resort, refresh everything.
         self.force_screen_refresh.set(true);
@@ -523,15 +591,17 @@ fn render_window_frame_by_line(
     renderer: &SoftwareRenderer,
     mut line_buffer: impl LineBufferProvider,
 ) -> PhysicalRegion {
-    let mut scene = prepare_scene(window, size, renderer);
+    let mut scene = prepare_scene(window, &background, size, renderer);
 
     let dirty_region = scene.dirty_region;
 
     debug_assert!(scene.current_line >= dirty_region.origin.y_length());
 
+    // A gradient background is part of the scene
     let mut background_color = TargetPixel::background();
-    // FIXME gradient
-    TargetPixel::blend(&mut background_color, background.color().into());
+    if let Brush::SolidColor(color) = background {
+        TargetPixel::blend(&mut background_color, color.into());
+    }
 
     while scene.current_line < dirty_region.origin.y_length() + dirty_region.size.height_length() {
         line_buffer.process_line(
@@ -546,68 +616,17 @@ fn render_window_frame_by_line(
                     debug_assert!(
                         scene.current_line < span.pos.y_length() + span.size.height_length(),
                     );
-                    match span.command {
-                        SceneCommand::Rectangle { color } => {
-                            TargetPixel::blend_slice(
-                                &mut line_buffer[span.pos.x as usize - offset
-                                    ..(span.pos.x_length() + span.size.width_length()).get()
-                                        as usize
-                                        - offset],
-                                color,
-                            );
-                        }
-                        SceneCommand::Texture { texture_index } => {
-                            let texture = &scene.vectors.textures[texture_index as usize];
-                            draw_functions::draw_texture_line(
-                                &PhysicalRect {
-                                    origin: span.pos - euclid::vec2(offset as i16, 0),
-                                    size: span.size,
-                                },
-                                scene.current_line,
-                                texture,
-                                line_buffer,
-                            );
-                        }
-                        SceneCommand::SharedBuffer { shared_buffer_index } => {
-                            let texture = scene.vectors.shared_buffers
-                                [shared_buffer_index as usize]
-                                .as_texture();
-                            draw_functions::draw_texture_line(
-                                &PhysicalRect {
-                                    origin: span.pos - euclid::vec2(offset as i16, 0),
-                                    size: span.size,
-                                },
-                                scene.current_line,
-                                &texture,
-                                line_buffer,
-                            );
-                        }
-                        SceneCommand::RoundedRectangle { rectangle_index } => {
-                            let rr = &scene.vectors.rounded_rectangles[rectangle_index as usize];
-                            draw_functions::draw_rounded_rectangle_line(
-                                &PhysicalRect {
-                                    origin: span.pos - euclid::vec2(offset as i16, 0),
-                                    size: span.size,
-                                },
-                                scene.current_line,
-                                rr,
-                                line_buffer,
-                            );
-                        }
-                        SceneCommand::Gradient { gradient_index } => {
-                            let g = &scene.vectors.gradients[gradient_index as usize];
-
-                            draw_functions::draw_gradient_line(
-                                &PhysicalRect {
-                                    origin: span.pos - euclid::vec2(offset as i16, 0),
-                                    size: span.size,
-                                },
-                                scene.current_line,
-                                g,
-                                line_buffer,
-                            );
-                        }
-                    }
+                    draw_scene_command_line(
+                        span.command,
+                        &scene.vectors,
+                        &PhysicalRect {
+                            origin: span.pos - euclid::vec2(offset as i16, 0),
+                            size: span.size,
+                        },
+                        scene.current_line,
+                        offset as i16,
+                        line_buffer,
+                    );
                 }
             },
         );
@@ -619,12 +638,104 @@ fn render_window_frame_by_line(
     PhysicalRegion(dirty_region)
 }
 
+/// Draw one line of the `command` of a [`SceneItem`].
+///
+/// `span` is the geometry of the item relative to the `line_buffer`, whose first pixel is at the
+/// window coordinate `buffer_x`.
+fn draw_scene_command_line(
+    command: SceneCommand,
+    vectors: &SceneVectors,
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    buffer_x: i16,
+    line_buffer: &mut [impl TargetPixel],
+) {
+    match command {
+        SceneCommand::Rectangle { color } => {
+            TargetPixel::blend_slice(
+                &mut line_buffer[span.min_x() as usize..span.max_x() as usize],
+                color,
+            );
+        }
+        SceneCommand::Texture { texture_index } => {
+            let texture = &vectors.textures[texture_index as usize];
+            draw_functions::draw_texture_line(span, line, texture, line_buffer);
+        }
+        SceneCommand::SharedBuffer { shared_buffer_index } => {
+            let texture = vectors.shared_buffers[shared_buffer_index as usize].as_texture();
+            draw_functions::draw_texture_line(span, line, &texture, line_buffer);
+        }
+        SceneCommand::RoundedRectangle { rectangle_index } => {
+            let rr = &vectors.rounded_rectangles[rectangle_index as usize];
+            draw_functions::draw_rounded_rectangle_line(span, line, rr, line_buffer);
+        }
+        SceneCommand::Gradient { gradient_index } => {
+            let g = &vectors.gradients[gradient_index as usize];
+            draw_functions::draw_gradient_line(span, line, g, line_buffer);
+        }
+        #[cfg(feature = "std")]
+        SceneCommand::Path { path_index } => {
+            let p = &vectors.paths[path_index as usize];
+            path::draw_path_line(span, line, p, line_buffer);
+        }
+        SceneCommand::Clipped { clipped_index } => {
+            let clipped = &vectors.clipped[clipped_index as usize];
+            clip::draw_clipped_line(
+                span,
+                line,
+                buffer_x,
+                &clipped.clip,
+                line_buffer,
+                |clipped_span, buffer| {
+                    draw_scene_command_line(
+                        clipped.command,
+                        vectors,
+                        clipped_span,
+                        line,
+                        span.origin.x + buffer_x,
+                        buffer,
+                    )
+                },
+            );
+        }
+        SceneCommand::Brushed { brushed_index } => {
+            let brushed = &vectors.brushed[brushed_index as usize];
+            gradient::draw_line_with_gradient(
+                span,
+                line,
+                buffer_x,
+                &brushed.gradient,
+                line_buffer,
+                |brushed_span, buffer| {
+                    draw_scene_command_line(
+                        brushed.command,
+                        vectors,
+                        brushed_span,
+                        line,
+                        span.origin.x + buffer_x,
+                        buffer,
+                    )
+                },
+            );
+        }
+        SceneCommand::Transformed { transformed_index } => {
+            let t = &vectors.transformed[transformed_index as usize];
+            transform::draw_transformed_texture_line(span, line, t, line_buffer);
+        }
+    }
+}
+
 #[derive(Default)]
 struct SceneVectors {
     textures: Vec<SceneTexture<'static>>,
     rounded_rectangles: Vec<RoundedRectangle>,
     shared_buffers: Vec<SharedBufferCommand>,
     gradients: Vec<GradientCommand>,
+    #[cfg(feature = "std")]
+    paths: Vec<path::PathCommand>,
+    clipped: Vec<ClippedCommand>,
+    brushed: Vec<BrushedCommand>,
+    transformed: Vec<transform::TransformedTexture>,
 }
 
 struct Scene {
@@ -821,6 +932,35 @@ enum SceneCommand {
     Gradient {
         gradient_index: u16,
     },
+    /// path_index is an index in the [`SceneVectors::paths`] array
+    #[cfg(feature = "std")]
+    Path {
+        path_index: u16,
+    },
+    /// clipped_index is an index in the [`SceneVectors::clipped`] array
+    Clipped {
+        clipped_index: u16,
+    },
+    /// brushed_index is an index in the [`SceneVectors::brushed`] array
+    Brushed {
+        brushed_index: u16,
+    },
+    /// transformed_index is an index in the [`SceneVectors::transformed`] array
+    Transformed {
+        transformed_index: u16,
+    },
+}
+
+/// A command that must be drawn through the mask of a rounded clip
+struct ClippedCommand {
+    command: SceneCommand,
+    clip: Rc<clip::RoundedClip>,
+}
+
+/// A command that is filled with a gradient instead of its own color
+struct BrushedCommand {
+    command: SceneCommand,
+    gradient: Rc<gradient::GradientBrush>,
 }
 
 struct SceneTexture<'a> {
@@ -941,11 +1081,13 @@ struct GradientCommand {
 
 fn prepare_scene(
     window: &WindowInner,
+    background: &Brush,
     size: PhysicalSize,
     software_renderer: &SoftwareRenderer,
 ) -> Scene {
     let factor = ScaleFactor::new(window.scale_factor());
-    let prepare_scene = SceneBuilder::new(size, factor, window, PrepareScene::default());
+    let prepare_scene =
+        SceneBuilder::new(size, factor, window, PrepareScene::default(), software_renderer);
     let mut renderer = crate::item_rendering::PartialRenderer::new(
         &software_renderer.partial_cache,
         software_renderer.force_dirty.take(),
@@ -966,6 +1108,7 @@ fn prepare_scene(
             LogicalLength::zero(),
             LogicalLength::zero(),
         );
+        renderer.actual_renderer.draw_background_gradient(background, size, dirty_region);
         for (component, origin) in components {
             crate::item_rendering::render_component_items(component, &mut renderer, *origin);
         }
@@ -981,67 +1124,236 @@ trait ProcessScene {
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle);
     fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand);
     fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand);
+    #[cfg(feature = "std")]
+    fn process_path(&mut self, geometry: PhysicalRect, path: path::PathCommand);
+    fn process_transformed_texture(
+        &mut self,
+        geometry: PhysicalRect,
+        texture: transform::TransformedTexture,
+    );
+    /// Set the rounded clip that applies to the next commands
+    fn set_clip_mask(&mut self, clip: Option<Rc<clip::RoundedClip>>);
+    /// Set the gradient that replaces the color of the next commands
+    fn set_gradient(&mut self, gradient: Option<Rc<gradient::GradientBrush>>);
+}
+
+/// Forwards the commands to the `inner` processor, and records the region they cover
+struct TrackDrawnRegion<T> {
+    inner: T,
+    region: PhysicalRect,
+}
+
+impl<T: ProcessScene> ProcessScene for TrackDrawnRegion<T> {
+    fn process_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'static>) {
+        self.region = self.region.union(&geometry);
+        self.inner.process_texture(geometry, texture)
+    }
+
+    fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgbaColor) {
+        self.region = self.region.union(&geometry);
+        self.inner.process_rectangle(geometry, color)
+    }
+
+    fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRectangle) {
+        self.region = self.region.union(&geometry);
+        self.inner.process_rounded_rectangle(geometry, data)
+    }
+
+    fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand) {
+        self.region = self.region.union(&geometry);
+        self.inner.process_shared_image_buffer(geometry, buffer)
+    }
+
+    fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand) {
+        self.region = self.region.union(&geometry);
+        self.inner.process_gradient(geometry, gradient)
+    }
+
+    #[cfg(feature = "std")]
+    fn process_path(&mut self, geometry: PhysicalRect, path: path::PathCommand) {
+        self.region = self.region.union(&geometry);
+        self.inner.process_path(geometry, path)
+    }
+
+    fn process_transformed_texture(
+        &mut self,
+        geometry: PhysicalRect,
+        texture: transform::TransformedTexture,
+    ) {
+        self.region = self.region.union(&geometry);
+        self.inner.process_transformed_texture(geometry, texture)
+    }
+
+    fn set_clip_mask(&mut self, clip: Option<Rc<clip::RoundedClip>>) {
+        self.inner.set_clip_mask(clip)
+    }
+
+    fn set_gradient(&mut self, gradient: Option<Rc<gradient::GradientBrush>>) {
+        self.inner.set_gradient(gradient)
+    }
+}
+
+/// A command that the [`RenderToBuffer`] can draw line by line
+trait DrawLine {
+    fn draw_line(&self, span: &PhysicalRect, line: PhysicalLength, buffer: &mut [impl TargetPixel]);
+}
+
+impl DrawLine for SceneTexture<'_> {
+    fn draw_line(
+        &self,
+        span: &PhysicalRect,
+        line: PhysicalLength,
+        buffer: &mut [impl TargetPixel],
+    ) {
+        draw_functions::draw_texture_line(span, line, self, buffer)
+    }
+}
+
+impl DrawLine for PremultipliedRgbaColor {
+    fn draw_line(&self, span: &PhysicalRect, _: PhysicalLength, buffer: &mut [impl TargetPixel]) {
+        TargetPixel::blend_slice(&mut buffer[span.min_x() as usize..span.max_x() as usize], *self)
+    }
+}
+
+impl DrawLine for RoundedRectangle {
+    fn draw_line(
+        &self,
+        span: &PhysicalRect,
+        line: PhysicalLength,
+        buffer: &mut [impl TargetPixel],
+    ) {
+        draw_functions::draw_rounded_rectangle_line(span, line, self, buffer)
+    }
+}
+
+impl DrawLine for GradientCommand {
+    fn draw_line(
+        &self,
+        span: &PhysicalRect,
+        line: PhysicalLength,
+        buffer: &mut [impl TargetPixel],
+    ) {
+        draw_functions::draw_gradient_line(span, line, self, buffer)
+    }
+}
+
+impl DrawLine for transform::TransformedTexture {
+    fn draw_line(
+        &self,
+        span: &PhysicalRect,
+        line: PhysicalLength,
+        buffer: &mut [impl TargetPixel],
+    ) {
+        transform::draw_transformed_texture_line(span, line, self, buffer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl DrawLine for path::PathCommand {
+    fn draw_line(
+        &self,
+        span: &PhysicalRect,
+        line: PhysicalLength,
+        buffer: &mut [impl TargetPixel],
+    ) {
+        path::draw_path_line(span, line, self, buffer)
+    }
 }
 
 struct RenderToBuffer<'a, TargetPixel> {
     buffer: &'a mut [TargetPixel],
     stride: usize,
+    clip_mask: Option<Rc<clip::RoundedClip>>,
+    gradient: Option<Rc<gradient::GradientBrush>>,
 }
 
-impl<'a, T: TargetPixel> ProcessScene for RenderToBuffer<'a, T> {
-    fn process_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'static>) {
+impl<'a, T: TargetPixel> RenderToBuffer<'a, T> {
+    fn draw_lines(&mut self, geometry: PhysicalRect, command: &impl DrawLine) {
+        let clip_mask = self.clip_mask.as_deref().filter(|c| c.intersects_corners(&geometry));
+        let gradient = self.gradient.as_deref();
         for line in geometry.min_y()..geometry.max_y() {
-            draw_functions::draw_texture_line(
-                &geometry,
-                PhysicalLength::new(line),
-                &texture,
-                &mut self.buffer[line as usize * self.stride..],
-            );
+            let line_buffer = &mut self.buffer[line as usize * self.stride..];
+            let line = PhysicalLength::new(line);
+            match (clip_mask, gradient) {
+                (None, None) => command.draw_line(&geometry, line, line_buffer),
+                (None, Some(gradient)) => gradient::draw_line_with_gradient(
+                    &geometry,
+                    line,
+                    0,
+                    gradient,
+                    line_buffer,
+                    |span, buffer| command.draw_line(span, line, buffer),
+                ),
+                (Some(clip_mask), None) => clip::draw_clipped_line(
+                    &geometry,
+                    line,
+                    0,
+                    clip_mask,
+                    line_buffer,
+                    |span, buffer| command.draw_line(span, line, buffer),
+                ),
+                (Some(clip_mask), Some(gradient)) => clip::draw_clipped_line(
+                    &geometry,
+                    line,
+                    0,
+                    clip_mask,
+                    line_buffer,
+                    |span, buffer| {
+                        gradient::draw_line_with_gradient(
+                            span,
+                            line,
+                            geometry.origin.x,
+                            gradient,
+                            buffer,
+                            |span, buffer| command.draw_line(span, line, buffer),
+                        )
+                    },
+                ),
+            }
         }
     }
+}
+
+impl<'a, T: TargetPixel> ProcessScene for RenderToBuffer<'a, T> {
+    fn process_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'static>) {
+        self.draw_lines(geometry, &texture);
+    }
 
     fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: SharedBufferCommand) {
-        let texture = buffer.as_texture();
-        for line in geometry.min_y()..geometry.max_y() {
-            draw_functions::draw_texture_line(
-                &geometry,
-                PhysicalLength::new(line),
-                &texture,
-                &mut self.buffer[line as usize * self.stride..],
-            );
-        }
+        self.draw_lines(geometry, &buffer.as_texture());
     }
 
     fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgbaColor) {
-        for line in geometry.min_y()..geometry.max_y() {
-            let begin = line as usize * self.stride + geometry.origin.x as usize;
-            TargetPixel::blend_slice(
-                &mut self.buffer[begin..begin + geometry.width() as usize],
-                color,
-            );
-        }
+        self.draw_lines(geometry, &color);
     }
 
     fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, rr: RoundedRectangle) {
-        for line in geometry.min_y()..geometry.max_y() {
-            draw_functions::draw_rounded_rectangle_line(
-                &geometry,
-                PhysicalLength::new(line),
-                &rr,
-                &mut self.buffer[line as usize * self.stride..],
-            );
-        }
+        self.draw_lines(geometry, &rr);
     }
 
     fn process_gradient(&mut self, geometry: PhysicalRect, g: GradientCommand) {
-        for line in geometry.min_y()..geometry.max_y() {
-            draw_functions::draw_gradient_line(
-                &geometry,
-                PhysicalLength::new(line),
-                &g,
-                &mut self.buffer[line as usize * self.stride..],
-            );
-        }
+        self.draw_lines(geometry, &g);
+    }
+
+    #[cfg(feature = "std")]
+    fn process_path(&mut self, geometry: PhysicalRect, p: path::PathCommand) {
+        self.draw_lines(geometry, &p);
+    }
+
+    fn process_transformed_texture(
+        &mut self,
+        geometry: PhysicalRect,
+        texture: transform::TransformedTexture,
+    ) {
+        self.draw_lines(geometry, &texture);
+    }
+
+    fn set_clip_mask(&mut self, clip: Option<Rc<clip::RoundedClip>>) {
+        self.clip_mask = clip;
+    }
+
+    fn set_gradient(&mut self, gradient: Option<Rc<gradient::GradientBrush>>) {
+        self.gradient = gradient;
     }
 }
 
@@ -1049,6 +1361,29 @@ fn process_gradient(&mut self, geometry: PhysicalRect, g: GradientCommand) {
 struct PrepareScene {
     items: Vec<SceneItem>,
     vectors: SceneVectors,
+    clip_mask: Option<Rc<clip::RoundedClip>>,
+    gradient: Option<Rc<gradient::GradientBrush>>,
+}
+
+impl PrepareScene {
+    fn push_item(&mut self, geometry: PhysicalRect, mut command: SceneCommand) {
+        if let Some(gradient) = &self.gradient {
+            let brushed_index = self.vectors.brushed.len() as u16;
+            self.vectors.brushed.push(BrushedCommand { command, gradient: gradient.clone() });
+            command = SceneCommand::Brushed { brushed_index };
+        }
+        if let Some(clip) = self.clip_mask.as_ref().filter(|c| c.intersects_corners(&geometry)) {
+            let clipped_index = self.vectors.clipped.len() as u16;
+            self.vectors.clipped.push(ClippedCommand { command, clip: clip.clone() });
+            command = SceneCommand::Clipped { clipped_index };
+        }
+        self.items.push(SceneItem {
+            pos: geometry.origin,
+            size: geometry.size,
+            z: self.items.len() as u16,
+            command,
+        });
+    }
 }
 
 impl ProcessScene for PrepareScene {
@@ -1057,12 +1392,7 @@ fn process_texture(&mut self, geometry: PhysicalRect, texture: SceneTexture<'sta
         if !size.is_empty() {
             let texture_index = self.vectors.textures.len() as u16;
             self.vectors.textures.push(texture);
-            self.items.push(SceneItem {
-                pos: geometry.origin,
-                size,
-                z: self.items.len() as u16,
-                command: SceneCommand::Texture { texture_index },
-            });
+            self.push_item(geometry, SceneCommand::Texture { texture_index });
         }
     }
 
@@ -1071,21 +1401,14 @@ fn process_shared_image_buffer(&mut self, geometry: PhysicalRect, buffer: Shared
         if !size.is_empty() {
             let shared_buffer_index = self.vectors.shared_buffers.len() as u16;
             self.vectors.shared_buffers.push(buffer);
-            self.items.push(SceneItem {
-                pos: geometry.origin,
-                size,
-                z: self.items.len() as u16,
-                command: SceneCommand::SharedBuffer { shared_buffer_index },
-            });
+            self.push_item(geometry, SceneCommand::SharedBuffer { shared_buffer_index });
         }
     }
 
     fn process_rectangle(&mut self, geometry: PhysicalRect, color: PremultipliedRgbaColor) {
         let size = geometry.size;
         if !size.is_empty() {
-            let z = self.items.len() as u16;
-            let pos = geometry.origin;
-            self.items.push(SceneItem { pos, size, z, command: SceneCommand::Rectangle { color } });
+            self.push_item(geometry, SceneCommand::Rectangle { color });
         }
     }
 
@@ -1094,12 +1417,7 @@ fn process_rounded_rectangle(&mut self, geometry: PhysicalRect, data: RoundedRec
         if !size.is_empty() {
             let rectangle_index = self.vectors.rounded_rectangles.len() as u16;
             self.vectors.rounded_rectangles.push(data);
-            self.items.push(SceneItem {
-                pos: geometry.origin,
-                size,
-                z: self.items.len() as u16,
-                command: SceneCommand::RoundedRectangle { rectangle_index },
-            });
+            self.push_item(geometry, SceneCommand::RoundedRectangle { rectangle_index });
         }
     }
 
@@ -1108,14 +1426,40 @@ fn process_gradient(&mut self, geometry: PhysicalRect, gradient: GradientCommand
         if !size.is_empty() {
             let gradient_index = self.vectors.gradients.len() as u16;
             self.vectors.gradients.push(gradient);
-            self.items.push(SceneItem {
-                pos: geometry.origin,
-                size,
-                z: self.items.len() as u16,
-                command: SceneCommand::Gradient { gradient_index },
-            });
+            self.push_item(geometry, SceneCommand::Gradient { gradient_index });
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn process_path(&mut self, geometry: PhysicalRect, path: path::PathCommand) {
+        let size = geometry.size;
+        if !size.is_empty() {
+            let path_index = self.vectors.paths.len() as u16;
+            self.vectors.paths.push(path);
+            self.push_item(geometry, SceneCommand::Path { path_index });
+        }
+    }
+
+    fn process_transformed_texture(
+        &mut self,
+        geometry: PhysicalRect,
+        texture: transform::TransformedTexture,
+    ) {
+        let size = geometry.size;
+        if !size.is_empty() {
+            let transformed_index = self.vectors.transformed.len() as u16;
+            self.vectors.transformed.push(texture);
+            self.push_item(geometry, SceneCommand::Transformed { transformed_index });
         }
     }
+
+    fn set_clip_mask(&mut self, clip: Option<Rc<clip::RoundedClip>>) {
+        self.clip_mask = clip;
+    }
+
+    fn set_gradient(&mut self, gradient: Option<Rc<gradient::GradientBrush>>) {
+        self.gradient = gradient;
+    }
 }
 
 struct SceneBuilder<'a, T> {
@@ -1124,6 +1468,15 @@ struct SceneBuilder<'a, T> {
     current_state: RenderState,
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
+    #[cfg(feature = "std")]
+    box_shadow_cache:
+        &'a crate::graphics::boxshadowcache::BoxShadowCache<box_shadow::ShadowAlphaMap>,
+    /// The shadows cached per item, or None if they can't be cached because they are not
+    /// rendered at the scale factor of the window
+    #[cfg(feature = "std")]
+    box_shadow_item_cache:
+        Option<&'a crate::item_rendering::ItemCache<Option<box_shadow::ShadowAlphaMap>>>,
+    transformed_buffers: &'a RefCell<Vec<Rc<[PremultipliedRgbaColor]>>>,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1132,7 +1485,18 @@ fn new(
         scale_factor: ScaleFactor,
         window: &'a WindowInner,
         processor: T,
+        software_renderer: &'a SoftwareRenderer,
     ) -> Self {
+        #[cfg(feature = "std")]
+        {
+            software_renderer
+                .box_shadow_item_cache
+                .clear_cache_if_scale_factor_changed(window.window_adapter().window());
+            // Forget the shadows that are not used by any item anymore
+            software_renderer
+                .box_shadow_cache
+                .retain(|_, shadow| Rc::strong_count(&shadow.data) > 1);
+        }
         Self {
             processor,
             state_stack: vec![],
@@ -1143,9 +1507,16 @@ fn new(
                     LogicalPoint::default(),
                     (size.cast() / scale_factor).cast(),
                 ),
+                clip_mask: None,
+                transform: None,
             },
             scale_factor,
             window,
+            #[cfg(feature = "std")]
+            box_shadow_cache: &software_renderer.box_shadow_cache,
+            #[cfg(feature = "std")]
+            box_shadow_item_cache: Some(&software_renderer.box_shadow_item_cache),
+            transformed_buffers: &software_renderer.transformed_buffers,
         }
     }
 
@@ -1312,6 +1683,7 @@ fn draw_text_paragraph<Font: AbstractFont>(
         physical_clip: euclid::Rect<f32, PhysicalPx>,
         offset: euclid::Vector2D<f32, PhysicalPx>,
         color: Color,
+        gradient: Option<Rc<gradient::GradientBrush>>,
         selection: Option<SelectionInfo>,
     ) where
         Font: crate::textlayout::TextShaper<Length = PhysicalLength>,
@@ -1347,11 +1719,11 @@ fn draw_text_paragraph<Font: AbstractFont>(
                         )
                         .cast();
 
-                        let color = match &selection {
+                        let (color, gradient) = match &selection {
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
-                                s.selection_color
+                                (s.selection_color, None)
                             }
-                            _ => color,
+                            _ => (color, gradient.as_ref()),
                         };
 
                         if let Some(clipped_src) = src_rect.intersection(&physical_clip) {
@@ -1365,6 +1737,9 @@ fn draw_text_paragraph<Font: AbstractFont>(
                             let stride = glyph.width.get() as u16;
                             let geometry = geometry.cast();
 
+                            if gradient.is_some() {
+                                self.processor.set_gradient(gradient.cloned());
+                            }
                             match &glyph.alpha_map {
                                 fonts::GlyphAlphaMap::Static(data) => {
                                     self.processor.process_texture(
@@ -1399,6 +1774,9 @@ fn draw_text_paragraph<Font: AbstractFont>(
                                     );
                                 }
                             };
+                            if gradient.is_some() {
+                                self.processor.set_gradient(None);
+                            }
                         }
                     }
                     core::ops::ControlFlow::Continue(())
@@ -1421,6 +1799,289 @@ fn alpha_color(&self, color: Color) -> Color {
             color
         }
     }
+
+    /// Draw a `BorderRectangle` whose background or border is a gradient.
+    ///
+    /// Unlike the plain color case, the border cannot be merged with the background, so the
+    /// background is drawn inside the border, and then the border around it.
+    #[allow(clippy::unnecessary_cast)] // Coord
+    fn draw_border_rectangle_with_gradients(
+        &mut self,
+        geom: LogicalRect,
+        background: Brush,
+        border_brush: Brush,
+        border: LogicalLength,
+        radius: LogicalLength,
+    ) {
+        let (color, gradient) = self.color_and_gradient(&background, geom);
+        let (border_color, border_gradient) = if border.get() as f32 > 0.01 {
+            self.color_and_gradient(&border_brush, geom)
+        } else {
+            (Color::default(), None)
+        };
+        let transparent = PremultipliedRgbaColor::default();
+
+        if radius.get() > 0 as _ {
+            let radius =
+                radius.min(geom.width_length() / 2 as Coord).min(geom.height_length() / 2 as Coord);
+            let Some(clipped) = geom.intersection(&self.current_state.clip) else { return };
+            let geom2 = geom.cast() * self.scale_factor;
+            let clipped2 = clipped.cast() * self.scale_factor;
+            let rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast();
+            // Add a small value to make sure that the clip is always positive despite floating point shenanigans
+            const E: f32 = 0.00001;
+            let scale_factor = self.scale_factor;
+            let rounded_rectangle =
+                |width: LogicalLength, border_color, inner_color| RoundedRectangle {
+                    radius: (radius.cast() * scale_factor).cast(),
+                    width: (width.cast() * scale_factor).cast(),
+                    border_color,
+                    inner_color,
+                    top_clip: PhysicalLength::new((clipped2.min_y() - geom2.min_y() + E) as _),
+                    bottom_clip: PhysicalLength::new((geom2.max_y() - clipped2.max_y() + E) as _),
+                    left_clip: PhysicalLength::new((clipped2.min_x() - geom2.min_x() + E) as _),
+                    right_clip: PhysicalLength::new((geom2.max_x() - clipped2.max_x() + E) as _),
+                };
+            if color.alpha() > 0 {
+                // The transparent border keeps the background from showing through the border
+                let rr = rounded_rectangle(border, transparent, color.into());
+                self.with_gradient(gradient, |this| {
+                    this.processor.process_rounded_rectangle(rect, rr)
+                });
+            }
+            if border_color.alpha() > 0 {
+                let rr = rounded_rectangle(border, border_color.into(), transparent);
+                self.with_gradient(border_gradient, |this| {
+                    this.processor.process_rounded_rectangle(rect, rr)
+                });
+            }
+            return;
+        }
+
+        let to_physical = |this: &Self, r: LogicalRect| -> Option<PhysicalRect> {
+            r.intersection(&this.current_state.clip).map(|r| {
+                (r.translate(this.current_state.offset.to_vector()).cast() * this.scale_factor)
+                    .round()
+                    .cast()
+            })
+        };
+        if color.alpha() > 0 {
+            if let Some(r) = to_physical(self, geom.inflate(-border.get(), -border.get())) {
+                self.with_gradient(gradient, |this| {
+                    this.processor.process_rectangle(r, color.into())
+                });
+            }
+        }
+        if border_color.alpha() > 0 {
+            let b = border.get();
+            let borders = [
+                euclid::rect(0 as _, 0 as _, geom.width(), b),
+                euclid::rect(0 as _, geom.height() - b, geom.width(), b),
+                euclid::rect(0 as _, b, b, geom.height() - b - b),
+                euclid::rect(geom.width() - b, b, b, geom.height() - b - b),
+            ];
+            self.with_gradient(border_gradient, |this| {
+                for r in borders {
+                    if let Some(r) = to_physical(this, r) {
+                        this.processor.process_rectangle(r, border_color.into());
+                    }
+                }
+            });
+        }
+    }
+
+    /// Apply the `local_transform` (a rotation or a scale around the current origin) to the
+    /// items drawn next, in addition to the current transformation.
+    fn apply_transform(&mut self, local_transform: euclid::default::Transform2D<f32>) {
+        let pivot = self.current_state.offset.cast::<f32>().to_untyped().to_vector();
+        let local_transform = euclid::default::Transform2D::translation(-pivot.x, -pivot.y)
+            .then(&local_transform)
+            .then_translate(pivot);
+        let (transform, clip) = match self.current_state.transform {
+            Some(t) => (local_transform.then(&t.transform), t.clip),
+            None => (
+                local_transform,
+                (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                    * self.scale_factor)
+                    .round_out()
+                    .cast(),
+            ),
+        };
+        self.current_state.transform = Some(ItemTransform { transform, clip });
+        // The items are clipped in window coordinates when drawn. In item coordinates, the clip
+        // is the bounding box of the window clip mapped back in the item coordinates.
+        if let Some(inverse) = transform.inverse() {
+            let window_clip = (clip.cast::<f32>() / self.scale_factor).to_untyped();
+            self.current_state.clip = LogicalRect::from_untyped(
+                &inverse
+                    .outer_transformed_rect(&window_clip)
+                    .translate(-self.current_state.offset.cast::<f32>().to_untyped().to_vector())
+                    .cast(),
+            );
+        } else {
+            self.current_state.clip = LogicalRect::default();
+        }
+    }
+
+    /// Draw an item that is transformed.
+    ///
+    /// `draw` renders the item without the transformation in a temporary buffer that covers the
+    /// `rect` (in item coordinates), and this buffer is then drawn with the transformation.
+    fn draw_transformed(
+        &mut self,
+        transform: ItemTransform,
+        rect: LogicalRect,
+        draw: impl FnOnce(&mut SceneBuilder<'_, RenderToBuffer<'_, PremultipliedRgbaColor>>),
+    ) {
+        if !self.should_draw(&rect) {
+            return;
+        }
+        let scale_factor = self.scale_factor.get();
+        // The item is rendered at the size it has once transformed, so that it stays sharp when
+        // scaled up and doesn't alias when scaled down.
+        let t = &transform.transform;
+        let buffer_scale = scale_factor * t.m11.hypot(t.m12).max(t.m21.hypot(t.m22));
+        if !buffer_scale.is_normal() {
+            return;
+        }
+        // The pixels of the buffer are aligned on the item, regardless of the clip, so that the
+        // interpolation of the pixels doesn't depend on the part of the window being drawn.
+        let physical_rect = rect.cast::<f32>().to_untyped().scale(buffer_scale, buffer_scale);
+        let origin = physical_rect.origin.floor();
+        let item_rect =
+            euclid::default::Rect::new(origin, (physical_rect.max() - origin).ceil().to_size());
+
+        // Maps the pixels of the item, at the buffer scale, to the physical window coordinates
+        let offset = self.current_state.offset.cast::<f32>().to_untyped().to_vector();
+        let to_window = euclid::default::Transform2D::scale(1. / buffer_scale, 1. / buffer_scale)
+            .then_translate(offset)
+            .then(&transform.transform)
+            .then_scale(scale_factor, scale_factor);
+        let Some(from_window) = to_window.inverse() else { return };
+        let geometry = PhysicalRect::from_untyped(
+            &to_window.outer_transformed_rect(&item_rect).round_out().cast(),
+        );
+        let Some(geometry) = geometry.intersection(&transform.clip) else { return };
+        let (width, height) = (item_rect.size.width as usize, item_rect.size.height as usize);
+        if width == 0 || height == 0 || width > i16::MAX as usize || height > i16::MAX as usize {
+            return;
+        }
+
+        // The whole item is rendered, regardless of the clip, so that the interpolation of the
+        // pixels doesn't depend on the part of the window being drawn. The buffers are reused
+        // once the previous textures using them have been drawn.
+        let mut transformed_buffers = self.transformed_buffers.borrow_mut();
+        let mut data = match transformed_buffers
+            .iter()
+            .position(|b| Rc::strong_count(b) == 1 && b.len() >= width * height)
+        {
+            Some(index) => transformed_buffers.swap_remove(index),
+            None => vec![PremultipliedRgbaColor::default(); width * height].into(),
+        };
+        drop(transformed_buffers);
+        let buffer = &mut Rc::get_mut(&mut data).unwrap()[..width * height];
+        buffer.fill(PremultipliedRgbaColor::default());
+        let mut builder = SceneBuilder {
+            processor: RenderToBuffer { buffer, stride: width, clip_mask: None, gradient: None },
+            state_stack: vec![],
+            current_state: RenderState {
+                alpha: self.current_state.alpha,
+                offset: LogicalPoint::from_untyped((origin / -buffer_scale).cast()),
+                clip: rect,
+                clip_mask: None,
+                transform: None,
+            },
+            scale_factor: ScaleFactor::new(buffer_scale),
+            window: self.window,
+            #[cfg(feature = "std")]
+            box_shadow_cache: self.box_shadow_cache,
+            // The cached shadows are rendered at the scale factor of the window
+            #[cfg(feature = "std")]
+            box_shadow_item_cache: self
+                .box_shadow_item_cache
+                .filter(|_| buffer_scale == scale_factor),
+            transformed_buffers: self.transformed_buffers,
+        };
+        draw(&mut builder);
+        self.transformed_buffers.borrow_mut().push(data.clone());
+
+        self.processor.process_transformed_texture(
+            geometry,
+            transform::TransformedTexture {
+                data,
+                width: width as u16,
+                height: height as u16,
+                transform: euclid::default::Transform2D::translation(
+                    geometry.origin.x as f32,
+                    geometry.origin.y as f32,
+                )
+                .then(&from_window)
+                .then_translate(-origin.to_vector()),
+            },
+        );
+    }
+
+    /// Fill the `region` with the window `background`, if it is a gradient.
+    /// (A plain color background is filled by the caller directly in the buffer.)
+    fn draw_background_gradient(
+        &mut self,
+        background: &Brush,
+        size: PhysicalSize,
+        region: PhysicalRect,
+    ) {
+        let Some(gradient) =
+            gradient::GradientBrush::new(background, euclid::Rect::from_size(size.cast()), 1.)
+        else {
+            return;
+        };
+        if region.is_empty() || gradient.is_transparent() {
+            return;
+        }
+        self.with_gradient(Some(Rc::new(gradient)), |this| {
+            this.processor.process_rectangle(region, Color::from_rgb_u8(255, 255, 255).into())
+        });
+    }
+
+    /// Returns the gradient to fill the `rect` (in item coordinates) with the `brush`,
+    /// or None if the brush is a plain color.
+    fn gradient(&self, brush: &Brush, rect: LogicalRect) -> Option<Rc<gradient::GradientBrush>> {
+        let rect = rect.translate(self.current_state.offset.to_vector()).cast() * self.scale_factor;
+        gradient::GradientBrush::new(brush, rect, self.current_state.alpha).map(Rc::new)
+    }
+
+    /// Returns the color to draw with and the gradient to fill it with, if the brush is a gradient.
+    ///
+    /// The color is opaque when the brush is a gradient, so that the gradient fully replaces it.
+    fn color_and_gradient(
+        &self,
+        brush: &Brush,
+        rect: LogicalRect,
+    ) -> (Color, Option<Rc<gradient::GradientBrush>>) {
+        match self.gradient(brush, rect) {
+            Some(g) if g.is_transparent() => (Color::default(), None),
+            Some(g) => (Color::from_rgb_u8(255, 255, 255), Some(g)),
+            None => (self.alpha_color(brush.color()), None),
+        }
+    }
+
+    /// Process the commands emitted by `f` with the `gradient` replacing their color.
+    fn with_gradient(
+        &mut self,
+        gradient: Option<Rc<gradient::GradientBrush>>,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let has_gradient = gradient.is_some();
+        if has_gradient {
+            self.processor.set_gradient(gradient);
+        }
+        f(self);
+        if has_gradient {
+            self.processor.set_gradient(None);
+        }
+    }
 }
 
 struct SelectionInfo {
@@ -1429,11 +2090,25 @@ struct SelectionInfo {
     selection: core::ops::Range<usize>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct RenderState {
     alpha: f32,
     offset: LogicalPoint,
     clip: LogicalRect,
+    /// The rounded clip, in physical window coordinates, if any of the clips has rounded corners
+    clip_mask: Option<Rc<clip::RoundedClip>>,
+    /// The transformation applied to the items, if they are rotated or scaled
+    transform: Option<ItemTransform>,
+}
+
+/// A transformation (a rotation and a scale) applied to the items, in addition to the offset
+#[derive(Clone, Copy)]
+struct ItemTransform {
+    /// Maps the position in logical window coordinates that an item would have without the
+    /// transformation to its actual position
+    transform: euclid::default::Transform2D<f32>,
+    /// The clip, in physical window coordinates, when the transformation was applied
+    clip: PhysicalRect,
 }
 
 impl<'a, T: ProcessScene> crate::item_rendering::ItemRenderer for SceneBuilder<'a, T> {
@@ -1441,10 +2116,14 @@ impl<'a, T: ProcessScene> crate::item_rendering::ItemRenderer for SceneBuilder<'
     fn draw_rectangle(
         &mut self,
         rect: Pin<&crate::items::Rectangle>,
-        _: &ItemRc,
+        item_rc: &ItemRc,
         size: LogicalSize,
     ) {
         let geom = LogicalRect::from(size);
+        if let Some(transform) = self.current_state.transform {
+            return self
+                .draw_transformed(transform, geom, |b| b.draw_rectangle(rect, item_rc, size));
+        }
         if self.should_draw(&geom) {
             let clipped = match geom.intersection(&self.current_state.clip) {
                 Some(geom) => geom,
@@ -1548,19 +2227,18 @@ fn draw_rectangle(
                 return;
             }
 
-            // FIXME: gradients
-            let color = self.alpha_color(background.color());
+            let (color, gradient) = self.color_and_gradient(&background, geom);
 
             if color.alpha() == 0 {
                 return;
             }
-            self.processor.process_rectangle(
-                (clipped.translate(self.current_state.offset.to_vector()).cast()
-                    * self.scale_factor)
-                    .round()
-                    .cast(),
-                color.into(),
-            );
+            let rect = (clipped.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast();
+            self.with_gradient(gradient, |this| {
+                this.processor.process_rectangle(rect, color.into())
+            });
         }
     }
 
@@ -1568,14 +2246,32 @@ fn draw_rectangle(
     fn draw_border_rectangle(
         &mut self,
         rect: Pin<&crate::items::BorderRectangle>,
-        _: &ItemRc,
+        item_rc: &ItemRc,
         size: LogicalSize,
     ) {
         let geom = LogicalRect::from(size);
+        if let Some(transform) = self.current_state.transform {
+            return self.draw_transformed(transform, geom, |b| {
+                b.draw_border_rectangle(rect, item_rc, size)
+            });
+        }
         if self.should_draw(&geom) {
             let mut border = rect.border_width();
             let radius = rect.border_radius();
-            // FIXME: gradients
+            let background = rect.background();
+            let border_brush = rect.border_color();
+            if !matches!(background, Brush::SolidColor(_))
+                || (!matches!(border_brush, Brush::SolidColor(_)) && border.get() as f32 > 0.01)
+            {
+                self.draw_border_rectangle_with_gradients(
+                    geom,
+                    background,
+                    border_brush,
+                    border,
+                    radius,
+                );
+                return;
+            }
             let color = self.alpha_color(rect.background().color());
             let border_color = if border.get() as f32 > 0.01 {
                 self.alpha_color(rect.border_color().color())
@@ -1659,7 +2355,6 @@ fn draw_border_rectangle(
                 }
             }
 
-            // FIXME: gradients
             if border_color.alpha > 0 {
                 let mut add_border = |r: LogicalRect| {
                     if let Some(r) = r.intersection(&self.current_state.clip) {
@@ -1681,8 +2376,16 @@ fn draw_border_rectangle(
         }
     }
 
-    fn draw_image(&mut self, image: Pin<&crate::items::ImageItem>, _: &ItemRc, size: LogicalSize) {
+    fn draw_image(
+        &mut self,
+        image: Pin<&crate::items::ImageItem>,
+        item_rc: &ItemRc,
+        size: LogicalSize,
+    ) {
         let geom = LogicalRect::from(size);
+        if let Some(transform) = self.current_state.transform {
+            return self.draw_transformed(transform, geom, |b| b.draw_image(image, item_rc, size));
+        }
         if self.should_draw(&geom) {
             let source = image.source();
             self.draw_image_impl(
@@ -1698,10 +2401,14 @@ fn draw_image(&mut self, image: Pin<&crate::items::ImageItem>, _: &ItemRc, size:
     fn draw_clipped_image(
         &mut self,
         image: Pin<&crate::items::ClippedImage>,
-        _: &ItemRc,
+        item_rc: &ItemRc,
         size: LogicalSize,
     ) {
         let geom = LogicalRect::from(size);
+        if let Some(transform) = self.current_state.transform {
+            return self
+                .draw_transformed(transform, geom, |b| b.draw_clipped_image(image, item_rc, size));
+        }
         if self.should_draw(&geom) {
             let source = image.source();
 
@@ -1728,19 +2435,22 @@ fn draw_clipped_image(
         }
     }
 
-    fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: LogicalSize) {
+    fn draw_text(&mut self, text: Pin<&crate::items::Text>, item_rc: &ItemRc, size: LogicalSize) {
         let string = text.text();
         if string.trim().is_empty() {
             return;
         }
         let geom = LogicalRect::from(size);
+        if let Some(transform) = self.current_state.transform {
+            return self.draw_transformed(transform, geom, |b| b.draw_text(text, item_rc, size));
+        }
         if !self.should_draw(&geom) {
             return;
         }
 
         let font_request = text.font_request(self.window);
 
-        let color = self.alpha_color(text.color().color());
+        let (color, gradient) = self.color_and_gradient(&text.color(), geom);
         let max_size = (geom.size.cast() * self.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
@@ -1772,7 +2482,14 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.clone(),
+                    None,
+                );
             }
             #[cfg(all(feature = "software-renderer-systemfonts", not(target_arch = "wasm32")))]
             fonts::Font::VectorFont(vf) => {
@@ -1790,7 +2507,14 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
                     single_line: false,
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, None);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.clone(),
+                    None,
+                );
             }
         }
     }
@@ -1798,17 +2522,22 @@ fn draw_text(&mut self, text: Pin<&crate::items::Text>, _: &ItemRc, size: Logica
     fn draw_text_input(
         &mut self,
         text_input: Pin<&crate::items::TextInput>,
-        _: &ItemRc,
+        item_rc: &ItemRc,
         size: LogicalSize,
     ) {
         let geom = LogicalRect::from(size);
+        if let Some(transform) = self.current_state.transform {
+            return self.draw_transformed(transform, geom, |b| {
+                b.draw_text_input(text_input, item_rc, size)
+            });
+        }
         if !self.should_draw(&geom) {
             return;
         }
 
         let font_request = text_input.font_request(&self.window.window_adapter());
 
-        let color = self.alpha_color(text_input.color().color());
+        let (color, gradient) = self.color_and_gradient(&text_input.color(), geom);
         let max_size = (geom.size.cast() * self.scale_factor).cast();
 
         // Clip glyphs not only against the global clip but also against the Text's geometry to avoid drawing outside
@@ -1847,7 +2576,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.clone(),
+                    selection,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), pf.height())
@@ -1867,7 +2603,14 @@ fn draw_text_input(
                     single_line: text_input.single_line(),
                 };
 
-                self.draw_text_paragraph(&paragraph, physical_clip, offset, color, selection);
+                self.draw_text_paragraph(
+                    &paragraph,
+                    physical_clip,
+                    offset,
+                    color,
+                    gradient.clone(),
+                    selection,
+                );
 
                 text_visual_representation.cursor_position.map(|cursor_offset| {
                     (paragraph.cursor_pos_for_byte_offset(cursor_offset), vf.height())
@@ -1885,35 +2628,192 @@ fn draw_text_input(
             );
 
             if let Some(clipped_src) = cursor_rect.intersection(&physical_clip.cast()) {
-                self.processor
-                    .process_rectangle(clipped_src.translate(offset.cast()), color.into());
+                self.with_gradient(gradient, |this| {
+                    this.processor
+                        .process_rectangle(clipped_src.translate(offset.cast()), color.into())
+                });
             }
         }
     }
 
     #[cfg(feature = "std")]
-    fn draw_path(&mut self, _path: Pin<&crate::items::Path>, _: &ItemRc, _size: LogicalSize) {
-        // TODO
+    fn draw_path(&mut self, path: Pin<&crate::items::Path>, item_rc: &ItemRc, size: LogicalSize) {
+        let geom = LogicalRect::from(size);
+        if let Some(transform) = self.current_state.transform {
+            return self.draw_transformed(transform, geom, |b| b.draw_path(path, item_rc, size));
+        }
+        if !self.should_draw(&geom) {
+            return;
+        }
+        let Some(clipped) = geom.intersection(&self.current_state.clip) else {
+            return;
+        };
+        let Some((offset, path_events)) = path.fitted_path_events(item_rc) else {
+            return;
+        };
+
+        let (fill_color, fill_gradient) = self.color_and_gradient(&path.fill(), geom);
+        let (stroke_color, stroke_gradient) = self.color_and_gradient(&path.stroke(), geom);
+        let stroke_width = (path.stroke_width().cast() * self.scale_factor).get();
+
+        let span: PhysicalRect = (clipped.translate(self.current_state.offset.to_vector()).cast()
+            * self.scale_factor)
+            .round()
+            .cast();
+        let translation =
+            (self.current_state.offset.to_vector() + offset).cast() * self.scale_factor;
+        let transform =
+            lyon_path::math::Transform::scale(self.scale_factor.get(), self.scale_factor.get())
+                .then_translate(translation.to_untyped());
+
+        if fill_color.alpha() > 0 {
+            let fill = path::fill_path(
+                path_events.iter(),
+                &transform,
+                &span,
+                path.fill_rule(),
+                fill_color.into(),
+            );
+            if !fill.is_empty() {
+                self.with_gradient(fill_gradient, |this| this.processor.process_path(span, fill));
+            }
+        }
+        if stroke_color.alpha() > 0 && stroke_width > 0. {
+            let stroke = path::stroke_path(
+                path_events.iter(),
+                &transform,
+                &span,
+                stroke_width,
+                stroke_color.into(),
+            );
+            if !stroke.is_empty() {
+                self.with_gradient(stroke_gradient, |this| {
+                    this.processor.process_path(span, stroke)
+                });
+            }
+        }
     }
 
     fn draw_box_shadow(
         &mut self,
-        _box_shadow: Pin<&crate::items::BoxShadow>,
-        _: &ItemRc,
-        _size: LogicalSize,
+        box_shadow: Pin<&crate::items::BoxShadow>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
     ) {
-        // TODO
+        let offset = LogicalVector::from_lengths(box_shadow.offset_x(), box_shadow.offset_y());
+        let blur = box_shadow.blur();
+        if offset.x == 0 as Coord && offset.y == 0 as Coord && blur <= LogicalLength::zero() {
+            // The shadow is entirely hidden behind the element
+            return;
+        }
+        let color = self.alpha_color(box_shadow.color());
+        if color.alpha() == 0 {
+            return;
+        }
+        let blur = blur.max(LogicalLength::zero());
+        let shadow_rect = LogicalRect::new(LogicalPoint::default() + offset, size)
+            .inflate(blur.get(), blur.get());
+        if !self.should_draw(&shadow_rect) {
+            return;
+        }
+        if let Some(transform) = self.current_state.transform {
+            return self.draw_transformed(transform, shadow_rect, |b| {
+                b.draw_box_shadow(box_shadow, self_rc, size)
+            });
+        }
+
+        #[cfg(feature = "std")]
+        let uncached_items;
+        #[cfg(feature = "std")]
+        let item_cache = match self.box_shadow_item_cache {
+            Some(item_cache) => item_cache,
+            None => {
+                uncached_items = crate::item_rendering::ItemCache::default();
+                &uncached_items
+            }
+        };
+        #[cfg(feature = "std")]
+        let Some(alpha_map) = self.box_shadow_cache.get_box_shadow(
+            self_rc,
+            item_cache,
+            box_shadow,
+            self.scale_factor,
+            |options| {
+                box_shadow::render_shadow(
+                    options.width.get(),
+                    options.height.get(),
+                    options.radius.get(),
+                    options.blur.get(),
+                )
+            },
+        ) else {
+            return;
+        };
+        #[cfg(not(feature = "std"))]
+        let alpha_map = {
+            let _ = self_rc;
+            let phys_size = size.cast() * self.scale_factor;
+            box_shadow::render_shadow(
+                phys_size.width,
+                phys_size.height,
+                (box_shadow.border_radius().cast() * self.scale_factor).get(),
+                (blur.cast() * self.scale_factor).get(),
+            )
+        };
+
+        let origin = ((self.current_state.offset + offset).cast() * self.scale_factor
+            - euclid::vec2(1., 1.) * (blur.cast() * self.scale_factor).get())
+        .round()
+        .cast::<i16>();
+        let shadow_geometry = PhysicalRect::new(
+            origin,
+            PhysicalSize::new(alpha_map.width as i16, alpha_map.height as i16),
+        );
+        let clip =
+            (self.current_state.clip.translate(self.current_state.offset.to_vector()).cast()
+                * self.scale_factor)
+                .round()
+                .cast();
+        let Some(geometry) = shadow_geometry.intersection(&clip) else { return };
+        self.processor.process_shared_image_buffer(
+            geometry,
+            SharedBufferCommand {
+                buffer: SharedBufferData::AlphaMap { data: alpha_map.data, width: alpha_map.width },
+                source_rect: geometry.translate(-origin.to_vector()),
+                colorize: color,
+                alpha: color.alpha(),
+            },
+        );
     }
 
+    #[allow(clippy::unnecessary_cast)] // Coord
     fn combine_clip(
         &mut self,
         other: LogicalRect,
-        _radius: LogicalLength,
-        _border_width: LogicalLength,
+        radius: LogicalLength,
+        border_width: LogicalLength,
     ) -> bool {
+        // Same as the other renderers: the clip goes through the middle of the border
+        let border_width =
+            border_width.max(LogicalLength::zero()).min(other.width_length() / 2 as Coord);
+        let other =
+            other.inflate(-border_width.get() / 2 as Coord, -border_width.get() / 2 as Coord);
         match self.current_state.clip.intersection(&other) {
             Some(r) => {
                 self.current_state.clip = r;
+                // Within a transformation, the clip is reduced to its bounding rectangle. That's
+                // enough because the rotated or scaled elements can't have children to clip.
+                if radius > LogicalLength::zero() && self.current_state.transform.is_none() {
+                    let clip_mask = clip::RoundedClip::new(
+                        (other.translate(self.current_state.offset.to_vector()).cast()
+                            * self.scale_factor)
+                            .cast(),
+                        (radius.cast() * self.scale_factor).get(),
+                        self.current_state.clip_mask.take(),
+                    );
+                    self.processor.set_clip_mask(clip_mask.clone());
+                    self.current_state.clip_mask = clip_mask;
+                }
                 true
             }
             None => {
@@ -1921,7 +2821,6 @@ fn combine_clip(
                 false
             }
         }
-        // TODO: handle radius and border
     }
 
     fn get_current_clip(&self) -> LogicalRect {
@@ -1933,8 +2832,20 @@ fn translate(&mut self, distance: LogicalVector) {
         self.current_state.clip = self.current_state.clip.translate(-distance)
     }
 
-    fn rotate(&mut self, _angle_in_degrees: f32) {
-        todo!()
+    fn rotate(&mut self, angle_in_degrees: f32) {
+        if angle_in_degrees % 360. == 0. {
+            return;
+        }
+        self.apply_transform(euclid::default::Transform2D::rotation(euclid::Angle::degrees(
+            angle_in_degrees,
+        )));
+    }
+
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        if x_factor == 1. && y_factor == 1. {
+            return;
+        }
+        self.apply_transform(euclid::default::Transform2D::scale(x_factor, y_factor));
     }
 
     fn apply_opacity(&mut self, opacity: f32) {
@@ -1942,11 +2853,20 @@ fn apply_opacity(&mut self, opacity: f32) {
     }
 
     fn save_state(&mut self) {
-        self.state_stack.push(self.current_state);
+        self.state_stack.push(self.current_state.clone());
     }
 
     fn restore_state(&mut self) {
+        let previous_clip_mask = self.current_state.clip_mask.take();
         self.current_state = self.state_stack.pop().unwrap();
+        let changed = match (&previous_clip_mask, &self.current_state.clip_mask) {
+            (None, None) => false,
+            (Some(a), Some(b)) => !Rc::ptr_eq(a, b),
+            _ => true,
+        };
+        if changed {
+            self.processor.set_clip_mask(self.current_state.clip_mask.clone());
+        }
     }
 
     fn scale_factor(&self) -> f32 {
@@ -1995,8 +2915,18 @@ fn draw_string(&mut self, _string: &str, _color: Color) {
         todo!()
     }
 
-    fn draw_image_direct(&mut self, _image: crate::graphics::Image) {
-        todo!()
+    fn draw_image_direct(&mut self, image: crate::graphics::Image) {
+        let size = image.size();
+        let geom = LogicalRect::from(LogicalSize::new(size.width as _, size.height as _));
+        if self.should_draw(&geom) {
+            self.draw_image_impl(
+                geom,
+                &image,
+                euclid::Rect::new(Default::default(), size.cast()),
+                ImageFit::Fill,
+                Default::default(),
+            );
+        }
     }
 
     fn window(&self) -> &crate::window::WindowInner {
@@ -2083,3 +3013,27 @@ fn deref(&self) -> &Self::Target {
         &self.window
     }
 }
+
+#[test]
+fn post_render_callback() {
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::ReusedBuffer);
+    window.renderer.set_window_adapter(&(window.clone() as Rc<dyn WindowAdapter>));
+    let mut pixels = SharedPixelBuffer::<crate::graphics::Rgba8Pixel>::new(2, 2);
+    pixels.make_mut_slice().fill(crate::graphics::Rgba8Pixel::new(255, 0, 0, 255));
+    let image = crate::graphics::Image::from_rgba8(pixels);
+
+    let mut buffer = vec![crate::graphics::Rgb8Pixel::new(0, 0, 0); 100];
+    window.renderer.render_with_post_callback(&mut buffer, 10, &|item_renderer| {
+        item_renderer.translate(LogicalVector::new(3., 4.));
+        item_renderer.draw_image_direct(image.clone());
+    });
+    let red = crate::graphics::Rgb8Pixel::new(255, 0, 0);
+    let drawn = |x: usize, y: usize| buffer[y * 10 + x] == red;
+    assert!(drawn(3, 4) && drawn(4, 4) && drawn(3, 5) && drawn(4, 5));
+    assert!(!drawn(2, 4) && !drawn(5, 4) && !drawn(3, 3) && !drawn(3, 6));
+    // The region of the callback is drawn again in the next frame to erase it
+    assert_eq!(
+        window.renderer.post_render_region.get(),
+        euclid::Box2D::new(euclid::point2(3., 4.), euclid::point2(5., 6.)).cast()
+    );
+}
@@ -0,0 +1,107 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Rendering of the `BoxShadow` element for the software renderer.
+//!
+//! The shadow is rendered once into an alpha map (which is cached when possible), that is then
+//! drawn colorized like a glyph.
+
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+#[allow(unused)]
+use num_traits::Float;
+
+/// The alpha map of a blurred rounded rectangle.
+#[derive(Clone)]
+pub(super) struct ShadowAlphaMap {
+    pub data: Rc<[u8]>,
+    pub width: u16,
+    pub height: u16,
+}
+
+/// Render the alpha map of a rounded rectangle of the given physical size and radius, blurred
+/// with the given blur radius.
+///
+/// The alpha map is `2 * blur` bigger than the rectangle in both dimensions, the rectangle
+/// being at the position `(blur, blur)`.
+pub(super) fn render_shadow(width: f32, height: f32, radius: f32, blur: f32) -> ShadowAlphaMap {
+    let blur = blur.max(0.);
+    let map_width = (width + 2. * blur).ceil().clamp(0., i16::MAX as f32) as usize;
+    let map_height = (height + 2. * blur).ceil().clamp(0., i16::MAX as f32) as usize;
+
+    let half = (width / 2., height / 2.);
+    let center = (blur + half.0, blur + half.1);
+    let radius = radius.clamp(0., half.0.min(half.1));
+
+    let mut buffer = Vec::with_capacity(map_width * map_height);
+    for y in 0..map_height {
+        for x in 0..map_width {
+            let dx = (x as f32 + 0.5 - center.0).abs() - half.0 + radius;
+            let dy = (y as f32 + 0.5 - center.1).abs() - half.1 + radius;
+            buffer.push(super::clip::rounded_rect_coverage(dx, dy, radius));
+        }
+    }
+
+    // Like the other renderers, the blur is a gaussian blur with a standard deviation of half
+    // the blur radius, approximated by three successive box blurs.
+    let sigma = blur / 2.;
+    if sigma > 0. {
+        let mut tmp = alloc::vec![0.; map_width.max(map_height)];
+        for box_radius in box_radii_for_gaussian(sigma) {
+            for line in buffer.chunks_exact_mut(map_width) {
+                box_blur(line, 1, box_radius, &mut tmp);
+            }
+            for x in 0..map_width {
+                box_blur(&mut buffer[x..], map_width, box_radius, &mut tmp);
+            }
+        }
+    }
+
+    ShadowAlphaMap {
+        data: buffer.iter().map(|a| (a * 255.).round().clamp(0., 255.) as u8).collect(),
+        width: map_width as u16,
+        height: map_height as u16,
+    }
+}
+
+/// The radius of the three box blurs that approximate a gaussian blur with the standard deviation `sigma`
+fn box_radii_for_gaussian(sigma: f32) -> [usize; 3] {
+    const PASSES: f32 = 3.;
+    let ideal_width = (12. * sigma * sigma / PASSES + 1.).sqrt();
+    let mut lower = ideal_width.floor() as i32;
+    if lower % 2 == 0 {
+        lower -= 1;
+    }
+    let lower = lower.max(1);
+    let upper = lower + 2;
+    let (l, p) = (lower as f32, PASSES);
+    let lower_count =
+        ((12. * sigma * sigma - p * l * l - 4. * p * l - 3. * p) / (-4. * l - 4.)).round() as i32;
+    core::array::from_fn(|i| {
+        (if (i as i32) < lower_count { lower } else { upper } as usize - 1) / 2
+    })
+}
+
+/// Blur in place the values of `data` at indices `0, step, 2 * step, ...` with a box of the
+/// given radius. The values outside of `data` are considered to be 0.
+fn box_blur(data: &mut [f32], step: usize, radius: usize, tmp: &mut [f32]) {
+    if radius == 0 {
+        return;
+    }
+    let len = (data.len() + step - 1) / step;
+    let tmp = &mut tmp[..len];
+    for (i, t) in tmp.iter_mut().enumerate() {
+        *t = data[i * step];
+    }
+    let scale = 1. / (2 * radius + 1) as f32;
+    let mut sum: f32 = tmp.iter().take(radius).sum();
+    for i in 0..len {
+        if i + radius < len {
+            sum += tmp[i + radius];
+        }
+        if i > radius {
+            sum -= tmp[i - radius - 1];
+        }
+        data[i * step] = sum * scale;
+    }
+}
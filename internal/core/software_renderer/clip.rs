@@ -0,0 +1,121 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Clipping to rounded rectangles in the software renderer.
+//!
+//! Rectangular clips are applied by reducing the geometry of what is drawn. When the clip has
+//! rounded corners, the commands touching the corners are drawn line by line in a temporary
+//! buffer, which is then blended in the actual buffer through a coverage mask.
+
+use super::{PhysicalLength, PhysicalPx, PhysicalRect, PremultipliedRgbaColor, TargetPixel};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+#[allow(unused)]
+use num_traits::Float;
+
+/// A rounded rectangle, in physical window coordinates, that clips everything drawn.
+#[derive(Debug)]
+pub(super) struct RoundedClip {
+    rect: euclid::Rect<f32, PhysicalPx>,
+    radius: f32,
+    /// The clip of the parent element, if it also had rounded corners.
+    parent: Option<Rc<RoundedClip>>,
+}
+
+impl RoundedClip {
+    /// Returns a clip with the given rect and radius, nested into `parent`.
+    /// Returns the parent if the clip has no rounded corner.
+    pub fn new(
+        rect: euclid::Rect<f32, PhysicalPx>,
+        radius: f32,
+        parent: Option<Rc<RoundedClip>>,
+    ) -> Option<Rc<RoundedClip>> {
+        let radius = radius.min(rect.width() / 2.).min(rect.height() / 2.);
+        if radius <= 0. || rect.is_empty() {
+            return parent;
+        }
+        Some(Rc::new(Self { rect, radius, parent }))
+    }
+
+    /// Returns true if some of the pixels of the `geometry` (in window coordinates) are in one
+    /// of the rounded corners, and so need to be drawn through the mask.
+    pub fn intersects_corners(&self, geometry: &PhysicalRect) -> bool {
+        let r = self.radius;
+        let corners = [
+            (self.rect.min_x(), self.rect.min_y()),
+            (self.rect.max_x() - r, self.rect.min_y()),
+            (self.rect.min_x(), self.rect.max_y() - r),
+            (self.rect.max_x() - r, self.rect.max_y() - r),
+        ];
+        corners.iter().any(|(x, y)| geometry.cast().intersects(&euclid::rect(*x, *y, r, r)))
+            || self.parent.as_ref().is_some_and(|p| p.intersects_corners(geometry))
+    }
+
+    /// Multiply the `coverage` of the pixels of the `line`, starting at the window coordinate
+    /// `x`, by the coverage of this clip
+    fn apply_to_line_coverage(&self, line: PhysicalLength, x: i16, coverage: &mut [u8]) {
+        let half_size = self.rect.size / 2.;
+        let center = self.rect.center();
+        let dy = (line.get() as f32 + 0.5 - center.y).abs() - half_size.height + self.radius;
+        for (i, cov) in coverage.iter_mut().enumerate() {
+            if *cov == 0 {
+                continue;
+            }
+            let dx = (x as f32 + i as f32 + 0.5 - center.x).abs() - half_size.width + self.radius;
+            let c = rounded_rect_coverage(dx, dy, self.radius);
+            *cov = (*cov as f32 * c) as u8;
+        }
+        if let Some(parent) = &self.parent {
+            parent.apply_to_line_coverage(line, x, coverage);
+        }
+    }
+}
+
+/// The coverage of a pixel by a rounded rectangle of the given `radius`.
+///
+/// `dx` and `dy` are the distances from the center of the pixel to the center of the
+/// rectangle, minus the half size of the rectangle plus the radius.
+pub(super) fn rounded_rect_coverage(dx: f32, dy: f32, radius: f32) -> f32 {
+    let outside = (dx.max(0.) * dx.max(0.) + dy.max(0.) * dy.max(0.)).sqrt();
+    let distance = outside + dx.max(dy).min(0.) - radius;
+    (0.5 - distance).clamp(0., 1.)
+}
+
+/// Draw one line of a command clipped by the `clip`.
+///
+/// `span` is the geometry of the command, relative to the `line_buffer`, and `buffer_x` is the
+/// window coordinate of the first pixel of the `line_buffer`.
+/// `draw_line` draws the line of the command in the buffer given to it.
+pub(super) fn draw_clipped_line(
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    buffer_x: i16,
+    clip: &RoundedClip,
+    line_buffer: &mut [impl TargetPixel],
+    draw_line: impl FnOnce(&PhysicalRect, &mut [PremultipliedRgbaColor]),
+) {
+    let width = span.size.width as usize;
+    let mut coverage = alloc::vec![255u8; width];
+    clip.apply_to_line_coverage(line, span.origin.x + buffer_x, &mut coverage);
+    if coverage.iter().all(|c| *c == 0) {
+        return;
+    }
+    let mut tmp: Vec<PremultipliedRgbaColor> = alloc::vec![TargetPixel::background(); width];
+    draw_line(
+        &PhysicalRect { origin: euclid::point2(0, span.origin.y), size: span.size },
+        &mut tmp,
+    );
+    let begin = span.origin.x as usize;
+    for ((pix, c), cov) in line_buffer[begin..begin + width].iter_mut().zip(tmp).zip(coverage) {
+        if cov == 0 || c.alpha == 0 {
+            continue;
+        }
+        let cov = cov as u16;
+        pix.blend(PremultipliedRgbaColor {
+            red: (c.red as u16 * cov / 255) as u8,
+            green: (c.green as u16 * cov / 255) as u8,
+            blue: (c.blue as u16 * cov / 255) as u8,
+            alpha: (c.alpha as u16 * cov / 255) as u8,
+        });
+    }
+}
@@ -561,6 +561,161 @@ fn from(p: Rgb565Pixel) -> Self {
     }
 }
 
+/// A 16bit pixel that has 5 blue bits, 6 green bits and 5 red bits
+///
+/// This is the [`Rgb565Pixel`] with the red and blue components swapped.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Bgr565Pixel(pub u16);
+
+impl Bgr565Pixel {
+    fn swapped(color: PremultipliedRgbaColor) -> PremultipliedRgbaColor {
+        PremultipliedRgbaColor { red: color.blue, blue: color.red, ..color }
+    }
+}
+
+impl TargetPixel for Bgr565Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let mut pix = Rgb565Pixel(self.0);
+        pix.blend(Self::swapped(color));
+        self.0 = pix.0;
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(Rgb565Pixel::from_rgb(b, g, r).0)
+    }
+}
+
+impl From<Rgb8Pixel> for Bgr565Pixel {
+    fn from(p: Rgb8Pixel) -> Self {
+        Self::from_rgb(p.r, p.g, p.b)
+    }
+}
+
+impl From<Bgr565Pixel> for Rgb8Pixel {
+    fn from(p: Bgr565Pixel) -> Self {
+        let p = Rgb565Pixel(p.0);
+        Rgb8Pixel { r: p.blue(), g: p.green(), b: p.red() }
+    }
+}
+
+/// A 24bit pixel stored as three bytes in the blue, green, red order
+pub type Bgr8Pixel = rgb::alt::BGR8;
+
+impl TargetPixel for Bgr8Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let a = (u8::MAX - color.alpha) as u16;
+        self.r = (self.r as u16 * a / 255) as u8 + color.red;
+        self.g = (self.g as u16 * a / 255) as u8 + color.green;
+        self.b = (self.b as u16 * a / 255) as u8 + color.blue;
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { b, g, r }
+    }
+}
+
+/// A 32bit pixel with 8 bits for each of the alpha, red, green and blue components, from the
+/// most significant to the least significant byte of the integer.
+///
+/// The color components are pre-multiplied by the alpha.
+/// This corresponds to the `ARGB8888` format of DRM (on little-endian platforms).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Argb8888Pixel(pub u32);
+
+impl Argb8888Pixel {
+    fn to_premultiplied(self) -> PremultipliedRgbaColor {
+        let [blue, green, red, alpha] = self.0.to_le_bytes();
+        PremultipliedRgbaColor { red, green, blue, alpha }
+    }
+
+    fn from_premultiplied(color: PremultipliedRgbaColor) -> Self {
+        Self(u32::from_le_bytes([color.blue, color.green, color.red, color.alpha]))
+    }
+}
+
+impl TargetPixel for Argb8888Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let mut pix = self.to_premultiplied();
+        pix.blend(color);
+        *self = Self::from_premultiplied(pix);
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::from_premultiplied(PremultipliedRgbaColor::from_rgb(r, g, b))
+    }
+
+    fn background() -> Self {
+        Self(0)
+    }
+}
+
+/// A 32bit pixel with 8 bits for each of the red, green and blue components, from the most
+/// significant to the least significant byte of the integer. The most significant byte is unused.
+///
+/// This corresponds to the `XRGB8888` format of DRM (on little-endian platforms).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Xrgb8888Pixel(pub u32);
+
+impl TargetPixel for Xrgb8888Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let [b, g, r, _] = self.0.to_le_bytes();
+        let mut pix = Rgb8Pixel { r, g, b };
+        pix.blend(color);
+        *self = Self::from_rgb(pix.r, pix.g, pix.b);
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(u32::from_le_bytes([b, g, r, 0xff]))
+    }
+}
+
+impl From<Rgb8Pixel> for Xrgb8888Pixel {
+    fn from(p: Rgb8Pixel) -> Self {
+        Self::from_rgb(p.r, p.g, p.b)
+    }
+}
+
+impl From<Xrgb8888Pixel> for Rgb8Pixel {
+    fn from(p: Xrgb8888Pixel) -> Self {
+        let [b, g, r, _] = p.0.to_le_bytes();
+        Rgb8Pixel { r, g, b }
+    }
+}
+
+/// A 8bit grayscale pixel, where 0 is black and 255 is white
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Gray8Pixel(pub u8);
+
+impl Gray8Pixel {
+    /// The luminance of the color, with the weights of ITU-R BT.601
+    fn luma(red: u8, green: u8, blue: u8) -> u8 {
+        ((red as u32 * 77 + green as u32 * 150 + blue as u32 * 29 + 128) >> 8) as u8
+    }
+}
+
+impl TargetPixel for Gray8Pixel {
+    fn blend(&mut self, color: PremultipliedRgbaColor) {
+        let a = (u8::MAX - color.alpha) as u16;
+        // The components are pre-multiplied, so is the luminance
+        let luma = Self::luma(color.red, color.green, color.blue);
+        self.0 = ((self.0 as u16 * a / 255) as u8).saturating_add(luma);
+    }
+
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self(Self::luma(r, g, b))
+    }
+}
+
+impl From<Rgb8Pixel> for Gray8Pixel {
+    fn from(p: Rgb8Pixel) -> Self {
+        Self::from_rgb(p.r, p.g, p.b)
+    }
+}
+
 #[test]
 fn rgb565() {
     let pix565 = Rgb565Pixel::from_rgb(0xff, 0x25, 0);
@@ -571,3 +726,39 @@ fn rgb565() {
     let pix888: Rgb8Pixel = pix565.into();
     assert_eq!(pix565, pix888.into());
 }
+
+#[test]
+fn bgr565() {
+    let pix: Bgr565Pixel = Rgb8Pixel::new(0xf8, 0x24, 0).into();
+    assert_eq!(pix, Bgr565Pixel(0b00000_001001_11111));
+    let pix888: Rgb8Pixel = pix.into();
+    assert_eq!(pix888, Rgb8Pixel::new(0xf8, 0x24, 0));
+
+    let mut pix = Bgr565Pixel::from_rgb(0, 0, 0xff);
+    pix.blend(PremultipliedRgbaColor { red: 0x80, green: 0, blue: 0, alpha: 0x80 });
+    let mut expected = Rgb565Pixel::from_rgb(0xff, 0, 0);
+    expected.blend(PremultipliedRgbaColor { red: 0, green: 0, blue: 0x80, alpha: 0x80 });
+    assert_eq!(pix.0, expected.0);
+}
+
+#[test]
+fn xrgb8888() {
+    let pix = Xrgb8888Pixel::from_rgb(0x12, 0x34, 0x56);
+    assert_eq!(pix.0, 0xff123456);
+    let mut pix = Xrgb8888Pixel(0x00ffffff);
+    pix.blend(PremultipliedRgbaColor { red: 0, green: 0x7f, blue: 0, alpha: 0x80 });
+    assert_eq!(pix.0, 0xff7ffe7f);
+
+    let mut pix = Argb8888Pixel::background();
+    pix.blend(PremultipliedRgbaColor { red: 0x40, green: 0, blue: 0x20, alpha: 0x80 });
+    assert_eq!(pix.0, 0x80400020);
+}
+
+#[test]
+fn gray8() {
+    assert_eq!(Gray8Pixel::from_rgb(0xff, 0xff, 0xff), Gray8Pixel(0xff));
+    assert_eq!(Gray8Pixel::from_rgb(0, 0, 0), Gray8Pixel(0));
+    let mut pix = Gray8Pixel(0xff);
+    pix.blend(PremultipliedRgbaColor { red: 0, green: 0, blue: 0, alpha: 0x80 });
+    assert_eq!(pix, Gray8Pixel(0x7f));
+}
@@ -0,0 +1,168 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Filling shapes with linear or radial gradients in the software renderer.
+//!
+//! The shape is drawn line by line in a temporary buffer with an opaque color, and the alpha of
+//! the result is then used as the coverage of the gradient, evaluated for each pixel.
+
+use super::{PhysicalLength, PhysicalPx, PhysicalRect, PremultipliedRgbaColor, TargetPixel};
+use crate::graphics::{Brush, Color};
+use alloc::vec::Vec;
+use euclid::{Point2D, Vector2D};
+#[allow(unused)]
+use num_traits::Float;
+
+#[derive(Debug)]
+enum GradientKind {
+    /// The position along the gradient is the projection of the pixel on the line that starts
+    /// at `start`: `(p - start) · direction`, where `direction` is the vector from the start to
+    /// the end of the gradient divided by its square length.
+    Linear {
+        start: Point2D<f32, PhysicalPx>,
+        direction: Vector2D<f32, PhysicalPx>,
+    },
+    Radial {
+        center: Point2D<f32, PhysicalPx>,
+        radius: f32,
+    },
+}
+
+/// A gradient, in physical window coordinates
+#[derive(Debug)]
+pub(super) struct GradientBrush {
+    kind: GradientKind,
+    /// The positions and (pre-multiplied) colors of the stops
+    stops: Vec<(f32, PremultipliedRgbaColor)>,
+}
+
+impl GradientBrush {
+    /// Create the gradient for a brush that fills the `rect` (in physical window coordinates).
+    /// The colors of the stops are multiplied by `alpha`.
+    ///
+    /// Returns None if the brush is not a gradient.
+    pub fn new(brush: &Brush, rect: euclid::Rect<f32, PhysicalPx>, alpha: f32) -> Option<Self> {
+        let to_premultiplied = |color: Color| -> PremultipliedRgbaColor {
+            if alpha < 1. {
+                Color::from_argb_u8(
+                    (color.alpha() as f32 * alpha) as u8,
+                    color.red(),
+                    color.green(),
+                    color.blue(),
+                )
+                .into()
+            } else {
+                color.into()
+            }
+        };
+        let (kind, stops) = match brush {
+            Brush::SolidColor(_) => return None,
+            Brush::LinearGradient(g) => {
+                let (start, end) = crate::graphics::line_for_angle(g.angle());
+                let scale = |p: euclid::default::Point2D<f32>| {
+                    rect.origin + Vector2D::new(p.x * rect.width(), p.y * rect.height())
+                };
+                let (start, end) = (scale(start), scale(end));
+                let direction = end - start;
+                let square_length = direction.square_length();
+                let direction =
+                    if square_length > 0. { direction / square_length } else { Vector2D::zero() };
+                (GradientKind::Linear { start, direction }, g.stops().collect::<Vec<_>>())
+            }
+            Brush::RadialGradient(g) => (
+                GradientKind::Radial {
+                    center: rect.center(),
+                    radius: rect.width().max(rect.height()) / 2.,
+                },
+                g.stops().collect(),
+            ),
+        };
+        let stops: Vec<_> =
+            stops.into_iter().map(|s| (s.position, to_premultiplied(s.color))).collect();
+        if stops.is_empty() {
+            return None;
+        }
+        Some(Self { kind, stops })
+    }
+
+    /// Returns true if all the colors of the gradient are fully transparent
+    pub fn is_transparent(&self) -> bool {
+        self.stops.iter().all(|(_, c)| c.alpha == 0)
+    }
+
+    fn position_at(&self, x: f32, y: f32) -> f32 {
+        match self.kind {
+            GradientKind::Linear { start, direction } => {
+                (Point2D::new(x, y) - start).dot(direction)
+            }
+            GradientKind::Radial { center, radius } => {
+                if radius > 0. {
+                    (Point2D::new(x, y) - center).length() / radius
+                } else {
+                    1.
+                }
+            }
+        }
+    }
+
+    fn color_at(&self, position: f32) -> PremultipliedRgbaColor {
+        let first = self.stops[0];
+        if position <= first.0 {
+            return first.1;
+        }
+        let mut previous = first;
+        for stop in self.stops.iter().skip(1) {
+            if position < stop.0 {
+                let f = (position - previous.0) / (stop.0 - previous.0);
+                let mix =
+                    |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * f).round().clamp(0., 255.);
+                return PremultipliedRgbaColor {
+                    red: mix(previous.1.red, stop.1.red) as u8,
+                    green: mix(previous.1.green, stop.1.green) as u8,
+                    blue: mix(previous.1.blue, stop.1.blue) as u8,
+                    alpha: mix(previous.1.alpha, stop.1.alpha) as u8,
+                };
+            }
+            previous = *stop;
+        }
+        previous.1
+    }
+}
+
+/// Draw one line of a command filled with the `gradient`.
+///
+/// `span` is the geometry of the command, relative to the `line_buffer`, and `buffer_x` is the
+/// window coordinate of the first pixel of the `line_buffer`.
+/// `draw_line` draws the line of the command in the buffer given to it. The command should be
+/// drawn with an opaque color, as only its alpha is used.
+pub(super) fn draw_line_with_gradient(
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    buffer_x: i16,
+    gradient: &GradientBrush,
+    line_buffer: &mut [impl TargetPixel],
+    draw_line: impl FnOnce(&PhysicalRect, &mut [PremultipliedRgbaColor]),
+) {
+    let width = span.size.width as usize;
+    let mut tmp: Vec<PremultipliedRgbaColor> = alloc::vec![TargetPixel::background(); width];
+    draw_line(
+        &PhysicalRect { origin: euclid::point2(0, span.origin.y), size: span.size },
+        &mut tmp,
+    );
+    let begin = span.origin.x as usize;
+    let x = (span.origin.x + buffer_x) as f32 + 0.5;
+    let y = line.get() as f32 + 0.5;
+    for (i, (pix, coverage)) in line_buffer[begin..begin + width].iter_mut().zip(tmp).enumerate() {
+        if coverage.alpha == 0 {
+            continue;
+        }
+        let c = gradient.color_at(gradient.position_at(x + i as f32, y));
+        let cov = coverage.alpha as u16;
+        pix.blend(PremultipliedRgbaColor {
+            red: (c.red as u16 * cov / 255) as u8,
+            green: (c.green as u16 * cov / 255) as u8,
+            blue: (c.blue as u16 * cov / 255) as u8,
+            alpha: (c.alpha as u16 * cov / 255) as u8,
+        });
+    }
+}
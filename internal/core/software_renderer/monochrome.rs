@@ -0,0 +1,116 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Rendering to 1-bit per pixel monochrome displays, such as e-paper displays.
+//!
+//! The scene is rendered line by line in grayscale, and each line is then converted to black
+//! and white, optionally with dithering.
+
+use super::{Gray8Pixel, LineBufferProvider};
+use alloc::vec::Vec;
+
+/// The dithering to apply when converting the grayscale rendering to black and white pixels
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Dithering {
+    /// Each pixel is black if it is darker than the middle gray, and white otherwise
+    #[default]
+    None,
+    /// Ordered dithering with a 4x4 Bayer matrix.
+    ///
+    /// The result only depends on the position of the pixel, so redrawing a part of the screen
+    /// doesn't produce visible seams.
+    Ordered,
+}
+
+/// The 4x4 Bayer threshold matrix
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+impl Dithering {
+    fn threshold(self, x: usize, y: usize) -> u8 {
+        match self {
+            Dithering::None => 128,
+            Dithering::Ordered => BAYER_4X4[y % 4][x % 4] * 16 + 8,
+        }
+    }
+}
+
+/// A [`LineBufferProvider`] that renders to a buffer with one bit per pixel.
+///
+/// Each line of the buffer starts at a multiple of `stride` bytes. Within a line, the pixels are
+/// packed with the most significant bit first: the pixel at the position `x` is the bit
+/// `0x80 >> (x % 8)` of the byte `x / 8`. A bit set to 1 is a white pixel, and a bit set to 0
+/// is a black pixel.
+///
+/// ```rust,no_run
+/// # use i_slint_core::software_renderer::{MonochromeFrameBuffer, Dithering, SoftwareRenderer};
+/// # fn xx(renderer: &SoftwareRenderer) {
+/// const WIDTH: usize = 200;
+/// const HEIGHT: usize = 200;
+/// let mut buffer = [0u8; WIDTH / 8 * HEIGHT];
+/// renderer.render_by_line(MonochromeFrameBuffer::new(&mut buffer, WIDTH / 8, Dithering::Ordered));
+/// // send the buffer to the e-paper display
+/// # }
+/// ```
+pub struct MonochromeFrameBuffer<'a> {
+    buffer: &'a mut [u8],
+    stride: usize,
+    dithering: Dithering,
+    line: Vec<Gray8Pixel>,
+}
+
+impl<'a> MonochromeFrameBuffer<'a> {
+    /// Creates a new frame buffer for the `buffer`, where each line is `stride` bytes long.
+    pub fn new(buffer: &'a mut [u8], stride: usize, dithering: Dithering) -> Self {
+        Self { buffer, stride, dithering, line: Vec::new() }
+    }
+}
+
+impl<'a> LineBufferProvider for MonochromeFrameBuffer<'a> {
+    type TargetPixel = Gray8Pixel;
+
+    fn process_line(
+        &mut self,
+        line: usize,
+        range: core::ops::Range<usize>,
+        render_fn: impl FnOnce(&mut [Self::TargetPixel]),
+    ) {
+        self.line.clear();
+        self.line.resize(range.len(), Gray8Pixel::default());
+        render_fn(&mut self.line);
+        let bytes = &mut self.buffer[line * self.stride..][..self.stride];
+        for (x, pixel) in range.zip(self.line.iter()) {
+            let mask = 0x80 >> (x % 8);
+            if pixel.0 >= self.dithering.threshold(x, line) {
+                bytes[x / 8] |= mask;
+            } else {
+                bytes[x / 8] &= !mask;
+            }
+        }
+    }
+}
+
+#[test]
+fn monochrome_packing() {
+    let mut buffer = [0x0fu8; 4];
+    let mut fb = MonochromeFrameBuffer::new(&mut buffer, 2, Dithering::None);
+    fb.process_line(1, 2..12, |line| {
+        assert_eq!(line.len(), 10);
+        for (i, pix) in line.iter_mut().enumerate() {
+            *pix = Gray8Pixel(if i % 2 == 0 { 0xff } else { 0x20 });
+        }
+    });
+    assert_eq!(buffer, [0x0f, 0x0f, 0b0010_1010, 0b1010_1111]);
+}
+
+#[test]
+fn monochrome_ordered_dithering() {
+    let mut buffer = [0u8; 4];
+    let mut fb = MonochromeFrameBuffer::new(&mut buffer, 1, Dithering::Ordered);
+    for y in 0..4 {
+        fb.process_line(y, 0..8, |line| line.fill(Gray8Pixel(0x80)));
+    }
+    // A middle gray turns half of the pixels white
+    assert_eq!(buffer.iter().map(|b| b.count_ones()).sum::<u32>(), 16);
+    assert_eq!(buffer, [0b1010_1010, 0b0101_0101, 0b1010_1010, 0b0101_0101]);
+}
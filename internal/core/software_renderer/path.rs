@@ -0,0 +1,304 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Rasterization of the `Path` element for the software renderer.
+//!
+//! The path is flattened into a list of straight edges in physical coordinates when the
+//! scene is built. The coverage of a line is then computed on demand by intersecting the
+//! edges with a few sub-scanlines, so that no mask of the whole path needs to be kept in
+//! memory and [`super::SoftwareRenderer::render_by_line`] keeps working.
+
+use super::{PhysicalLength, PhysicalRect, PremultipliedRgbaColor, TargetPixel};
+use crate::items::FillRule;
+use crate::lengths::PointLengths;
+use alloc::vec::Vec;
+use lyon_path::iterator::PathIterator;
+use lyon_path::math::{Point, Transform, Vector};
+use lyon_path::Event;
+
+/// The maximum distance, in physical pixels, between a curve and the line segments approximating it
+const TOLERANCE: f32 = 0.1;
+
+/// The number of sub-scanlines sampled for each pixel line, for the vertical anti-aliasing.
+/// (The horizontal anti-aliasing is computed exactly)
+const SUB_SCANLINES: usize = 5;
+
+/// Same default as SVG and CSS: the miter joins longer than this ratio of the stroke width are beveled
+const MITER_LIMIT: f32 = 4.;
+
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    /// The top point of the edge (x0, y0), relative to the span origin
+    x0: f32,
+    y0: f32,
+    /// The bottom y coordinate (y1 > y0)
+    y1: f32,
+    /// How much x changes when y increases by one
+    slope: f32,
+    /// +1 if the edge goes down, -1 if it goes up
+    winding: i8,
+}
+
+/// A path, or the stroke of a path, ready to be rendered line by line.
+#[derive(Debug)]
+pub(super) struct PathCommand {
+    /// Sorted by `y0`
+    edges: Vec<Edge>,
+    fill_rule: FillRule,
+    color: PremultipliedRgbaColor,
+    /// Buffers reused by [`draw_path_line`] so that it doesn't allocate for each line
+    scratch: core::cell::RefCell<Scratch>,
+}
+
+#[derive(Debug, Default)]
+struct Scratch {
+    /// The coverage of each pixel of the line, in unit of SUB_SCANLINES
+    coverage: Vec<f32>,
+    /// The x position and the winding of the edges crossing a sub scanline
+    crossings: Vec<(f32, i8)>,
+}
+
+impl PathCommand {
+    /// Create a command from the edges (in physical window coordinates) collected by the builder.
+    /// The edges are stored relative to the `span_origin` so that the span can be moved when
+    /// rendering line by line.
+    fn new(
+        mut builder: EdgeBuilder,
+        span_origin: Vector,
+        fill_rule: FillRule,
+        color: PremultipliedRgbaColor,
+    ) -> Self {
+        for e in builder.edges.iter_mut() {
+            e.x0 -= span_origin.x;
+            e.y0 -= span_origin.y;
+            e.y1 -= span_origin.y;
+        }
+        builder.edges.sort_unstable_by(|a, b| a.y0.total_cmp(&b.y0));
+        Self { edges: builder.edges, fill_rule, color, scratch: Default::default() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct EdgeBuilder {
+    edges: Vec<Edge>,
+}
+
+impl EdgeBuilder {
+    fn add_edge(&mut self, from: Point, to: Point, reversed: bool) {
+        if from.y == to.y || !from.y.is_finite() || !to.y.is_finite() {
+            // horizontal edges never cross a scanline
+            return;
+        }
+        let (top, bottom, winding) = if from.y < to.y { (from, to, 1) } else { (to, from, -1) };
+        self.edges.push(Edge {
+            x0: top.x,
+            y0: top.y,
+            y1: bottom.y,
+            slope: (bottom.x - top.x) / (bottom.y - top.y),
+            winding: if reversed { -winding } else { winding },
+        });
+    }
+
+    /// Add a closed polygon, always with a positive orientation so that the union of several
+    /// polygons can be filled with the non-zero rule.
+    fn add_positive_polygon(&mut self, points: &[Point]) {
+        let area: f32 = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| (b.x - a.x) * (b.y + a.y))
+            .sum();
+        let reversed = area < 0.;
+        for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+            self.add_edge(*a, *b, reversed);
+        }
+    }
+
+    /// Add the outline of a polyline of the given width
+    fn add_stroke(&mut self, points: &[Point], closed: bool, half_width: f32) {
+        let normal = |a: Point, b: Point| {
+            let d = (b - a).normalize();
+            Vector::new(-d.y, d.x) * half_width
+        };
+        for segment in points.windows(2) {
+            let (a, b) = (segment[0], segment[1]);
+            let n = normal(a, b);
+            self.add_positive_polygon(&[a + n, b + n, b - n, a - n]);
+        }
+        let mut add_join = |prev: Point, p: Point, next: Point| {
+            let (n1, n2) = (normal(prev, p), normal(p, next));
+            // The join is on the outer side of the turn
+            let (n1, n2) = if (p - prev).cross(next - p) > 0. { (-n1, -n2) } else { (n1, n2) };
+            let bisector = n1 + n2;
+            let cos_half_angle = bisector.length() / (2. * half_width);
+            if cos_half_angle > 1. / MITER_LIMIT {
+                let miter = bisector.normalize() * (half_width / cos_half_angle);
+                self.add_positive_polygon(&[p, p + n1, p + miter, p + n2]);
+            } else {
+                self.add_positive_polygon(&[p, p + n1, p + n2]);
+            }
+        };
+        for w in points.windows(3) {
+            add_join(w[0], w[1], w[2]);
+        }
+        if closed && points.len() > 2 {
+            let last = points.len() - 1;
+            add_join(points[last - 1], points[last], points[0]);
+            add_join(points[last], points[0], points[1]);
+            let n = normal(points[last], points[0]);
+            self.add_positive_polygon(&[
+                points[last] + n,
+                points[0] + n,
+                points[0] - n,
+                points[last] - n,
+            ]);
+        }
+    }
+}
+
+/// Build the command to fill the path.
+///
+/// `transform` maps the path events to physical window coordinates, and `span` is the (clipped)
+/// physical rectangle in which the path will be rendered.
+pub(super) fn fill_path(
+    events: impl Iterator<Item = Event<Point, Point>>,
+    transform: &Transform,
+    span: &PhysicalRect,
+    fill_rule: FillRule,
+    color: PremultipliedRgbaColor,
+) -> PathCommand {
+    let mut builder = EdgeBuilder::default();
+    for event in events.transformed(transform).flattened(TOLERANCE) {
+        match event {
+            Event::Line { from, to } => builder.add_edge(from, to, false),
+            // Filling always implicitly closes the sub-paths
+            Event::End { last, first, .. } => builder.add_edge(last, first, false),
+            _ => {}
+        }
+    }
+    PathCommand::new(builder, span.origin.to_vector().cast().to_untyped(), fill_rule, color)
+}
+
+/// Build the command to stroke the path with a line of the given physical `width`.
+///
+/// The joins are miter joins and the caps are butt caps.
+pub(super) fn stroke_path(
+    events: impl Iterator<Item = Event<Point, Point>>,
+    transform: &Transform,
+    span: &PhysicalRect,
+    width: f32,
+    color: PremultipliedRgbaColor,
+) -> PathCommand {
+    let mut builder = EdgeBuilder::default();
+    let mut points: Vec<Point> = Vec::new();
+    for event in events.transformed(transform).flattened(TOLERANCE) {
+        match event {
+            Event::Begin { at } => {
+                points.clear();
+                points.push(at);
+            }
+            // skip degenerated segments as they have no direction
+            Event::Line { to, .. }
+                if points.last().map_or(true, |l| (to - *l).square_length() > f32::EPSILON) =>
+            {
+                points.push(to)
+            }
+            Event::End { close, .. } => {
+                if close
+                    && points.len() > 1
+                    && (points[0] - points[points.len() - 1]).square_length() <= f32::EPSILON
+                {
+                    points.pop();
+                }
+                builder.add_stroke(&points, close, width / 2.);
+            }
+            _ => {}
+        }
+    }
+    PathCommand::new(builder, span.origin.to_vector().cast().to_untyped(), FillRule::Nonzero, color)
+}
+
+/// Draw one line of the path in the line buffer
+pub(super) fn draw_path_line(
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    path: &PathCommand,
+    line_buffer: &mut [impl TargetPixel],
+) {
+    let width = span.size.width as usize;
+    let y = (line - span.origin.y_length()).get() as f32;
+    if !path.edges.iter().take_while(|e| e.y0 < y + 1.).any(|e| e.y1 > y) {
+        return;
+    }
+
+    let mut scratch = path.scratch.borrow_mut();
+    let Scratch { coverage, crossings } = &mut *scratch;
+    coverage.clear();
+    coverage.resize(width, 0.);
+    for sub in 0..SUB_SCANLINES {
+        let y = y + (sub as f32 + 0.5) / SUB_SCANLINES as f32;
+        crossings.clear();
+        crossings.extend(
+            path.edges
+                .iter()
+                .take_while(|e| e.y0 <= y)
+                .filter(|e| y < e.y1)
+                .map(|e| (e.x0 + (y - e.y0) * e.slope, e.winding)),
+        );
+        crossings.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        let is_inside = |winding: i32| match path.fill_rule {
+            FillRule::Nonzero => winding != 0,
+            FillRule::Evenodd => winding % 2 != 0,
+        };
+        let mut winding = 0i32;
+        let mut start = 0.;
+        for (x, w) in crossings.iter().copied() {
+            let was_inside = is_inside(winding);
+            winding += w as i32;
+            match (was_inside, is_inside(winding)) {
+                (false, true) => start = x,
+                (true, false) => add_span_coverage(coverage, start, x),
+                _ => {}
+            }
+        }
+    }
+
+    let pos_x = span.origin.x as usize;
+    let c = path.color;
+    for (x, cov) in coverage.iter().enumerate() {
+        if *cov <= 0. {
+            continue;
+        }
+        let cov = ((*cov * 255. / SUB_SCANLINES as f32) as u32).min(255);
+        line_buffer[pos_x + x].blend(PremultipliedRgbaColor {
+            alpha: ((c.alpha as u32 * cov) / 255) as u8,
+            red: ((c.red as u32 * cov) / 255) as u8,
+            green: ((c.green as u32 * cov) / 255) as u8,
+            blue: ((c.blue as u32 * cov) / 255) as u8,
+        });
+    }
+}
+
+/// Add the coverage of the horizontal span between x1 and x2 in the coverage buffer
+fn add_span_coverage(coverage: &mut [f32], x1: f32, x2: f32) {
+    let x1 = x1.max(0.);
+    let x2 = x2.min(coverage.len() as f32);
+    if x1 >= x2 {
+        return;
+    }
+    let (i1, i2) = (x1 as usize, x2 as usize);
+    if i1 == i2 {
+        coverage[i1] += x2 - x1;
+        return;
+    }
+    coverage[i1] += (i1 + 1) as f32 - x1;
+    for c in &mut coverage[i1 + 1..i2] {
+        *c += 1.;
+    }
+    if i2 < coverage.len() {
+        coverage[i2] += x2 - i2 as f32;
+    }
+}
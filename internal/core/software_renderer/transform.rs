@@ -0,0 +1,81 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Drawing of rotated (or otherwise transformed) items in the software renderer.
+//!
+//! A transformed item is first rendered without transformation in a temporary buffer, which is
+//! then drawn with the transformation applied, sampling the buffer with a bilinear filter.
+
+use super::{PhysicalLength, PhysicalRect, PremultipliedRgbaColor, TargetPixel};
+use alloc::rc::Rc;
+#[allow(unused)]
+use num_traits::Float;
+
+/// An item rendered in a buffer, and the transformation to apply to draw it in the window.
+pub(super) struct TransformedTexture {
+    pub data: Rc<[PremultipliedRgbaColor]>,
+    pub width: u16,
+    pub height: u16,
+    /// Maps the position of a pixel, relative to the geometry of the command, to the position
+    /// in the texture.
+    pub transform: euclid::default::Transform2D<f32>,
+}
+
+impl TransformedTexture {
+    fn pixel(&self, x: i32, y: i32) -> PremultipliedRgbaColor {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return PremultipliedRgbaColor::default();
+        }
+        self.data[y as usize * self.width as usize + x as usize]
+    }
+
+    /// Returns the color at the given position in the texture, with bilinear interpolation
+    /// between the four nearest pixels
+    fn sample(&self, x: f32, y: f32) -> PremultipliedRgbaColor {
+        // The center of the pixel (0, 0) is at (0.5, 0.5)
+        let (x, y) = (x - 0.5, y - 0.5);
+        if x <= -1. || y <= -1. || x >= self.width as f32 || y >= self.height as f32 {
+            return PremultipliedRgbaColor::default();
+        }
+        let (x0, y0) = (x.floor(), y.floor());
+        let fx = ((x - x0) * 256.) as u32;
+        let fy = ((y - y0) * 256.) as u32;
+        let (x0, y0) = (x0 as i32, y0 as i32);
+        let weights = [(256 - fx) * (256 - fy), fx * (256 - fy), (256 - fx) * fy, fx * fy];
+        let pixels = [
+            self.pixel(x0, y0),
+            self.pixel(x0 + 1, y0),
+            self.pixel(x0, y0 + 1),
+            self.pixel(x0 + 1, y0 + 1),
+        ];
+        let mix = |component: fn(&PremultipliedRgbaColor) -> u8| {
+            (pixels.iter().zip(weights).map(|(p, w)| component(p) as u32 * w).sum::<u32>() >> 16)
+                as u8
+        };
+        PremultipliedRgbaColor {
+            red: mix(|p| p.red),
+            green: mix(|p| p.green),
+            blue: mix(|p| p.blue),
+            alpha: mix(|p| p.alpha),
+        }
+    }
+}
+
+/// Draw one line of a transformed texture.
+///
+/// `span` is the geometry of the command, relative to the `buffer`.
+pub(super) fn draw_transformed_texture_line(
+    span: &PhysicalRect,
+    line: PhysicalLength,
+    texture: &TransformedTexture,
+    buffer: &mut [impl TargetPixel],
+) {
+    let y = (line.get() - span.origin.y) as f32 + 0.5;
+    for (x, pix) in buffer[span.min_x() as usize..span.max_x() as usize].iter_mut().enumerate() {
+        let p = texture.transform.transform_point(euclid::point2(x as f32 + 0.5, y));
+        let color = texture.sample(p.x, p.y);
+        if color.alpha > 0 {
+            pix.blend(color);
+        }
+    }
+}
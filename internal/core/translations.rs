@@ -1,8 +1,11 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
+use crate::properties::Property;
 use crate::SharedString;
+use alloc::boxed::Box;
 use core::fmt::Display;
+use core::pin::Pin;
 pub use formatter::FormatArgs;
 
 mod formatter {
@@ -171,6 +174,31 @@ fn from_name<'b>(&'b self, name: &str) -> Option<Self::Output<'b>> {
     }
 }
 
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+thread_local!(
+/// The language selected with [`select_language`]. An empty string means the default language
+/// of the system. Every translation reads this property, so that the bindings that use
+/// translations get re-evaluated when the language changes.
+static SELECTED_LANGUAGE : Pin<Box<Property<SharedString>>> = Box::pin(Property::new_named(SharedString::default(), "i_slint_core::translations::SELECTED_LANGUAGE"))
+);
+
+/// Returns the language selected with [`select_language`], or an empty string if the default
+/// language of the system is used.
+///
+/// This registers the current binding as a dependency.
+pub fn selected_language() -> SharedString {
+    SELECTED_LANGUAGE.with(|language| language.as_ref().get())
+}
+
+/// Change the language used for the translations, and mark all the bindings that use
+/// translations as dirty. An empty string selects the default language of the system.
+pub fn select_language(language: &str) {
+    BUNDLED_LANGUAGE_INDEX.with(|index| index.take());
+    SELECTED_LANGUAGE.with(|l| l.as_ref().set(language.into()));
+}
+
 /// Do the translation and formatting
 pub fn translate(
     original: &str,
@@ -181,17 +209,137 @@ pub fn translate(
     plural: &str,
 ) -> SharedString {
     #![allow(unused)]
+    // Register a dependency on the language, so the binding is evaluated again when it changes
+    let language = selected_language();
     let mut output = SharedString::default();
     let translated = if plural.is_empty() || n == 1 { original } else { plural };
     #[cfg(all(target_family = "unix", feature = "gettext-rs"))]
-    let translated = translate_gettext(original, contextid, domain, n, plural);
+    let translated = translate_gettext(&language, original, contextid, domain, n, plural);
     use core::fmt::Write;
     write!(output, "{}", formatter::format(&translated, &WithPlural(arguments, n))).unwrap();
     output
 }
 
+thread_local!(
+/// The languages of the bundled translations, and the index of the language to use in them as
+/// computed by [`bundled_language_index`]. Reset by [`select_language`].
+static BUNDLED_LANGUAGE_INDEX : core::cell::RefCell<Option<(alloc::vec::Vec<SharedString>, Option<usize>)>> = Default::default()
+);
+
+/// Returns the index, in `languages`, of the language to use for the bundled translations.
+///
+/// This is the language selected with [`select_language`] or, if none was selected, the
+/// language of the system. Returns None if the strings should not be translated.
+/// The result is cached until the next call to [`select_language`].
+fn bundled_language_index(languages: &[&str]) -> Option<usize> {
+    // Register a dependency on the language, so the binding is evaluated again when it changes
+    let selected = selected_language();
+    BUNDLED_LANGUAGE_INDEX.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        match &*cache {
+            Some((cached_languages, index)) if cached_languages.iter().eq(languages.iter()) => {
+                *index
+            }
+            _ => {
+                let index = find_bundled_language_index(languages, &selected);
+                *cache = Some((languages.iter().map(|l| SharedString::from(*l)).collect(), index));
+                index
+            }
+        }
+    })
+}
+
+fn find_bundled_language_index(languages: &[&str], selected: &str) -> Option<usize> {
+    let find = |language: &str| {
+        // Ignore the encoding and the modifier, as in `de_CH.UTF-8@euro`
+        let language = language.split(['.', '@']).next().unwrap_or_default();
+        languages.iter().position(|l| *l == language).or_else(|| {
+            // Fallback to the language without the territory: `de_CH` -> `de`
+            let base = language.split(['_', '-']).next().unwrap_or_default();
+            languages.iter().position(|l| *l == base)
+        })
+    };
+    if !selected.is_empty() {
+        return find(selected);
+    }
+    #[cfg(feature = "std")]
+    {
+        // The environment variables that gettext uses, in order of priority
+        for var in ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"] {
+            if let Some(value) = std::env::var(var).ok().filter(|v| !v.is_empty()) {
+                return value.split(':').find_map(find);
+            }
+        }
+    }
+    None
+}
+
+/// Do the formatting of a string translated with translations embedded in the binary.
+///
+/// `translations` contains the translation of `original` in each of the `languages`, or None
+/// if it is not translated in that language.
+pub fn translate_from_bundle(
+    languages: &[&str],
+    original: &str,
+    translations: &[Option<&str>],
+    arguments: &(impl FormatArgs + ?Sized),
+) -> SharedString {
+    let translated = bundled_language_index(languages)
+        .and_then(|index| translations.get(index).copied().flatten())
+        .unwrap_or(original);
+    let mut output = SharedString::default();
+    use core::fmt::Write;
+    write!(output, "{}", formatter::format(translated, arguments)).unwrap();
+    output
+}
+
+/// Same as [`translate_from_bundle`] for a string with a plural form.
+///
+/// `original` contains the original singular and plural forms, and `translations` contains all
+/// the plural forms of each of the `languages`, or an empty slice if it is not translated in that
+/// language. The `plural_rules` return the index of the plural form to use for `n` in each language.
+pub fn translate_from_bundle_with_plural(
+    languages: &[&str],
+    plural_rules: &[&dyn Fn(i32) -> usize],
+    original: [&str; 2],
+    translations: &[&[&str]],
+    arguments: &(impl FormatArgs + ?Sized),
+    n: i32,
+) -> SharedString {
+    let translated = bundled_language_index(languages)
+        .and_then(|index| {
+            let forms = translations.get(index)?;
+            forms.get(plural_rules.get(index)?(n)).or(forms.last()).copied()
+        })
+        .filter(|translated| !translated.is_empty())
+        .unwrap_or(if n == 1 { original[0] } else { original[1] });
+    let mut output = SharedString::default();
+    use core::fmt::Write;
+    write!(output, "{}", formatter::format(translated, &WithPlural(arguments, n))).unwrap();
+    output
+}
+
+#[cfg(all(target_family = "unix", feature = "gettext-rs"))]
+mod gettext_catalog;
+
+/// Translate with gettext, or with the `.mo` file of the `language` if one was selected with
+/// [`select_language`].
 #[cfg(all(target_family = "unix", feature = "gettext-rs"))]
-fn translate_gettext(string: &str, ctx: &str, domain: &str, n: i32, plural: &str) -> String {
+fn translate_gettext(
+    language: &SharedString,
+    string: &str,
+    ctx: &str,
+    domain: &str,
+    n: i32,
+    plural: &str,
+) -> String {
+    if !language.is_empty() {
+        return gettext_catalog::translate(language, string, ctx, domain, n, plural)
+            .unwrap_or_else(|| {
+                if plural.is_empty() || n == 1 { string } else { plural }.to_owned()
+            });
+    }
+
     fn mangle_context(ctx: &str, s: &str) -> String {
         format!("{}\u{4}{}", ctx, s)
     }
@@ -230,15 +378,113 @@ pub fn gettext_bindtextdomain(_domain: &str, _dirname: std::path::PathBuf) -> st
         START.call_once(|| {
             gettextrs::setlocale(gettextrs::LocaleCategory::LcAll, "");
         });
+        gettext_catalog::domain_bound(_domain);
+        // The strings of the selected language may now be found in the new directory
+        SELECTED_LANGUAGE.with(|language| language.mark_dirty());
     }
     Ok(())
 }
 
+#[test]
+fn select_language_marks_translations_dirty() {
+    let translated = Box::pin(Property::<SharedString>::default());
+    translated.as_ref().set_binding(|| translate("Hello", "", "", &[] as &[SharedString], 0, ""));
+    assert_eq!(translated.as_ref().get(), "Hello");
+    assert!(!translated.is_dirty());
+    select_language("de");
+    assert!(translated.is_dirty());
+    assert_eq!(selected_language(), "de");
+    assert_eq!(translated.as_ref().get(), "Hello");
+    select_language("");
+    assert!(translated.is_dirty());
+}
+
+#[cfg(all(target_family = "unix", feature = "gettext-rs"))]
+#[test]
+fn select_language_before_binding_the_domain() {
+    const DOMAIN: &str = "i_slint_core_select_language_test";
+    let directory = std::env::temp_dir().join(format!("{DOMAIN}_{}", std::process::id()));
+    let write_catalog = |name: &str, translation: &str| {
+        let path = directory.join(name).join("de").join("LC_MESSAGES");
+        std::fs::create_dir_all(&path).unwrap();
+        let data = gettext_catalog::build_mo_file(&[("Hello", translation)]);
+        std::fs::write(path.join(format!("{DOMAIN}.mo")), data).unwrap();
+    };
+    write_catalog("first", "Hallo");
+    write_catalog("second", "Servus");
+
+    let translated = Box::pin(Property::<SharedString>::default());
+    translated
+        .as_ref()
+        .set_binding(|| translate("Hello", "", DOMAIN, &[] as &[SharedString], 0, ""));
+    select_language("de");
+    assert_eq!(translated.as_ref().get(), "Hello");
+    gettext_bindtextdomain(DOMAIN, directory.join("first")).unwrap();
+    assert!(translated.is_dirty());
+    assert_eq!(translated.as_ref().get(), "Hallo");
+    // Binding the domain to another directory doesn't keep the old catalog
+    gettext_bindtextdomain(DOMAIN, directory.join("second")).unwrap();
+    assert_eq!(translated.as_ref().get(), "Servus");
+
+    select_language("");
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn bundled_translations() {
+    let languages = ["de", "fr", "nl", "pt_BR"];
+    let translate = |original| {
+        translate_from_bundle(
+            &languages,
+            original,
+            &[Some("Hallo {}"), None, Some("Hallo {}"), Some("Olá {}")],
+            &["Welt"] as &[&str],
+        )
+    };
+    let not_one: &dyn Fn(i32) -> usize = &|n| (n != 1) as usize;
+    let plural_rules: [&dyn Fn(i32) -> usize; 4] =
+        [not_one, &|n| (n > 1) as usize, not_one, not_one];
+    let translate_plural = |n| {
+        translate_from_bundle_with_plural(
+            &languages,
+            &plural_rules,
+            ["{n} file", "{n} files"],
+            &[
+                &["{n} Datei", "{n} Dateien"],
+                &["{n} fichier", "{n} fichiers"],
+                &["{n} bestand", ""],
+                &[],
+            ],
+            &[] as &[&str],
+            n,
+        )
+    };
+    select_language("de");
+    assert_eq!(translate("Hello {}"), "Hallo Welt");
+    assert_eq!(translate_plural(1), "1 Datei");
+    assert_eq!(translate_plural(0), "0 Dateien");
+    select_language("fr_CA.UTF-8");
+    assert_eq!(translate("Hello {}"), "Hello Welt");
+    assert_eq!(translate_plural(0), "0 fichier");
+    assert_eq!(translate_plural(2), "2 fichiers");
+    select_language("nl");
+    assert_eq!(translate_plural(1), "1 bestand");
+    // An empty plural form is not translated, as with gettext
+    assert_eq!(translate_plural(2), "2 files");
+    select_language("pt_BR");
+    assert_eq!(translate("Hello {}"), "Olá Welt");
+    assert_eq!(translate_plural(2), "2 files");
+    select_language("it");
+    assert_eq!(translate("Hello {}"), "Hello Welt");
+    select_language("");
+}
+
 #[cfg(feature = "ffi")]
 mod ffi {
     #![allow(unsafe_code)]
     use super::*;
     use crate::slice::Slice;
+    use alloc::vec::Vec;
 
     #[no_mangle]
     /// Returns a nul-terminated pointer for this string.
@@ -255,4 +501,78 @@ pub extern "C" fn slint_translate(
         *to_translate =
             translate(to_translate.as_str(), &context, &domain, arguments.as_slice(), n, &plural)
     }
+
+    #[no_mangle]
+    /// Selects the language used for the translations. See [`select_language`].
+    pub extern "C" fn slint_select_language(language: &SharedString) {
+        select_language(language.as_str())
+    }
+    /// for cbindgen.
+    #[allow(non_camel_case_types)]
+    type c_char = u8;
+
+    /// Safety: `s` must be null or a nul-terminated UTF-8 string
+    unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+        (!s.is_null())
+            .then(|| core::str::from_utf8_unchecked(core::ffi::CStr::from_ptr(s.cast()).to_bytes()))
+    }
+
+    #[no_mangle]
+    /// Translate `original` with the translations bundled in the code generated for C++.
+    /// `translations` contains the translation in each of the `languages`, or null if the string
+    /// is not translated in that language.
+    ///
+    /// Safety: all the strings must be null or nul-terminated UTF-8 strings
+    pub unsafe extern "C" fn slint_translate_from_bundle(
+        languages: Slice<*const c_char>,
+        original: *const c_char,
+        translations: Slice<*const c_char>,
+        arguments: Slice<SharedString>,
+        output: &mut SharedString,
+    ) {
+        let languages =
+            languages.iter().map(|l| to_str(*l).unwrap_or_default()).collect::<Vec<_>>();
+        let translations = translations.iter().map(|t| to_str(*t)).collect::<Vec<_>>();
+        *output = translate_from_bundle(
+            &languages,
+            to_str(original).unwrap_or_default(),
+            &translations,
+            arguments.as_slice(),
+        );
+    }
+
+    #[no_mangle]
+    /// Same as [`slint_translate_from_bundle`] for a string with a plural form.
+    /// `translations` contains the plural forms of each of the `languages`, each language
+    /// being terminated by a null pointer.
+    ///
+    /// Safety: all the strings must be null or nul-terminated UTF-8 strings
+    pub unsafe extern "C" fn slint_translate_from_bundle_with_plural(
+        languages: Slice<*const c_char>,
+        plural_rules: Slice<extern "C" fn(i32) -> usize>,
+        original: *const c_char,
+        original_plural: *const c_char,
+        translations: Slice<*const c_char>,
+        arguments: Slice<SharedString>,
+        n: i32,
+        output: &mut SharedString,
+    ) {
+        let languages =
+            languages.iter().map(|l| to_str(*l).unwrap_or_default()).collect::<Vec<_>>();
+        let plural_rules =
+            plural_rules.iter().map(|rule| rule as &dyn Fn(i32) -> usize).collect::<Vec<_>>();
+        let translations = translations
+            .split(|t| t.is_null())
+            .map(|forms| forms.iter().map(|f| to_str(*f).unwrap_or_default()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let translations = translations.iter().map(|forms| forms.as_slice()).collect::<Vec<_>>();
+        *output = translate_from_bundle_with_plural(
+            &languages,
+            &plural_rules,
+            [to_str(original).unwrap_or_default(), to_str(original_plural).unwrap_or_default()],
+            &translations,
+            arguments.as_slice(),
+            n,
+        );
+    }
 }
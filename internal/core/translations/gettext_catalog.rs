@@ -0,0 +1,172 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Lookup of the translations in the `.mo` files of the language selected with
+//! [`select_language`](super::select_language).
+//!
+//! gettext only takes the language from the environment and the locale of the process, so the
+//! catalogs of the selected language are loaded here instead, from the directory that is bound
+//! to the domain.
+
+use crate::SharedString;
+use i_slint_common::plural_rule::{plural_rule_from_header, PluralRule};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// The messages of a `.mo` file
+#[derive(Default)]
+struct Catalog {
+    /// Map the original string (prefixed by the context and `\u{4}`, as in the `.mo` file) to the
+    /// plural forms of its translation
+    messages: HashMap<String, Vec<String>>,
+    plural_rule: Option<PluralRule>,
+}
+
+impl Catalog {
+    /// Parse the content of a `.mo` file. Returns None if the file is not valid.
+    fn parse(data: &[u8]) -> Option<Self> {
+        let read_u32 = |offset: usize, little_endian: bool| -> Option<u32> {
+            let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+            Some(if little_endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) })
+        };
+        let little_endian = match read_u32(0, true)? {
+            0x950412de => true,
+            0xde120495 => false,
+            _ => return None,
+        };
+        let read_u32 = |offset: usize| read_u32(offset, little_endian);
+        let count = read_u32(8)? as usize;
+        let originals = read_u32(12)? as usize;
+        let translations = read_u32(16)? as usize;
+        // Each table entry is the length and the offset of a string
+        let read_string = |table: usize, index: usize| -> Option<&str> {
+            let len = read_u32(table + index * 8)? as usize;
+            let offset = read_u32(table + index * 8 + 4)? as usize;
+            core::str::from_utf8(data.get(offset..offset + len)?).ok()
+        };
+
+        let mut catalog = Catalog::default();
+        for index in 0..count {
+            // The original of a string with a plural is `singular\0plural`
+            let original = read_string(originals, index)?.split('\0').next().unwrap_or_default();
+            let translated = read_string(translations, index)?;
+            if original.is_empty() {
+                catalog.plural_rule =
+                    plural_rule_from_header(translated).and_then(|r| PluralRule::parse(r).ok());
+            }
+            catalog
+                .messages
+                .insert(original.into(), translated.split('\0').map(Into::into).collect());
+        }
+        Some(catalog)
+    }
+
+    /// Returns the translation of `original`, using the plural form for `n` if `plural` is true
+    fn lookup(&self, original: &str, plural: bool, n: i32) -> Option<&str> {
+        let forms = self.messages.get(original)?;
+        let index = match &self.plural_rule {
+            _ if !plural => 0,
+            Some(rule) => rule.evaluate(n as i64) as usize,
+            // The default rule of gettext, as for the languages of germanic origin
+            None => (n != 1) as usize,
+        };
+        forms.get(index).map(|s| s.as_str()).filter(|s| !s.is_empty())
+    }
+
+    /// Load the catalog of the `domain` for the `language`, from the `directory` bound with
+    /// `bindtextdomain`. Like gettext, also look for the language without the encoding, the
+    /// modifier and the territory: `de_CH.UTF-8@euro` -> `de_CH` -> `de`.
+    fn load(directory: &Path, domain: &str, language: &str) -> Option<Self> {
+        let without_encoding = language.split(['.', '@']).next().unwrap_or_default();
+        let base = without_encoding.split(['_', '-']).next().unwrap_or_default();
+        [language, without_encoding, base].into_iter().filter(|l| !l.is_empty()).find_map(|l| {
+            let path = directory.join(l).join("LC_MESSAGES").join(format!("{domain}.mo"));
+            Self::parse(&std::fs::read(path).ok()?)
+        })
+    }
+}
+
+/// The catalogs that were already loaded, by domain and language. None if the language has no
+/// catalog for the domain. Only the domains bound with [`domain_bound`] have an entry, so that
+/// nothing is remembered about a domain before its directory is known.
+static CATALOGS: Mutex<BTreeMap<String, HashMap<SharedString, Option<Catalog>>>> =
+    Mutex::new(BTreeMap::new());
+
+/// Forget the catalogs of the `domain`, as it was just bound to a (new) directory with
+/// `bindtextdomain`. They are loaded again from that directory on the next translation.
+pub(super) fn domain_bound(domain: &str) {
+    CATALOGS.lock().unwrap().insert(domain.into(), HashMap::new());
+}
+
+/// Translate the string with the `.mo` file of the `language`, or returns None if there is no
+/// translation for it.
+pub(super) fn translate(
+    language: &SharedString,
+    string: &str,
+    ctx: &str,
+    domain: &str,
+    n: i32,
+    plural: &str,
+) -> Option<String> {
+    let mut catalogs = CATALOGS.lock().unwrap();
+    let catalog = catalogs
+        .get_mut(domain)?
+        .entry(language.clone())
+        .or_insert_with(|| {
+            let directory = gettextrs::domain_directory(domain).ok()?;
+            Catalog::load(&directory, domain, language)
+        })
+        .as_ref()?;
+    let original =
+        if ctx.is_empty() { string.to_owned() } else { format!("{}\u{4}{}", ctx, string) };
+    catalog.lookup(&original, !plural.is_empty(), n).map(Into::into)
+}
+
+#[cfg(test)]
+pub(super) fn build_mo_file(entries: &[(&str, &str)]) -> Vec<u8> {
+    // A little-endian `.mo` file without hash table
+    let table_size = entries.len() * 8;
+    let mut strings = Vec::new();
+    let mut originals = Vec::new();
+    let mut translations = Vec::new();
+    let strings_offset = 28 + 2 * table_size;
+    for (original, translated) in entries {
+        for (table, s) in [(&mut originals, original), (&mut translations, translated)] {
+            table.extend((s.len() as u32).to_le_bytes());
+            table.extend(((strings_offset + strings.len()) as u32).to_le_bytes());
+            strings.extend(s.as_bytes());
+            strings.push(0);
+        }
+    }
+    let mut data = Vec::new();
+    for value in [0x950412de, 0, entries.len() as u32, 28, (28 + table_size) as u32, 0, 0] {
+        data.extend(u32::to_le_bytes(value));
+    }
+    data.extend(originals);
+    data.extend(translations);
+    data.extend(strings);
+    data
+}
+
+#[test]
+fn parse_mo_file() {
+    // A string with a context and a plural
+    let data = build_mo_file(&[
+        (
+            "",
+            "Content-Type: text/plain; charset=UTF-8\nPlural-Forms: nplurals=2; plural=(n > 1);\n",
+        ),
+        ("Main\u{4}Hello", "Bonjour"),
+        ("{n} file\0{n} files", "{n} fichier\0{n} fichiers"),
+    ]);
+
+    let catalog = Catalog::parse(&data).unwrap();
+    assert_eq!(catalog.lookup("Main\u{4}Hello", false, 0), Some("Bonjour"));
+    assert_eq!(catalog.lookup("Hello", false, 0), None);
+    assert_eq!(catalog.lookup("{n} file", true, 1), Some("{n} fichier"));
+    assert_eq!(catalog.lookup("{n} file", true, 0), Some("{n} fichier"));
+    assert_eq!(catalog.lookup("{n} file", true, 2), Some("{n} fichiers"));
+    assert!(Catalog::parse(&data[..20]).is_none());
+    assert!(Catalog::parse(b"not a mo file, not a mo file").is_none());
+}
@@ -14,10 +14,13 @@
 use crate::graphics::Point;
 use crate::input::{
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, KeyInputEvent,
-    KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker,
+    KeyboardModifiers, MouseEvent, MouseInputState, TextCursorBlinker, TouchInputState,
+};
+use crate::item_tree::{ItemRc, ItemWeak};
+use crate::items::{
+    ItemRef, MouseCursor, MultiTouchArea, PointerEventButton, PointerEventKind, PopupPlacement,
+    TouchEvent,
 };
-use crate::item_tree::ItemRc;
-use crate::items::{ItemRef, MouseCursor};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, SizeLengths};
 use crate::properties::{Property, PropertyTracker};
 use crate::renderer::Renderer;
@@ -25,6 +28,7 @@
 use alloc::boxed::Box;
 use alloc::rc::{Rc, Weak};
 use core::cell::{Cell, RefCell};
+use core::num::NonZeroU32;
 use core::pin::Pin;
 use euclid::num::Zero;
 use vtable::VRcMapped;
@@ -308,20 +312,75 @@ fn notify(&self) {
 enum PopupWindowLocation {
     /// The popup is rendered in its own top-level window that is know to the windowing system.
     TopLevel(Rc<dyn WindowAdapter>),
-    /// The popup is rendered as an embedded child window at the given position.
-    ChildWindow(LogicalPoint),
+    /// The popup is rendered as an embedded child window.
+    ChildWindow,
 }
 
 /// This structure defines a graphical element that is designed to pop up from the surrounding
 /// UI content, for example to show a context menu.
 struct PopupWindow {
+    /// The id that identifies this popup in [`WindowInner::close_popup`]
+    popup_id: NonZeroU32,
     /// The location defines where the pop up is rendered.
     location: PopupWindowLocation,
+    /// The position of the popup, relative to the window.
+    position: LogicalPoint,
     /// The component that is responsible for providing the popup content.
     component: ComponentRc,
     /// If true, Slint will close the popup after any mouse click within the popup.
     /// Set to false and call close() on the PopupWindow to close it manually.
     close_on_click: bool,
+    /// The item from which the popup was opened. The popup is closed together with the popup
+    /// that contains this item.
+    parent_item: ItemWeak,
+}
+
+/// The data of a [`PopupWindow`] rendered as a child window, needed to forward the events to it.
+struct EmbeddedPopup {
+    popup_id: NonZeroU32,
+    component: ComponentRc,
+    coordinates: LogicalPoint,
+    close_on_click: bool,
+}
+
+/// Returns true if the item is in the given component, or in one of its repeated sub-components.
+fn is_item_in_component(mut item: ItemRc, component: &ComponentRc) -> bool {
+    loop {
+        if vtable::VRc::ptr_eq(item.component(), component) {
+            return true;
+        }
+        match item.parent_item() {
+            Some(parent) => item = parent,
+            None => return false,
+        }
+    }
+}
+
+/// Returns the position of the popup, given in window coordinates, moved so that it stays within
+/// the window: a popup below (or above) the anchor is flipped to the other side of the anchor if
+/// it overflows at the bottom (or at the top), and it is shifted if it still doesn't fit.
+fn place_popup_within_window(
+    popup: LogicalRect,
+    anchor: LogicalRect,
+    window_size: LogicalSize,
+) -> LogicalPoint {
+    let mut y = popup.min_y();
+    if popup.max_y() > window_size.height && popup.min_y() >= anchor.max_y() {
+        // Flip above the anchor, keeping the same distance to it
+        let flipped = anchor.min_y() - (popup.min_y() - anchor.max_y()) - popup.height();
+        if flipped >= 0 as Coord {
+            y = flipped;
+        }
+    } else if popup.min_y() < 0 as Coord && popup.max_y() <= anchor.min_y() {
+        // Flip below the anchor, keeping the same distance to it
+        let flipped = anchor.max_y() + (anchor.min_y() - popup.max_y());
+        if flipped + popup.height() <= window_size.height {
+            y = flipped;
+        }
+    }
+    let y = y.min(window_size.height - popup.height()).max(0 as Coord);
+    let x = popup.min_x().min(window_size.width - popup.width()).max(0 as Coord);
+    LogicalPoint::new(x, y)
 }
 
 #[pin_project::pin_project]
@@ -346,6 +405,7 @@ pub struct WindowInner {
     /// When the window is visible, keep a strong reference
     strong_component_ref: RefCell<Option<ComponentRc>>,
     mouse_input_state: Cell<MouseInputState>,
+    touch_input_state: Cell<TouchInputState>,
     pub(crate) modifiers: Cell<InternalKeyboardModifierState>,
 
     /// itemRC will retrieve on wasms
@@ -353,7 +413,9 @@ pub struct WindowInner {
     cursor_blinker: RefCell<pin_weak::rc::PinWeak<crate::input::TextCursorBlinker>>,
 
     pinned_fields: Pin<Box<WindowPinnedFields>>,
-    active_popup: RefCell<Option<PopupWindow>>,
+    /// The stack of the popups that are currently shown, the last one being the topmost
+    active_popups: RefCell<Vec<PopupWindow>>,
+    next_popup_id: Cell<NonZeroU32>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
 }
@@ -392,6 +454,7 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             component: Default::default(),
             strong_component_ref: Default::default(),
             mouse_input_state: Default::default(),
+            touch_input_state: Default::default(),
             modifiers: Default::default(),
             pinned_fields: Box::pin(WindowPinnedFields {
                 redraw_tracker,
@@ -405,7 +468,8 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             }),
             focus_item: Default::default(),
             cursor_blinker: Default::default(),
-            active_popup: Default::default(),
+            active_popups: Default::default(),
+            next_popup_id: Cell::new(NonZeroU32::MIN),
             close_requested: Default::default(),
             click_state: ClickState::default(),
         }
@@ -414,9 +478,10 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
     /// Associates this window with the specified component. Further event handling and rendering, etc. will be
     /// done with that component.
     pub fn set_component(&self, component: &ComponentRc) {
-        self.close_popup();
+        self.close_all_popups();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
+        self.touch_input_state.replace(Default::default());
         self.modifiers.replace(Default::default());
         self.component.replace(ComponentRc::downgrade(component));
         self.pinned_fields.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
@@ -466,34 +531,26 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         // handle multiple press release
         event = self.click_state.check_repeat(event);
 
-        let close_popup_after_click = self.close_popup_after_click();
-
-        let embedded_popup_component =
-            self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
-                PopupWindowLocation::TopLevel(_) => None,
-                PopupWindowLocation::ChildWindow(coordinates) => {
-                    Some((popup.component.clone(), coordinates))
+        if let MouseEvent::Pressed { position, .. } = &event {
+            // close the embedded popups, from the topmost one, as long as the press is outside of them
+            while let Some((popup_id, geom)) = self.top_embedded_popup().map(|popup| {
+                let geom = ComponentRc::borrow_pin(&popup.component).as_ref().item_geometry(0);
+                (popup.popup_id, geom.translate(popup.coordinates.to_vector()))
+            }) {
+                if geom.contains(*position) {
+                    break;
                 }
-            });
+                self.close_popup(popup_id);
+            }
+        }
 
-        let component = embedded_popup_component
-            .as_ref()
-            .and_then(|(popup_component, coordinates)| {
-                event.translate(-coordinates.to_vector());
-
-                if let MouseEvent::Pressed { position, .. } = &event {
-                    // close the popup if one press outside the popup
-                    let geom = ComponentRc::borrow_pin(popup_component).as_ref().item_geometry(0);
-                    if !geom.contains(*position) {
-                        self.close_popup();
-                        return None;
-                    }
-                }
-                Some(popup_component.clone())
-            })
-            .or_else(|| self.component.borrow().upgrade());
+        // The events go to the topmost popup, if any
+        let embedded_popup = self.top_embedded_popup();
 
-        let component = if let Some(component) = component {
+        let component = if let Some(popup) = &embedded_popup {
+            event.translate(-popup.coordinates.to_vector());
+            popup.component.clone()
+        } else if let Some(component) = self.component.borrow().upgrade() {
             component
         } else {
             return;
@@ -506,12 +563,112 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
             self.mouse_input_state.take(),
         ));
 
-        if embedded_popup_component.is_some()
-            && close_popup_after_click
-            && matches!(event, MouseEvent::Released { .. })
+        if let Some(popup) = embedded_popup {
+            if popup.close_on_click && matches!(event, MouseEvent::Released { .. }) {
+                self.close_popup(popup.popup_id);
+            }
+        }
+    }
+
+    /// Returns the topmost popup if it is rendered as a child window of this window
+    fn top_embedded_popup(&self) -> Option<EmbeddedPopup> {
+        self.active_popups.borrow().last().and_then(|popup| match popup.location {
+            PopupWindowLocation::TopLevel(_) => None,
+            PopupWindowLocation::ChildWindow => Some(EmbeddedPopup {
+                popup_id: popup.popup_id,
+                component: popup.component.clone(),
+                coordinates: popup.position,
+                close_on_click: popup.close_on_click,
+            }),
+        })
+    }
+
+    /// Receive a touch event and pass it to the items of the component.
+    ///
+    /// The first finger is also dispatched as mouse events, so that all the items can be used
+    /// with touch. All the fingers are reported to the `MultiTouchArea` under the first finger.
+    ///
+    /// Arguments:
+    /// * `id`: Identifies the finger, as given by the windowing system.
+    /// * `kind`: Whether the finger was pressed, moved, released or cancelled.
+    /// * `position`: The position of the finger in window logical coordinates.
+    pub fn process_touch_input(&self, id: u64, kind: PointerEventKind, position: LogicalPoint) {
+        let mut touch_input_state = self.touch_input_state.take();
+        let Some((touch_id, position)) = touch_input_state.update(id, kind, position) else {
+            self.touch_input_state.set(touch_input_state);
+            return;
+        };
+
+        if touch_input_state.primary == Some(id) {
+            let event = match kind {
+                PointerEventKind::Down => MouseEvent::Pressed {
+                    position,
+                    button: PointerEventButton::Left,
+                    click_count: 0,
+                },
+                PointerEventKind::Move => MouseEvent::Moved { position },
+                PointerEventKind::Up => MouseEvent::Released {
+                    position,
+                    button: PointerEventButton::Left,
+                    click_count: 0,
+                },
+                PointerEventKind::Cancel => MouseEvent::Exit,
+            };
+            // The items can check with `is_dispatching_touch` that the mouse event is a finger
+            self.touch_input_state.set(touch_input_state);
+            self.process_mouse_input(event);
+            touch_input_state = self.touch_input_state.take();
+            if matches!(kind, PointerEventKind::Up | PointerEventKind::Cancel) {
+                touch_input_state.primary = None;
+            }
+        }
+
+        let send_touch_event = |target: &ItemRc, id: i32, kind: PointerEventKind, position| {
+            if let Some(area) = target.downcast::<MultiTouchArea>() {
+                let origin = target.map_to_window(target.geometry().origin);
+                let position: LogicalPoint = position - origin.to_vector();
+                area.as_pin_ref().touch_event(TouchEvent {
+                    id,
+                    kind,
+                    x: position.x,
+                    y: position.y,
+                });
+            }
+        };
+
+        let is_finger_down = matches!(kind, PointerEventKind::Down | PointerEventKind::Move);
+        if let Some(target) = touch_input_state.target.as_ref().and_then(|t| t.upgrade()) {
+            send_touch_event(&target, touch_id, kind, position);
+        } else if let Some(target) =
+            is_finger_down.then(|| self.with_mouse_input_state(|s| s.multi_touch_area())).flatten()
         {
-            self.close_popup();
+            // The area is found after the fingers touched the screen, for example because the
+            // press was delayed by a Flickable: report all the fingers that touch it.
+            touch_input_state.target = Some(target.downgrade());
+            for (id, position) in touch_input_state.points() {
+                send_touch_event(&target, id, PointerEventKind::Down, position);
+            }
+        }
+
+        if touch_input_state.points().next().is_none() {
+            touch_input_state.target = None;
         }
+        self.touch_input_state.set(touch_input_state);
+    }
+
+    /// Returns true if the mouse event being processed comes from a finger touching the screen
+    pub(crate) fn is_dispatching_touch(&self) -> bool {
+        let touch_input_state = self.touch_input_state.take();
+        let result = touch_input_state.primary.is_some();
+        self.touch_input_state.set(touch_input_state);
+        result
+    }
+
+    fn with_mouse_input_state<R>(&self, f: impl FnOnce(&MouseInputState) -> R) -> R {
+        let mouse_input_state = self.mouse_input_state.take();
+        let result = f(&mouse_input_state);
+        self.mouse_input_state.set(mouse_input_state);
+        result
     }
 
     /// Called by the input code's internal timer to send an event that was delayed
@@ -737,22 +894,26 @@ pub fn draw_contents<T>(
         let draw_fn = || {
             let component_rc = self.try_component()?;
 
-            let popup_component =
-                self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
+            let popup_components = self
+                .active_popups
+                .borrow()
+                .iter()
+                .filter_map(|popup| match popup.location {
                     PopupWindowLocation::TopLevel(_) => None,
-                    PopupWindowLocation::ChildWindow(coordinates) => {
-                        Some((popup.component.clone(), coordinates))
+                    PopupWindowLocation::ChildWindow => {
+                        Some((popup.component.clone(), popup.position))
                     }
-                });
+                })
+                .collect::<Vec<_>>();
 
-            Some(if let Some((popup_component, popup_coordinates)) = popup_component {
-                render_components(&[
-                    (&component_rc, LogicalPoint::default()),
-                    (&popup_component, popup_coordinates),
-                ])
-            } else {
-                render_components(&[(&component_rc, LogicalPoint::default())])
-            })
+            let components = core::iter::once((&component_rc, LogicalPoint::default()))
+                .chain(
+                    popup_components
+                        .iter()
+                        .map(|(component, coordinates)| (component, *coordinates)),
+                )
+                .collect::<Vec<_>>();
+            Some(render_components(&components))
         };
 
         self.pinned_fields
@@ -793,17 +954,34 @@ pub fn dark_color_scheme(&self) -> bool {
             .map_or(false, |x| x.dark_color_scheme())
     }
 
-    /// Show a popup at the given position relative to the item
+    /// Show a popup at the given position relative to the item, on top of the popups that are
+    /// already shown. The placement decides whether the popup may be moved to fit in the window.
+    ///
+    /// Returns the id to pass to [`Self::close_popup()`] to close that popup.
     pub fn show_popup(
         &self,
         popup_componentrc: &ComponentRc,
         position: Point,
         close_on_click: bool,
+        placement: PopupPlacement,
         parent_item: &ItemRc,
-    ) {
-        let position = parent_item.map_to_window(
-            parent_item.geometry().origin + LogicalPoint::from_untyped(position).to_vector(),
+    ) -> NonZeroU32 {
+        let parent_geometry = parent_item.geometry();
+        let mut anchor = LogicalRect::new(
+            parent_item.map_to_window(parent_geometry.origin),
+            parent_geometry.size,
         );
+        // When the parent item is itself in a popup, its position is relative to that popup
+        if let Some(parent_popup_position) = self
+            .active_popups
+            .borrow()
+            .iter()
+            .find(|popup| is_item_in_component(parent_item.clone(), &popup.component))
+            .map(|popup| popup.position)
+        {
+            anchor = anchor.translate(parent_popup_position.to_vector());
+        }
+        let position = anchor.origin + LogicalPoint::from_untyped(position).to_vector();
         let popup_component = ComponentRc::borrow_pin(popup_componentrc);
         let popup_root = popup_component.as_ref().get_item_ref(0);
 
@@ -831,6 +1009,16 @@ pub fn show_popup(
 
         let size = LogicalSize::from_lengths(w, h);
 
+        let position = match placement {
+            PopupPlacement::Fixed => position,
+            PopupPlacement::Auto => {
+                let window_size = self.try_component().map_or(LogicalSize::zero(), |component| {
+                    ComponentRc::borrow_pin(&component).as_ref().item_geometry(0).size
+                });
+                place_popup_within_window(LogicalRect::new(position, size), anchor, window_size)
+            }
+        };
+
         if let Some(window_item) = ItemRef::downcast_pin(popup_root) {
             let width_property =
                 crate::items::WindowItem::FIELD_OFFSETS.width.apply_pin(window_item);
@@ -847,7 +1035,7 @@ pub fn show_popup(
         {
             None => {
                 self.window_adapter().request_redraw();
-                PopupWindowLocation::ChildWindow(position)
+                PopupWindowLocation::ChildWindow
             }
 
             Some(window_adapter) => {
@@ -856,37 +1044,83 @@ pub fn show_popup(
             }
         };
 
-        self.active_popup.replace(Some(PopupWindow {
+        let popup_id = self.next_popup_id.get();
+        self.next_popup_id.set(popup_id.checked_add(1).unwrap_or(NonZeroU32::MIN));
+
+        self.active_popups.borrow_mut().push(PopupWindow {
+            popup_id,
             location,
+            position,
             component: popup_componentrc.clone(),
             close_on_click,
-        }));
-    }
-
-    /// Removes any active popup.
-    /// TODO: this function should take a component ref as parameter, to close a specific popup - i.e. when popup menus create a hierarchy of popups.
-    pub fn close_popup(&self) {
-        if let Some(current_popup) = self.active_popup.replace(None) {
-            if let PopupWindowLocation::ChildWindow(offset) = current_popup.location {
-                // Refresh the area that was previously covered by the popup.
-                let popup_region = crate::properties::evaluate_no_tracking(|| {
-                    let popup_component = ComponentRc::borrow_pin(&current_popup.component);
-                    popup_component.as_ref().item_geometry(0)
-                })
-                .translate(offset.to_vector());
+            parent_item: parent_item.downgrade(),
+        });
+        popup_id
+    }
 
-                if !popup_region.is_empty() {
-                    let window_adapter = self.window_adapter();
-                    window_adapter.renderer().mark_dirty_region(popup_region.to_box2d());
-                    window_adapter.request_redraw();
-                }
+    /// Closes the popup with the given id, as returned by [`Self::show_popup()`], as well as
+    /// the popups that were opened from within it. The other popups stay open.
+    /// Does nothing if that popup was already closed.
+    pub fn close_popup(&self, popup_id: NonZeroU32) {
+        let mut active_popups = self.active_popups.borrow_mut();
+        let Some(index) = active_popups.iter().position(|popup| popup.popup_id == popup_id) else {
+            return;
+        };
+        let popup = active_popups.remove(index);
+        let nested_popups = active_popups
+            .iter()
+            .filter(|nested| {
+                nested
+                    .parent_item
+                    .upgrade()
+                    .map_or(true, |item| is_item_in_component(item, &popup.component))
+            })
+            .map(|nested| nested.popup_id)
+            .collect::<Vec<_>>();
+        drop(active_popups);
+
+        self.discard_popup(popup);
+        for nested in nested_popups {
+            self.close_popup(nested);
+        }
+    }
+
+    /// Closes the topmost popup, if any.
+    pub fn close_top_popup(&self) {
+        let top_popup = self.active_popups.borrow().last().map(|popup| popup.popup_id);
+        if let Some(popup_id) = top_popup {
+            self.close_popup(popup_id);
+        }
+    }
+
+    /// Closes all the popups of this window.
+    pub fn close_all_popups(&self) {
+        let popups = core::mem::take(&mut *self.active_popups.borrow_mut());
+        for popup in popups.into_iter().rev() {
+            self.discard_popup(popup);
+        }
+    }
+
+    fn discard_popup(&self, current_popup: PopupWindow) {
+        if matches!(current_popup.location, PopupWindowLocation::ChildWindow) {
+            // Refresh the area that was previously covered by the popup.
+            let popup_region = crate::properties::evaluate_no_tracking(|| {
+                let popup_component = ComponentRc::borrow_pin(&current_popup.component);
+                popup_component.as_ref().item_geometry(0)
+            })
+            .translate(current_popup.position.to_vector());
+
+            if !popup_region.is_empty() {
+                let window_adapter = self.window_adapter();
+                window_adapter.renderer().mark_dirty_region(popup_region.to_box2d());
+                window_adapter.request_redraw();
             }
         }
     }
 
-    /// Returns true if the currently active popup is configured to close on click. None if there is no active popup.
+    /// Returns true if the topmost popup is configured to close on click. False if there is no active popup.
     pub fn close_popup_after_click(&self) -> bool {
-        self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_click)
+        self.active_popups.borrow().last().is_some_and(|popup| popup.close_on_click)
     }
 
     /// Returns the scale factor set on the window, as provided by the windowing system.
@@ -1106,28 +1340,31 @@ pub enum GraphicsAPI {
         WindowInner::from_pub(window_adapter.window()).set_component(component)
     }
 
-    /// Show a popup.
+    /// Show a popup and return its id, to be passed to slint_windowrc_close_popup.
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_show_popup(
         handle: *const WindowAdapterRcOpaque,
         popup: &ComponentRc,
         position: crate::graphics::Point,
         close_on_click: bool,
+        placement: PopupPlacement,
         parent_item: &ItemRc,
-    ) {
+    ) -> u32 {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).show_popup(
-            popup,
-            position,
-            close_on_click,
-            parent_item,
-        );
+        WindowInner::from_pub(window_adapter.window())
+            .show_popup(popup, position, close_on_click, placement, parent_item)
+            .get()
     }
-    /// Close the current popup
+    /// Close the popup with the given id, as returned by slint_windowrc_show_popup.
     #[no_mangle]
-    pub unsafe extern "C" fn slint_windowrc_close_popup(handle: *const WindowAdapterRcOpaque) {
+    pub unsafe extern "C" fn slint_windowrc_close_popup(
+        handle: *const WindowAdapterRcOpaque,
+        popup_id: u32,
+    ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).close_popup();
+        if let Some(popup_id) = NonZeroU32::new(popup_id) {
+            WindowInner::from_pub(window_adapter.window()).close_popup(popup_id);
+        }
     }
 
     /// C binding to the set_rendering_notifier() API of Window
@@ -1351,3 +1588,36 @@ fn test_empty_window() {
     assert_eq!(region.bounding_box_size(), PhysicalSize::default());
     assert_eq!(region.bounding_box_origin(), PhysicalPosition::default());
 }
+
+#[test]
+fn test_place_popup_within_window() {
+    let window_size = LogicalSize::new(100 as Coord, 100 as Coord);
+    // The anchor is a 10x10 item at (10, anchor_y)
+    let place = |anchor_y: i32, x: i32, y: i32, w: i32, h: i32| {
+        let anchor = LogicalRect::new(
+            LogicalPoint::new(10 as Coord, anchor_y as Coord),
+            LogicalSize::new(10 as Coord, 10 as Coord),
+        );
+        let popup = LogicalRect::new(
+            LogicalPoint::new(x as Coord, y as Coord),
+            LogicalSize::new(w as Coord, h as Coord),
+        );
+        let p = place_popup_within_window(popup, anchor, window_size);
+        (p.x as i32, p.y as i32)
+    };
+    // Fits below the anchor
+    assert_eq!(place(60, 10, 72, 30, 20), (10, 72));
+    // Flipped above the anchor, with the same gap of 2
+    assert_eq!(place(60, 10, 72, 30, 40), (10, 18));
+    // Shifted horizontally
+    assert_eq!(place(60, 80, 72, 30, 20), (70, 72));
+    assert_eq!(place(60, -5, 72, 30, 20), (0, 72));
+    // Overflowing at the top: flipped below the anchor
+    assert_eq!(place(20, 10, -22, 30, 40), (10, 32));
+    // Overlapping the anchor: only shifted
+    assert_eq!(place(60, 10, 50, 30, 60), (10, 40));
+    // Doesn't fit on either side: shifted up as much as needed
+    assert_eq!(place(60, 10, 70, 30, 80), (10, 20));
+    // Bigger than the window
+    assert_eq!(place(60, 10, 70, 130, 130), (0, 0));
+}
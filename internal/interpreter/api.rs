@@ -545,6 +545,15 @@ pub fn set_translation_domain(&mut self, domain: String) {
         self.config.translation_domain = Some(domain);
     }
 
+    /// Read the translations from the `.po` files in the `path` directory when compiling, instead
+    /// of looking them up with gettext at run-time.
+    ///
+    /// The files must follow the gettext layout: `<path>/<language>/LC_MESSAGES/<domain>.po`,
+    /// where the domain is set with [`Self::set_translation_domain()`].
+    pub fn set_bundled_translations(&mut self, path: PathBuf) {
+        self.config.translation_path_bundle = Some(path);
+    }
+
     /// Sets the callback that will be invoked when loading imported .slint files. The specified
     /// `file_loader_callback` parameter will be called with a canonical file path as argument
     /// and is expected to return a future that, when resolved, provides the source code of the
@@ -1635,6 +1644,61 @@ fn check_model(val: Value, r: &[f64]) {
     check_model(instance.get_property("prop").unwrap(), &[]);
 }
 
+#[test]
+fn bundled_translations() {
+    i_slint_backend_testing::init();
+    let dir =
+        std::env::temp_dir().join(format!("slint-bundled-translations-{}", std::process::id()));
+    let po_dir = dir.join("fr").join("LC_MESSAGES");
+    std::fs::create_dir_all(&po_dir).unwrap();
+    std::fs::write(
+        po_dir.join("test-domain.po"),
+        r#"
+msgid ""
+msgstr "Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+msgctxt "Dummy"
+msgid "Hello {}"
+msgstr "Bonjour {}"
+
+msgctxt "Dummy"
+msgid "{n} file"
+msgid_plural "{n} files"
+msgstr[0] "{n} fichier"
+msgstr[1] "{n} fichiers"
+"#,
+    )
+    .unwrap();
+
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_translation_domain("test-domain".into());
+    compiler.set_bundled_translations(dir.clone());
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    export component Dummy {
+        in property <int> count: 0;
+        out property <string> hello: @tr("Hello {}", "World");
+        out property <string> files: @tr("{n} file" | "{n} files" % count);
+    }"#
+            .into(),
+            "".into(),
+        ),
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    let instance = comp_def.unwrap().create().unwrap();
+
+    crate::select_language("en");
+    assert_eq!(instance.get_property("hello"), Ok(Value::String("Hello World".into())));
+    assert_eq!(instance.get_property("files"), Ok(Value::String("0 files".into())));
+    crate::select_language("fr");
+    assert_eq!(instance.get_property("hello"), Ok(Value::String("Bonjour World".into())));
+    assert_eq!(instance.get_property("files"), Ok(Value::String("0 fichier".into())));
+    instance.set_property("count", Value::Number(2.)).unwrap();
+    assert_eq!(instance.get_property("files"), Ok(Value::String("2 fichiers".into())));
+    crate::select_language("");
+}
+
 #[test]
 fn lang_type_to_value_type() {
     use std::collections::BTreeMap;
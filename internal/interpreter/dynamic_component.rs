@@ -5,7 +5,9 @@
 
 use crate::{api::Value, dynamic_type, eval};
 
+use core::cell::RefCell;
 use core::convert::TryInto;
+use core::num::NonZeroU32;
 use core::ptr::NonNull;
 use dynamic_type::{Instance, InstanceBox};
 use i_slint_compiler::expression_tree::{Expression, NamedReference};
@@ -24,12 +26,12 @@
     VisitChildrenResult,
 };
 use i_slint_core::items::{AccessibleRole, ItemRef, ItemVTable, PropertyAnimation};
-use i_slint_core::layout::{BoxLayoutCellData, LayoutInfo, Orientation};
+use i_slint_core::layout::{BoxLayoutCellData, FlexboxLayoutCellData, LayoutInfo, Orientation};
 use i_slint_core::lengths::{LogicalLength, LogicalRect};
 use i_slint_core::model::RepeatedComponent;
 use i_slint_core::model::Repeater;
 use i_slint_core::platform::PlatformError;
-use i_slint_core::properties::InterpolatedPropertyValue;
+use i_slint_core::properties::{ChangeTracker, InterpolatedPropertyValue};
 use i_slint_core::rtti::{self, AnimatedBindingKind, FieldOffset, PropertyInfo};
 use i_slint_core::slice::Slice;
 use i_slint_core::window::{WindowAdapterRc, WindowInner};
@@ -155,6 +157,26 @@ fn listview_layout(
     fn box_layout_data(self: Pin<&Self>, o: Orientation) -> BoxLayoutCellData {
         BoxLayoutCellData { constraint: self.borrow().as_ref().layout_info(o) }
     }
+
+    fn flexbox_layout_data(self: Pin<&Self>, o: Orientation) -> FlexboxLayoutCellData {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let mut cell = FlexboxLayoutCellData {
+            constraint: self.borrow().as_ref().layout_info(o),
+            ..Default::default()
+        };
+        crate::eval_layout::fill_flex_properties(
+            &mut cell,
+            &s.component_type.original.root_constraints.borrow(),
+            &|nr: &NamedReference| -> f32 {
+                eval::load_property(s.borrow_instance(), &nr.element(), nr.name())
+                    .unwrap()
+                    .try_into()
+                    .unwrap()
+            },
+        );
+        cell
+    }
 }
 
 impl Component for ErasedComponentBox {
@@ -228,6 +250,10 @@ fn accessible_string_property(
         self.borrow().as_ref().accessible_string_property(index, what, result)
     }
 
+    fn item_element_infos(self: Pin<&Self>, index: u32, result: &mut SharedString) -> bool {
+        self.borrow().as_ref().item_element_infos(index, result)
+    }
+
     fn window_adapter(self: Pin<&Self>, do_create: bool, result: &mut Option<WindowAdapterRc>) {
         self.borrow().as_ref().window_adapter(do_create, result);
     }
@@ -261,6 +287,9 @@ pub(crate) struct ComponentExtraData {
     pub(crate) embedding_position: OnceCell<(ComponentWeak, u32)>,
     // resource id -> file path
     pub(crate) embedded_file_resources: OnceCell<HashMap<usize, String>>,
+    pub(crate) change_trackers: OnceCell<Vec<ChangeTracker>>,
+    // index in the popup_windows of the component -> id of the popup while it is shown
+    pub(crate) popup_ids: RefCell<HashMap<usize, NonZeroU32>>,
     #[cfg(target_arch = "wasm32")]
     pub(crate) canvas_id: OnceCell<String>,
 }
@@ -810,6 +839,7 @@ pub(crate) fn generate_component<'id>(
                 rtti_for::<FocusScope>(),
                 rtti_for::<Path>(),
                 rtti_for::<Flickable>(),
+                rtti_for::<MultiTouchArea>(),
                 rtti_for::<WindowItem>(),
                 rtti_for::<TextInput>(),
                 rtti_for::<Clip>(),
@@ -1131,6 +1161,7 @@ macro_rules! match_enum_type {
         item_geometry,
         accessible_role,
         accessible_string_property,
+        item_element_infos,
         window_adapter,
         drop_in_place,
         dealloc,
@@ -1547,6 +1578,44 @@ pub fn run_setup_code(&self) {
         generativity::make_guard!(guard);
         let compo_box = self.unerase(guard);
         let instance_ref = compo_box.borrow_instance();
+        let self_weak = instance_ref.self_weak().get().unwrap();
+        let mut change_trackers = Vec::new();
+        object_tree::recurse_elem(
+            &self.0.component_type.original.root_element,
+            &(),
+            &mut |elem, _| {
+                for (nr, handler) in &elem.borrow().change_callbacks {
+                    let tracker = ChangeTracker::default();
+                    let nr = nr.clone();
+                    let handler = handler.clone();
+                    tracker.init(
+                        self_weak.clone(),
+                        move |self_weak| {
+                            let Some(self_rc) = self_weak.upgrade() else { return Value::Void };
+                            generativity::make_guard!(guard);
+                            let self_ = self_rc.unerase(guard);
+                            eval::load_property(self_.borrow_instance(), &nr.element(), nr.name())
+                                .unwrap()
+                        },
+                        move |self_weak, _| {
+                            let Some(self_rc) = self_weak.upgrade() else { return };
+                            generativity::make_guard!(guard);
+                            let self_ = self_rc.unerase(guard);
+                            eval::eval_expression(
+                                &handler,
+                                &mut eval::EvalLocalContext::from_component_instance(
+                                    self_.borrow_instance(),
+                                ),
+                            );
+                        },
+                    );
+                    change_trackers.push(tracker);
+                }
+            },
+        );
+        let extra_data = instance_ref.component_type.extra_data_offset.apply(instance_ref.as_ref());
+        extra_data.change_trackers.set(change_trackers).ok();
+
         for extra_init_code in self.0.component_type.original.init_code.borrow().iter() {
             eval::eval_expression(
                 extra_init_code,
@@ -1794,6 +1863,21 @@ extern "C" fn accessible_role(component: ComponentRefPin, item_index: u32) -> Ac
     }
 }
 
+extern "C" fn item_element_infos(
+    component: ComponentRefPin,
+    item_index: u32,
+    result: &mut SharedString,
+) -> bool {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let e = instance_ref.component_type.original_elements[item_index as usize].borrow();
+    if e.qualified_ids.is_empty() {
+        return false;
+    }
+    *result = e.qualified_ids.join("\n").into();
+    true
+}
+
 extern "C" fn accessible_string_property(
     component: ComponentRefPin,
     item_index: u32,
@@ -2006,7 +2090,7 @@ pub fn show_popup(
     parent_comp: ComponentRefPin,
     parent_window_adapter: WindowAdapterRc,
     parent_item: &ItemRc,
-) {
+) -> NonZeroU32 {
     generativity::make_guard!(guard);
     // FIXME: we should compile once and keep the cached compiled component
     let compiled = generate_component(&popup.component, guard);
@@ -2018,10 +2102,17 @@ pub fn show_popup(
         Default::default(),
     );
     inst.run_setup_code();
+    let placement = Value::EnumerationValue(
+        popup.placement.enumeration.name.clone(),
+        popup.placement.to_string(),
+    )
+    .try_into()
+    .unwrap();
     WindowInner::from_pub(parent_window_adapter.window()).show_popup(
         &vtable::VRc::into_dyn(inst),
         pos,
         close_on_click,
+        placement,
         parent_item,
-    );
+    )
 }
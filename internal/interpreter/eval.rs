@@ -346,6 +346,22 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::EaseInElastic => corelib::animations::EasingCurve::EaseInElastic,
+            EasingCurve::EaseOutElastic => corelib::animations::EasingCurve::EaseOutElastic,
+            EasingCurve::EaseInOutElastic => corelib::animations::EasingCurve::EaseInOutElastic,
+            EasingCurve::EaseInBounce => corelib::animations::EasingCurve::EaseInBounce,
+            EasingCurve::EaseOutBounce => corelib::animations::EasingCurve::EaseOutBounce,
+            EasingCurve::EaseInOutBounce => corelib::animations::EasingCurve::EaseInOutBounce,
+            EasingCurve::Steps { count, jump_start } => {
+                corelib::animations::EasingCurve::Steps { count: *count, jump_start: *jump_start }
+            }
+            EasingCurve::Spring { mass, stiffness, damping } => {
+                corelib::animations::EasingCurve::Spring {
+                    mass: *mass,
+                    stiffness: *stiffness,
+                    damping: *damping,
+                }
+            }
         }),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
@@ -539,18 +555,10 @@ fn call_builtin_function(
             };
             if let Expression::ElementReference(popup_window) = &arguments[0] {
                 let popup_window = popup_window.upgrade().unwrap();
-                let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
-                let parent_component = pop_comp
-                    .parent_element
-                    .upgrade()
-                    .unwrap()
-                    .borrow()
-                    .enclosing_component
-                    .upgrade()
-                    .unwrap();
+                let parent_component = popup_parent_component(&popup_window);
                 let popup_list = parent_component.popup_windows.borrow();
-                let popup =
-                    popup_list.iter().find(|p| Rc::ptr_eq(&p.component, &pop_comp)).unwrap();
+                let popup_index = popup_index(&popup_list, &popup_window);
+                let popup = &popup_list[popup_index];
                 let x = load_property_helper(
                     local_context.component_instance,
                     &popup.x.element(),
@@ -576,7 +584,17 @@ fn call_builtin_function(
                     parent_item_info.item_index(),
                 );
 
-                crate::dynamic_component::show_popup(
+                let popup_ids = &enclosing_component
+                    .component_type
+                    .extra_data_offset
+                    .apply(enclosing_component.as_ref())
+                    .popup_ids;
+                let current_id = popup_ids.borrow_mut().remove(&popup_index);
+                if let Some(current_id) = current_id {
+                    component.access_window(|window| window.close_popup(current_id));
+                }
+
+                let popup_id = crate::dynamic_component::show_popup(
                     popup,
                     i_slint_core::graphics::Point::new(
                         x.try_into().unwrap(),
@@ -587,22 +605,48 @@ fn call_builtin_function(
                     component.window_adapter(),
                     &parent_item,
                 );
+                popup_ids.borrow_mut().insert(popup_index, popup_id);
                 Value::Void
             } else {
-                panic!("internal error: argument to SetFocusItem must be an element")
+                panic!("internal error: argument to ShowPopupWindow must be an element")
             }
         }
         BuiltinFunction::ClosePopupWindow => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ClosePopupWindow")
+            }
             let component = match local_context.component_instance {
                 ComponentInstance::InstanceRef(c) => c,
                 ComponentInstance::GlobalComponent(_) => {
-                    panic!("Cannot show popup from a global component")
+                    panic!("Cannot close popup from a global component")
                 }
             };
+            if let Expression::ElementReference(popup_window) = &arguments[0] {
+                let popup_window = popup_window.upgrade().unwrap();
+                let parent_component = popup_parent_component(&popup_window);
+                let popup_list = parent_component.popup_windows.borrow();
+                let popup_index = popup_index(&popup_list, &popup_window);
 
-            component.access_window(|window| window.close_popup());
-
-            Value::Void
+                generativity::make_guard!(guard);
+                let enclosing_component = enclosing_component_for_element(
+                    &popup_list[popup_index].parent_element,
+                    component,
+                    guard,
+                );
+                let current_id = enclosing_component
+                    .component_type
+                    .extra_data_offset
+                    .apply(enclosing_component.as_ref())
+                    .popup_ids
+                    .borrow_mut()
+                    .remove(&popup_index);
+                if let Some(current_id) = current_id {
+                    component.access_window(|window| window.close_popup(current_id));
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to ClosePopupWindow must be an element")
+            }
         }
         BuiltinFunction::ItemMemberFunction(name) => {
             if arguments.len() != 1 {
@@ -937,18 +981,82 @@ fn from_index(&self, index: usize) -> Option<SharedString> {
                     self.0.row_data(index).map(|x| x.try_into().unwrap())
                 }
             }
+            let n: i32 = eval_expression(&arguments[4], local_context).try_into().unwrap();
+            let plural: SharedString =
+                eval_expression(&arguments[5], local_context).try_into().unwrap();
+            let translations = match local_context.component_instance {
+                ComponentInstance::InstanceRef(instance) => instance
+                    .toplevel_instance()
+                    .component_type
+                    .original
+                    .translations
+                    .borrow()
+                    .clone(),
+                ComponentInstance::GlobalComponent(_) => None,
+            };
+            if let Some(translations) = translations {
+                return translate_from_bundle(
+                    &translations,
+                    &original,
+                    &context,
+                    &plural,
+                    &StringModelWrapper(args),
+                    n,
+                );
+            }
             Value::String(corelib::translations::translate(
                 &original,
                 &context,
                 &domain,
                 &StringModelWrapper(args),
-                eval_expression(&arguments[4], local_context).try_into().unwrap(),
-                &SharedString::try_from(eval_expression(&arguments[5], local_context)).unwrap(),
+                n,
+                &plural,
             ))
         }
     }
 }
 
+/// Translate a string with the translations bundled by the compiler
+fn translate_from_bundle(
+    translations: &i_slint_compiler::translations::Translations,
+    original: &str,
+    context: &str,
+    plural: &str,
+    args: &impl corelib::translations::FormatArgs,
+    n: i32,
+) -> Value {
+    let languages = translations.languages.iter().map(|l| l.as_str()).collect::<Vec<_>>();
+    let translated = translations.lookup(context, original).unwrap_or_default();
+    Value::String(if plural.is_empty() {
+        let translated = translated
+            .iter()
+            .map(|forms| forms.first().map(|s| s.as_str()).filter(|s| !s.is_empty()))
+            .collect::<Vec<_>>();
+        corelib::translations::translate_from_bundle(&languages, original, &translated, args)
+    } else {
+        let plural_rules = translations
+            .plural_rules
+            .iter()
+            .map(|rule| move |n: i32| rule.evaluate(n as i64) as usize)
+            .collect::<Vec<_>>();
+        let plural_rules =
+            plural_rules.iter().map(|rule| rule as &dyn Fn(i32) -> usize).collect::<Vec<_>>();
+        let translated = translated
+            .iter()
+            .map(|forms| forms.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let translated = translated.iter().map(|forms| forms.as_slice()).collect::<Vec<_>>();
+        corelib::translations::translate_from_bundle_with_plural(
+            &languages,
+            &plural_rules,
+            [original, plural],
+            &translated,
+            args,
+            n,
+        )
+    })
+}
+
 fn eval_assignment(lhs: &Expression, op: char, rhs: Value, local_context: &mut EvalLocalContext) {
     let eval = |lhs| match (lhs, &rhs, op) {
         (Value::String(ref mut a), Value::String(b), '+') => {
@@ -1352,6 +1460,25 @@ pub fn enclosing_component_for_element<'a, 'old_id, 'new_id>(
     }
 }
 
+/// Returns the component that contains the given PopupWindow element in its `popup_windows`
+fn popup_parent_component(
+    popup_window: &ElementRc,
+) -> Rc<i_slint_compiler::object_tree::Component> {
+    let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+    let parent_element = pop_comp.parent_element.upgrade().unwrap();
+    let parent_element = parent_element.borrow();
+    parent_element.enclosing_component.upgrade().unwrap()
+}
+
+/// Returns the index of the given PopupWindow element in the list of popups of its parent component
+fn popup_index(
+    popup_list: &[i_slint_compiler::object_tree::PopupWindow],
+    popup_window: &ElementRc,
+) -> usize {
+    let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+    popup_list.iter().position(|p| Rc::ptr_eq(&p.component, &pop_comp)).unwrap()
+}
+
 /// Return the component instance which hold the given element.
 /// The difference with enclosing_component_for_element is that it takes the GlobalComponent into account.
 pub(crate) fn enclosing_component_instance_for_element<'a, 'new_id>(
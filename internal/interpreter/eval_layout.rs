@@ -68,6 +68,27 @@ pub(crate) fn compute_layout_info(
             }
             .into()
         }
+        Layout::FlexboxLayout(flexbox_layout) => {
+            let is_main = orientation == flexbox_layout.main_axis;
+            let (cells, cross_cells) =
+                flexbox_layout_cells(flexbox_layout, is_main, component, &expr_eval, None);
+            let data = flexbox_layout_data(
+                flexbox_layout,
+                // The size along the main axis is only needed to know how the items wrap
+                !is_main,
+                false,
+                component,
+                &expr_eval,
+                &cells,
+                &cross_cells,
+            );
+            if is_main {
+                core_layout::flexbox_layout_info(&data)
+            } else {
+                core_layout::flexbox_layout_info_cross(&data)
+            }
+            .into()
+        }
     }
 }
 
@@ -136,6 +157,33 @@ pub(crate) fn solve_layout(
             )
             .into()
         }
+        Layout::FlexboxLayout(flexbox_layout) => {
+            let is_main = orientation == flexbox_layout.main_axis;
+            let mut repeated_indices = Vec::new();
+            let (cells, cross_cells) = flexbox_layout_cells(
+                flexbox_layout,
+                is_main,
+                component,
+                &expr_eval,
+                Some(&mut repeated_indices),
+            );
+            let data = flexbox_layout_data(
+                flexbox_layout,
+                true,
+                !is_main,
+                component,
+                &expr_eval,
+                &cells,
+                &cross_cells,
+            );
+            let repeated_indices = Slice::from(repeated_indices.as_slice());
+            if is_main {
+                core_layout::solve_flexbox_layout(&data, repeated_indices)
+            } else {
+                core_layout::solve_flexbox_layout_cross(&data, repeated_indices)
+            }
+            .into()
+        }
     }
 }
 
@@ -192,23 +240,7 @@ fn box_layout_data(
     let mut cells = Vec::with_capacity(box_layout.elems.len());
     for cell in &box_layout.elems {
         if cell.element.borrow().repeated.is_some() {
-            generativity::make_guard!(guard);
-            let rep = crate::dynamic_component::get_repeater_by_name(
-                component,
-                cell.element.borrow().id.as_str(),
-                guard,
-            );
-            rep.0.as_ref().ensure_updated(|| {
-                let instance = crate::dynamic_component::instantiate(
-                    rep.1.clone(),
-                    Some(component.borrow()),
-                    None,
-                    None,
-                    Default::default(),
-                );
-                instance
-            });
-            let component_vec = rep.0.as_ref().components_vec();
+            let component_vec = repeated_instances(component, &cell.element);
             if let Some(ri) = repeater_indices.as_mut() {
                 ri.push(cells.len() as _);
                 ri.push(component_vec.len() as _);
@@ -244,6 +276,159 @@ fn box_layout_data(
     (cells, alignment)
 }
 
+/// Make sure that the repeater is up to date, and return its instances
+fn repeated_instances(
+    component: InstanceRef,
+    element: &ElementRc,
+) -> Vec<crate::dynamic_component::DynamicComponentVRc> {
+    generativity::make_guard!(guard);
+    let rep = crate::dynamic_component::get_repeater_by_name(
+        component,
+        element.borrow().id.as_str(),
+        guard,
+    );
+    rep.0.as_ref().ensure_updated(|| {
+        let instance = crate::dynamic_component::instantiate(
+            rep.1.clone(),
+            Some(component.borrow()),
+            None,
+            None,
+            Default::default(),
+        );
+        instance
+    });
+    rep.0.as_ref().components_vec()
+}
+
+/// Return the cells along the main axis, and the cells along the cross axis (only if `is_main` is false)
+fn flexbox_layout_cells(
+    flexbox_layout: &i_slint_compiler::layout::FlexboxLayout,
+    is_main: bool,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+    mut repeater_indices: Option<&mut Vec<u32>>,
+) -> (Vec<core_layout::FlexboxLayoutCellData>, Vec<core_layout::BoxLayoutCellData>) {
+    let window_adapter = component.window_adapter();
+    let main_axis = flexbox_layout.main_axis;
+    let cross_axis = match main_axis {
+        Orientation::Horizontal => Orientation::Vertical,
+        Orientation::Vertical => Orientation::Horizontal,
+    };
+    let mut cells = Vec::with_capacity(flexbox_layout.elems.len());
+    let mut cross_cells = Vec::new();
+    for item in &flexbox_layout.elems {
+        if item.element.borrow().repeated.is_some() {
+            let component_vec = repeated_instances(component, &item.element);
+            if let Some(ri) = repeater_indices.as_mut() {
+                ri.push(cells.len() as _);
+                ri.push(component_vec.len() as _);
+            }
+            cells.extend(
+                component_vec
+                    .iter()
+                    .map(|x| x.as_pin_ref().flexbox_layout_data(to_runtime(main_axis))),
+            );
+            if !is_main {
+                cross_cells.extend(
+                    component_vec
+                        .iter()
+                        .map(|x| x.as_pin_ref().box_layout_data(to_runtime(cross_axis))),
+                );
+            }
+        } else {
+            let mut layout_info =
+                get_layout_info(&item.element, component, &window_adapter, main_axis);
+            fill_layout_info_constraints(
+                &mut layout_info,
+                &item.constraints,
+                main_axis,
+                &expr_eval,
+            );
+            let mut cell = core_layout::FlexboxLayoutCellData {
+                constraint: layout_info,
+                ..Default::default()
+            };
+            fill_flex_properties(&mut cell, &item.constraints, &expr_eval);
+            cells.push(cell);
+            if !is_main {
+                let mut layout_info =
+                    get_layout_info(&item.element, component, &window_adapter, cross_axis);
+                fill_layout_info_constraints(
+                    &mut layout_info,
+                    &item.constraints,
+                    cross_axis,
+                    &expr_eval,
+                );
+                cross_cells.push(core_layout::BoxLayoutCellData { constraint: layout_info });
+            }
+        }
+    }
+    (cells, cross_cells)
+}
+
+fn flexbox_layout_data<'a>(
+    flexbox_layout: &i_slint_compiler::layout::FlexboxLayout,
+    with_main_size: bool,
+    with_cross_size: bool,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+    cells: &'a [core_layout::FlexboxLayoutCellData],
+    cross_cells: &'a [core_layout::BoxLayoutCellData],
+) -> core_layout::FlexboxLayoutData<'a> {
+    let main_axis = flexbox_layout.main_axis;
+    let cross_axis = match main_axis {
+        Orientation::Horizontal => Orientation::Vertical,
+        Orientation::Vertical => Orientation::Horizontal,
+    };
+    let (main_padding, spacing) =
+        padding_and_spacing(&flexbox_layout.geometry, main_axis, expr_eval);
+    let (cross_padding, _) = padding_and_spacing(&flexbox_layout.geometry, cross_axis, expr_eval);
+    let size = |o| flexbox_layout.geometry.rect.size_reference(o).map_or(0., expr_eval);
+    fn load_enum<T: TryFrom<Value> + Default>(
+        component: InstanceRef,
+        nr: &Option<NamedReference>,
+    ) -> T {
+        nr.as_ref()
+            .and_then(|nr| {
+                eval::load_property(component, &nr.element(), nr.name()).unwrap().try_into().ok()
+            })
+            .unwrap_or_default()
+    }
+    let direction = &flexbox_layout.direction;
+    core_layout::FlexboxLayoutData {
+        main_size: if with_main_size { size(main_axis) } else { 0. },
+        cross_size: if with_cross_size { size(cross_axis) } else { 0. },
+        spacing,
+        main_padding,
+        cross_padding,
+        direction: i_slint_core::items::FlexDirection::from_str(
+            &direction.enumeration.values[direction.value],
+        )
+        .unwrap_or_default(),
+        wrap: load_enum(component, &flexbox_layout.wrap),
+        justify_content: load_enum(component, &flexbox_layout.justify_content),
+        align_content: load_enum(component, &flexbox_layout.align_content),
+        cells: Slice::from(cells),
+        cross_cells: Slice::from(cross_cells),
+    }
+}
+
+pub(crate) fn fill_flex_properties(
+    cell: &mut core_layout::FlexboxLayoutCellData,
+    constraints: &LayoutConstraints,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+) {
+    if let Some(e) = constraints.flex_grow.as_ref() {
+        cell.grow = expr_eval(e);
+    }
+    if let Some(e) = constraints.flex_shrink.as_ref() {
+        cell.shrink = expr_eval(e);
+    }
+    if let Some(e) = constraints.flex_basis.as_ref() {
+        cell.basis = expr_eval(e);
+    }
+}
+
 pub(crate) fn fill_layout_info_constraints(
     layout_info: &mut core_layout::LayoutInfo,
     constraints: &LayoutConstraints,
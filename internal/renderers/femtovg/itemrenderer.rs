@@ -1045,6 +1045,19 @@ fn rotate(&mut self, angle_in_degrees: f32) {
         *clip = LogicalRect::new(origin, (end - origin).into());
     }
 
+    fn scale(&mut self, x_factor: f32, y_factor: f32) {
+        self.canvas.borrow_mut().scale(x_factor, y_factor);
+        let clip = &mut self.state.last_mut().unwrap().scissor;
+        if x_factor == 0. || y_factor == 0. {
+            *clip = LogicalRect::default();
+            return;
+        }
+        // Map the clip in the scaled coordinates, flipping it if the factors are negative
+        *clip = LogicalRect::from_points(
+            [clip.min(), clip.max()].map(|p| LogicalPoint::new(p.x / x_factor, p.y / y_factor)),
+        );
+    }
+
     fn apply_opacity(&mut self, opacity: f32) {
         let state = &mut self.state.last_mut().unwrap().global_alpha;
         *state *= opacity;
@@ -55,6 +55,8 @@ fn main() -> std::io::Result<()> {
     //Make sure to use a consistent style
     println!("cargo:rustc-env=SLINT_STYLE=fluent");
     println!("cargo:rustc-env=SLINT_ENABLE_EXPERIMENTAL_FEATURES=1");
+    // The tests find elements by id
+    println!("cargo:rustc-env=SLINT_EMIT_DEBUG_INFO=1");
     Ok(())
 }
 
@@ -112,6 +114,7 @@ fn generate_source(
     compiler_config.enable_component_containers = true;
     compiler_config.include_paths = include_paths;
     compiler_config.style = Some("fluent".to_string());
+    compiler_config.debug_info = true;
     let (root_component, diag) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
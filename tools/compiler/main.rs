@@ -55,6 +55,10 @@ struct Cli {
     /// Translation domain
     #[arg(long = "translation-domain", action)]
     translation_domain: Option<String>,
+
+    /// Bundle the translations from the `.po` files in this directory in the generated code
+    #[arg(long = "bundle-translations", name = "translation path", action)]
+    bundle_translations: Option<std::path::PathBuf>,
 }
 
 fn main() -> std::io::Result<()> {
@@ -69,6 +73,7 @@ fn main() -> std::io::Result<()> {
     }
     let mut compiler_config = CompilerConfiguration::new(args.format);
     compiler_config.translation_domain = args.translation_domain;
+    compiler_config.translation_path_bundle = args.bundle_translations;
 
     // Override defaults from command line:
     if let Some(embed) = args.embed_resources {
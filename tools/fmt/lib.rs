@@ -1,5 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
+//! The formatter for .slint files, used by the `slint-fmt` tool and by the language server.
+
 pub mod fmt;
 pub mod writer;
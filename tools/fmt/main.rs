@@ -11,8 +11,8 @@
     Some code in this main.rs file is duplicated with the slint-updater, i guess it could
     be refactored in a separate utility crate or module or something.
 
-    The [`writer::TokenWriter`] trait is meant to be able to support the LSP later as the
-    LSP wants just the edits, not the full file
+    The formatter itself is in the library of this crate, which the language server also uses
+    to answer formatting requests through the [`writer::TokenWriter`] trait.
 */
 
 use i_slint_compiler::diagnostics::BuildDiagnostics;
@@ -21,9 +21,7 @@
 use std::path::Path;
 
 use clap::Parser;
-
-mod fmt;
-mod writer;
+use slint_fmt::{fmt, writer};
 
 #[derive(clap::Parser)]
 #[command(author, version, about, long_about = None)]
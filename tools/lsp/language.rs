@@ -4,9 +4,17 @@
 // cSpell: ignore descr rfind unindented
 
 mod completion;
+mod document_link;
+mod folding_range;
+mod formatting;
 mod goto;
+mod hover;
+mod inlay_hints;
 mod properties;
+mod references;
+mod selection_range;
 mod semantic_tokens;
+mod signature_help;
 #[cfg(test)]
 mod test;
 
@@ -24,16 +32,21 @@
 use i_slint_compiler::{typeloader::TypeLoader, typeregister::TypeRegister};
 use lsp_types::request::{
     CodeActionRequest, CodeLensRequest, ColorPresentationRequest, Completion, DocumentColor,
-    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, GotoDefinition, HoverRequest,
-    PrepareRenameRequest, Rename, SemanticTokensFullRequest,
+    DocumentHighlightRequest, DocumentLinkRequest, DocumentSymbolRequest, ExecuteCommand,
+    FoldingRangeRequest, Formatting, GotoDefinition, HoverRequest, InlayHintRequest,
+    OnTypeFormatting, PrepareRenameRequest, RangeFormatting, References, Rename,
+    SelectionRangeRequest, SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
 };
 use lsp_types::{
     ClientCapabilities, CodeActionOrCommand, CodeActionProviderCapability, CodeLens,
     CodeLensOptions, Color, ColorInformation, ColorPresentation, Command, CompletionOptions,
-    DocumentSymbol, DocumentSymbolResponse, Hover, InitializeParams, InitializeResult, OneOf,
-    Position, PrepareRenameResponse, PublishDiagnosticsParams, RenameOptions,
-    SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities,
-    ServerInfo, TextDocumentSyncCapability, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    DocumentLinkOptions, DocumentOnTypeFormattingOptions, DocumentSymbol, DocumentSymbolResponse,
+    FoldingRangeProviderCapability, HoverProviderCapability, InitializeParams, InitializeResult,
+    OneOf, Position, PrepareRenameResponse, PublishDiagnosticsParams, RenameOptions,
+    SelectionRangeProviderCapability, SemanticTokensFullOptions, SemanticTokensLegend,
+    SemanticTokensOptions, ServerCapabilities, ServerInfo, SignatureHelpOptions,
+    TextDocumentSyncCapability, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    WorkspaceSymbol, WorkspaceSymbolResponse,
 };
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -177,6 +190,34 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 .into(),
             ),
             document_highlight_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            workspace_symbol_provider: Some(OneOf::Left(true)),
+            signature_help_provider: Some(SignatureHelpOptions {
+                trigger_characters: Some(
+                    signature_help::TRIGGER_CHARACTERS.iter().map(|c| c.to_string()).collect(),
+                ),
+                retrigger_characters: None,
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+            }),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+            selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+            document_link_provider: Some(DocumentLinkOptions {
+                resolve_provider: None,
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+            }),
+            document_formatting_provider: Some(OneOf::Left(true)),
+            document_range_formatting_provider: Some(OneOf::Left(true)),
+            document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+                first_trigger_character: formatting::ON_TYPE_TRIGGER_CHARACTERS[0].into(),
+                more_trigger_character: Some(
+                    formatting::ON_TYPE_TRIGGER_CHARACTERS[1..]
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect(),
+                ),
+            }),
             rename_provider: Some(
                 if client_cap
                     .text_document
@@ -237,18 +278,51 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         });
         Ok(result)
     });
-    rh.register::<HoverRequest, _>(|_params, _ctx| async move {
-        /*let result =
-            token_descr(document_cache, params.text_document_position_params).map(|x| Hover {
-                contents: lsp_types::HoverContents::Scalar(MarkedString::from_language_code(
-                    "text".into(),
-                    format!("{:?}", x.token),
-                )),
-                range: None,
-            });
-        let resp = Response::new_ok(id, result);
-        connection.sender.send(Message::Response(resp))?;*/
-        Ok(None::<Hover>)
+    rh.register::<HoverRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = token_descr(
+            document_cache,
+            &params.text_document_position_params.text_document.uri,
+            &params.text_document_position_params.position,
+        )
+        .and_then(|(token, _)| hover::get_tooltip(document_cache, token));
+        Ok(result)
+    });
+    rh.register::<SignatureHelpRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = get_document_and_offset(
+            document_cache,
+            &params.text_document_position_params.text_document.uri,
+            &params.text_document_position_params.position,
+        )
+        .and_then(|(doc, o)| {
+            // The token before the cursor, which can be the `(` that was just typed
+            let node = doc.node.as_ref()?;
+            let token = node.token_at_offset(o.into()).left_biased()?;
+            Some((SyntaxToken { token, source_file: node.source_file.clone() }, o))
+        })
+        .and_then(|(token, o)| signature_help::get_signature_help(document_cache, token, o));
+        Ok(result)
+    });
+    rh.register::<InlayHintRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(inlay_hints::get_inlay_hints(document_cache, &params.text_document, &params.range))
+    });
+    rh.register::<FoldingRangeRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(folding_range::get_folding_ranges(document_cache, &params.text_document))
+    });
+    rh.register::<SelectionRangeRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(selection_range::get_selection_ranges(
+            document_cache,
+            &params.text_document,
+            &params.positions,
+        ))
+    });
+    rh.register::<DocumentLinkRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(document_link::get_document_links(document_cache, &params.text_document))
     });
     rh.register::<CodeActionRequest, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
@@ -358,6 +432,39 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         ctx.preview.highlight(None, 0)?;
         Ok(None)
     });
+    rh.register::<References, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = token_descr(
+            document_cache,
+            &params.text_document_position.text_document.uri,
+            &params.text_document_position.position,
+        )
+        .and_then(|token| {
+            references::find_references(document_cache, token.0, params.context.include_declaration)
+        });
+        Ok(result)
+    });
+    rh.register::<WorkspaceSymbolRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(get_workspace_symbols(document_cache, &params.query))
+    });
+    rh.register::<Formatting, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(formatting::format_document(document_cache, &params.text_document.uri))
+    });
+    rh.register::<RangeFormatting, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(formatting::format_range(document_cache, &params.text_document.uri, &params.range))
+    });
+    rh.register::<OnTypeFormatting, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(formatting::format_on_type(
+            document_cache,
+            &params.text_document_position.text_document.uri,
+            &params.text_document_position.position,
+            &params.ch,
+        ))
+    });
     rh.register::<Rename, _>(|params, ctx| async move {
         let mut document_cache = ctx.document_cache.borrow_mut();
         let uri = params.text_document_position.text_document.uri;
@@ -1079,6 +1186,75 @@ fn gen_children(elem: &ElementRc, ds: &DocumentSymbol) -> Option<Vec<DocumentSym
     Some(r.into())
 }
 
+/// Returns the components, globals, structs and enums exported by any of the loaded documents
+/// whose name contains the query
+fn get_workspace_symbols(
+    document_cache: &mut DocumentCache,
+    query: &str,
+) -> Option<WorkspaceSymbolResponse> {
+    let query = i_slint_compiler::parser::normalize_identifier(query).to_lowercase();
+
+    let mut r = Vec::new();
+    for doc in document_cache.documents.all_documents() {
+        for (name, exported) in doc.exports.iter() {
+            if !name.name.to_lowercase().contains(&query) {
+                continue;
+            }
+            let (node, kind) = if let Some(c) = exported.as_ref().left() {
+                let kind = if c.is_global() {
+                    lsp_types::SymbolKind::OBJECT
+                } else {
+                    lsp_types::SymbolKind::CLASS
+                };
+                let Some(node) = c.root_element.borrow().node.as_ref().and_then(|n| n.parent())
+                else {
+                    continue;
+                };
+                (node, kind)
+            } else {
+                match exported.as_ref().right() {
+                    Some(Type::Struct { node: Some(node), .. }) => {
+                        let Some(node) = node.parent() else { continue };
+                        (node, lsp_types::SymbolKind::STRUCT)
+                    }
+                    Some(Type::Enumeration(e)) => {
+                        let Some(node) = e.node.clone() else { continue };
+                        (node.into(), lsp_types::SymbolKind::ENUM)
+                    }
+                    _ => continue,
+                }
+            };
+            if node.source_file.path().starts_with("builtin:/") {
+                continue;
+            }
+            let Some((uri, range)) = node
+                .child_node(SyntaxKind::DeclaredIdentifier)
+                .and_then(|n| crate::util::map_node_and_url(&n))
+            else {
+                continue;
+            };
+            r.push(WorkspaceSymbol {
+                name: name.original_name(),
+                kind,
+                tags: None,
+                container_name: None,
+                location: OneOf::Left(lsp_types::Location { uri, range }),
+                data: None,
+            });
+        }
+    }
+
+    // A re-exported symbol is found in several documents
+    r.sort_by_key(|s| match &s.location {
+        OneOf::Left(l) => {
+            (s.name.clone(), l.uri.to_string(), l.range.start.line, l.range.start.character)
+        }
+        OneOf::Right(l) => (s.name.clone(), l.uri.to_string(), 0, 0),
+    });
+    r.dedup();
+    Some(WorkspaceSymbolResponse::Nested(r))
+}
+
 fn get_code_lenses(
     document_cache: &mut DocumentCache,
     text_document: &lsp_types::TextDocumentIdentifier,
@@ -1424,6 +1600,52 @@ fn test_document_symbols_hello_world() {
         }
     }
 
+    #[test]
+    fn test_workspace_symbols() {
+        let (mut dc, uri, _) = loaded_document_cache(
+            r#"import { Button } from "std-widgets.slint";
+export struct Item { name: string }
+export enum Mode { light, dark }
+export global AppState { in-out property <[Item]> items; }
+component Internal { }
+export component MainWindow inherits Window {
+    Button { }
+    Internal { }
+}
+            "#
+            .into(),
+        );
+        let symbols = |dc: &mut DocumentCache, query: &str| {
+            let Some(WorkspaceSymbolResponse::Nested(result)) = get_workspace_symbols(dc, query)
+            else {
+                unreachable!();
+            };
+            result
+        };
+
+        let result = symbols(&mut dc, "");
+        let names = result.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+        // Neither the internal component nor the std-widgets are reported
+        assert_eq!(names, ["AppState", "Item", "MainWindow", "Mode"]);
+        assert_eq!(result[0].kind, lsp_types::SymbolKind::OBJECT);
+        assert_eq!(result[1].kind, lsp_types::SymbolKind::STRUCT);
+        assert_eq!(result[2].kind, lsp_types::SymbolKind::CLASS);
+        assert_eq!(result[3].kind, lsp_types::SymbolKind::ENUM);
+        assert_eq!(
+            result[2].location,
+            OneOf::Left(lsp_types::Location {
+                uri,
+                range: lsp_types::Range::new(Position::new(5, 17), Position::new(5, 27))
+            })
+        );
+
+        let result = symbols(&mut dc, "mainwin");
+        assert_eq!(result.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(), ["MainWindow"]);
+        let result = symbols(&mut dc, "STATE");
+        assert_eq!(result.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(), ["AppState"]);
+        assert!(symbols(&mut dc, "Button").is_empty());
+    }
+
     #[test]
     fn test_code_actions() {
         let (mut dc, url, _) = loaded_document_cache(
@@ -425,8 +425,16 @@ fn resolve_element_scope(
         .collect::<Vec<_>>();
 
     if !matches!(element_type, ElementType::Global) {
+        let class_name = match &element_type {
+            ElementType::Component(c) => {
+                c.root_element.borrow().native_class().map(|n| n.class_name.clone())
+            }
+            ElementType::Builtin(b) => Some(b.native_class.class_name.clone()),
+            _ => None,
+        }
+        .unwrap_or_default();
         result.extend(
-            i_slint_compiler::typeregister::reserved_properties()
+            i_slint_compiler::typeregister::reserved_properties_for_class(&class_name)
                 .filter_map(|(k, t)| {
                     if matches!(t, Type::Function { .. }) {
                         return None;
@@ -0,0 +1,101 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::DocumentCache;
+use crate::util::map_range;
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+
+use i_slint_compiler::parser::{NodeOrToken, SyntaxKind, SyntaxNode, TextRange, TextSize};
+
+use lsp_types::{DocumentLink, Url};
+
+/// Returns the links for the paths of the `import` statements and of the `@image-url(...)`
+pub fn get_document_links(
+    document_cache: &DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+) -> Option<Vec<DocumentLink>> {
+    let uri_path = super::uri_to_file(&text_document.uri)?;
+    let doc = document_cache.documents.get_document(&uri_path)?;
+    let doc_node: SyntaxNode = doc.node.clone()?.into();
+    let sf = &doc_node.source_file;
+
+    let result = doc_node
+        .descendants()
+        .map(|node| SyntaxNode { node, source_file: sf.clone() })
+        .filter(|node| matches!(node.kind(), SyntaxKind::ImportSpecifier | SyntaxKind::AtImageUrl))
+        .filter_map(|node| {
+            let literal = node.child_token(SyntaxKind::StringLiteral)?;
+            let path = i_slint_compiler::literals::unescape_string(literal.text())?;
+            let target = if path.starts_with("http://") || path.starts_with("https://") {
+                Url::parse(&path).ok()?
+            } else {
+                // Resolve the path the same way as the compiler does
+                let (path, _) = document_cache
+                    .documents
+                    .resolve_import_path(Some(&NodeOrToken::from(node.clone())), &path)?;
+                Url::from_file_path(path).ok()?
+            };
+            // Only the content of the string is underlined
+            let range = literal.text_range();
+            let range = TextRange::new(
+                range.start() + TextSize::from(1),
+                (range.end() - TextSize::from(1)).max(range.start()),
+            );
+            Some(DocumentLink {
+                range: map_range(sf, range),
+                target: Some(target),
+                tooltip: None,
+                data: None,
+            })
+        })
+        .collect();
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::empty_document_cache;
+
+    #[test]
+    fn test_document_links() {
+        let dir = std::env::temp_dir().join("slint_lsp_document_links_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dunce::canonicalize(dir).unwrap();
+        std::fs::write(dir.join("lib.slint"), "export component Lib {}").unwrap();
+        std::fs::write(dir.join("logo.svg"), "<svg/>").unwrap();
+
+        let source = r#"import { Lib } from "lib.slint";
+import { Button } from "std-widgets.slint";
+component Foo {
+    Image { source: @image-url("logo.svg"); }
+    Image { source: @image-url("https://slint.dev/logo.png"); }
+    Image { source: @image-url("does-not-exist.png"); }
+}"#;
+        let mut dc = empty_document_cache();
+        let url = Url::from_file_path(dir.join("main.slint")).unwrap();
+        spin_on::spin_on(crate::language::reload_document_impl(
+            None,
+            source.into(),
+            url.clone(),
+            1,
+            &mut dc,
+        ));
+
+        let links = get_document_links(&dc, &lsp_types::TextDocumentIdentifier { uri: url })
+            .unwrap()
+            .into_iter()
+            .map(|l| (l.range.start.line, l.range.start.character, l.target.unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            links,
+            [
+                (0, 21, Url::from_file_path(dir.join("lib.slint")).unwrap()),
+                (3, 32, Url::from_file_path(dir.join("logo.svg")).unwrap()),
+                (4, 32, Url::parse("https://slint.dev/logo.png").unwrap()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::DocumentCache;
+use crate::util::map_position;
+
+use i_slint_compiler::parser::{SyntaxKind, SyntaxNode, TextSize};
+
+use lsp_types::{FoldingRange, FoldingRangeKind};
+
+/// Returns the foldable regions of the document: the content of elements, states, transitions,
+/// code blocks, structs, enums, multi-line literals, as well as the imports and the comments.
+pub fn get_folding_ranges(
+    document_cache: &DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+) -> Option<Vec<FoldingRange>> {
+    let uri_path = super::uri_to_file(&text_document.uri)?;
+    let doc = document_cache.documents.get_document(&uri_path)?;
+    let doc_node: SyntaxNode = doc.node.clone()?.into();
+    let sf = &doc_node.source_file;
+    let line = |offset: TextSize| map_position(sf, offset).line;
+
+    let mut result = Vec::new();
+    for node in doc_node.descendants() {
+        let node = SyntaxNode { node, source_file: sf.clone() };
+        match node.kind() {
+            SyntaxKind::Element
+            | SyntaxKind::States
+            | SyntaxKind::State
+            | SyntaxKind::Transitions
+            | SyntaxKind::Transition
+            | SyntaxKind::CodeBlock
+            | SyntaxKind::ObjectType
+            | SyntaxKind::EnumDeclaration
+            | SyntaxKind::ObjectLiteral
+            | SyntaxKind::Array => {
+                let Some((start, end)) = content_between_brackets(&node) else { continue };
+                let (start, end) = (map_position(sf, start), map_position(sf, end));
+                if start.line < end.line {
+                    result.push(FoldingRange {
+                        start_line: start.line,
+                        start_character: Some(start.character),
+                        end_line: end.line,
+                        end_character: Some(end.character),
+                        ..Default::default()
+                    });
+                }
+            }
+            SyntaxKind::ImportSpecifier => {
+                let (start, end) = (line(node.text_range().start()), line(node.text_range().end()));
+                // Consecutive imports are folded together
+                match result.last_mut() {
+                    Some(FoldingRange {
+                        kind: Some(FoldingRangeKind::Imports), end_line, ..
+                    }) if *end_line + 1 >= start => {
+                        *end_line = end;
+                    }
+                    _ => result.push(FoldingRange {
+                        start_line: start,
+                        end_line: end,
+                        kind: Some(FoldingRangeKind::Imports),
+                        ..Default::default()
+                    }),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Block comments, and consecutive line comments
+    let mut comments: Vec<FoldingRange> = Vec::new();
+    let mut previous_is_line_comment = false;
+    for token in doc_node.descendants_with_tokens().filter_map(|t| t.into_token()) {
+        if token.kind() != SyntaxKind::Comment {
+            continue;
+        }
+        let (start, end) = (line(token.text_range().start()), line(token.text_range().end()));
+        let is_line_comment = token.text().starts_with("//");
+        match comments.last_mut() {
+            Some(previous)
+                if is_line_comment
+                    && previous_is_line_comment
+                    && previous.end_line + 1 == start =>
+            {
+                previous.end_line = end;
+            }
+            _ => comments.push(FoldingRange {
+                start_line: start,
+                end_line: end,
+                kind: Some(FoldingRangeKind::Comment),
+                ..Default::default()
+            }),
+        }
+        previous_is_line_comment = is_line_comment;
+    }
+
+    result.extend(comments);
+    result.retain(|r| r.start_line < r.end_line);
+    result.sort_by_key(|r| (r.start_line, r.end_line));
+    Some(result)
+}
+
+/// The range between the opening brace (or bracket) of the node and the end of the last
+/// non-whitespace token before the closing one
+fn content_between_brackets(node: &SyntaxNode) -> Option<(TextSize, TextSize)> {
+    let mut tokens = node.children_with_tokens().filter_map(|t| t.into_token());
+    let open = tokens.find(|t| matches!(t.kind(), SyntaxKind::LBrace | SyntaxKind::LBracket))?;
+    let close = node
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| matches!(t.kind(), SyntaxKind::RBrace | SyntaxKind::RBracket))
+        .last()?;
+    let mut last = close.token.prev_token()?;
+    while last.kind() == SyntaxKind::Whitespace {
+        last = last.prev_token()?;
+    }
+    Some((open.text_range().end(), last.text_range().end().max(open.text_range().end())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    #[test]
+    fn test_folding_ranges() {
+        let source = r#"import { Button } from "std-widgets.slint";
+import {
+    Slider
+} from "std-widgets.slint";
+
+// A line comment
+// on two lines
+struct Point {
+    x: length,
+    y: length,
+}
+
+/* A block
+   comment */
+component Foo {
+    states [
+        pressed when ta.pressed: {
+            r.background: red;
+        }
+    ]
+    ta := TouchArea {
+        clicked => {
+            debug("clicked");
+        }
+    }
+    r := Rectangle { }
+    Button { text: "single line"; }
+}
+"#;
+        let (dc, url, _) = loaded_document_cache(source.into());
+        let ranges = get_folding_ranges(&dc, &lsp_types::TextDocumentIdentifier { uri: url })
+            .unwrap()
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line, r.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            [
+                (0, 3, Some(FoldingRangeKind::Imports)),
+                (5, 6, Some(FoldingRangeKind::Comment)),
+                (7, 9, None),
+                (12, 13, Some(FoldingRangeKind::Comment)),
+                (14, 26, None),
+                (15, 18, None),
+                (16, 17, None),
+                (20, 23, None),
+                (21, 22, None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Document, range and on-type formatting, using the formatter from the `slint-fmt` crate
+
+use super::DocumentCache;
+use crate::util::map_range;
+use slint_fmt::{fmt, writer::TokenWriter};
+
+use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxToken, TextRange, TextSize};
+use lsp_types::{Position, Range, TextEdit, Url};
+
+/// The characters after which the enclosing block or statement gets formatted
+pub const ON_TYPE_TRIGGER_CHARACTERS: &[&str] = &["}", ";"];
+
+/// A [`TokenWriter`] that records the changes made by the formatter, instead of writing the
+/// whole document
+#[derive(Default)]
+struct EditWriter {
+    edits: Vec<(TextRange, String)>,
+}
+
+impl EditWriter {
+    /// Adds an edit, merging it with the previous one if they touch: the formatter often removes
+    /// a whitespace and inserts a new one before the next token, and these must not be split
+    /// when only some of the edits are applied.
+    fn push(&mut self, range: TextRange, contents: &str) {
+        match self.edits.last_mut() {
+            Some((last, text)) if last.end() == range.start() => {
+                *last = last.cover(range);
+                *text += contents;
+            }
+            _ => self.edits.push((range, contents.into())),
+        }
+    }
+}
+
+impl TokenWriter for EditWriter {
+    fn no_change(&mut self, _token: SyntaxToken) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn with_new_content(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        if token.text() != contents {
+            self.push(token.text_range(), contents);
+        }
+        Ok(())
+    }
+
+    fn insert_before(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        if !contents.is_empty() {
+            self.push(TextRange::empty(token.text_range().start()), contents);
+        }
+        Ok(())
+    }
+}
+
+/// Formats the document, and returns the changes together with the re-parsed document.
+///
+/// Documents with syntax errors are not formatted, as the formatter could lose the text that
+/// the parser could not make sense of.
+fn document_edits(
+    document_cache: &mut DocumentCache,
+    uri: &Url,
+) -> Option<(syntax_nodes::Document, Vec<(TextRange, String)>)> {
+    let path = super::uri_to_file(uri)?;
+    let doc = document_cache.documents.get_document(&path)?;
+    let source = doc.node.as_ref()?.source_file.source()?.to_owned();
+
+    let mut diag = BuildDiagnostics::default();
+    let node = i_slint_compiler::parser::parse(source, Some(&path), &mut diag);
+    if diag.has_error() {
+        return None;
+    }
+    let doc = syntax_nodes::Document::new(node)?;
+    let mut writer = EditWriter::default();
+    fmt::format_document(doc.clone(), &mut writer).ok()?;
+    let source = doc.source_file.source()?;
+    writer.edits.retain(|(range, text)| source[*range] != *text);
+    Some((doc, writer.edits))
+}
+
+fn to_text_edits(
+    doc: &syntax_nodes::Document,
+    edits: impl Iterator<Item = (TextRange, String)>,
+) -> Vec<TextEdit> {
+    edits
+        .map(|(range, new_text)| TextEdit { range: map_range(&doc.source_file, range), new_text })
+        .collect()
+}
+
+fn offset(doc: &syntax_nodes::Document, pos: &Position) -> TextSize {
+    TextSize::from(doc.source_file.offset(pos.line as usize + 1, pos.character as usize + 1) as u32)
+}
+
+/// Handles `textDocument/formatting`
+pub fn format_document(document_cache: &mut DocumentCache, uri: &Url) -> Option<Vec<TextEdit>> {
+    let (doc, edits) = document_edits(document_cache, uri)?;
+    Some(to_text_edits(&doc, edits.into_iter()))
+}
+
+/// Handles `textDocument/rangeFormatting`: only the changes within the range are returned
+pub fn format_range(
+    document_cache: &mut DocumentCache,
+    uri: &Url,
+    range: &Range,
+) -> Option<Vec<TextEdit>> {
+    let (doc, edits) = document_edits(document_cache, uri)?;
+    let range = TextRange::new(offset(&doc, &range.start), offset(&doc, &range.end));
+    Some(to_text_edits(&doc, edits.into_iter().filter(|(r, _)| range.contains_range(*r))))
+}
+
+/// Handles `textDocument/onTypeFormatting`: after one of the [`ON_TYPE_TRIGGER_CHARACTERS`]
+/// was typed, the block it closes or the statement it ends is formatted.
+pub fn format_on_type(
+    document_cache: &mut DocumentCache,
+    uri: &Url,
+    position: &Position,
+    ch: &str,
+) -> Option<Vec<TextEdit>> {
+    let (doc, edits) = document_edits(document_cache, uri)?;
+    let typed = offset(&doc, position).checked_sub(1.into())?;
+    let token = doc.token_at_offset(typed).find(|t| t.text() == ch)?;
+    if !matches!(token.kind(), SyntaxKind::RBrace | SyntaxKind::Semicolon) {
+        return None;
+    }
+    let mut node = token.parent()?;
+    if token.kind() == SyntaxKind::Semicolon {
+        // Format the whole statement, not only the expression before the semicolon
+        while let Some(parent) = node.parent() {
+            if matches!(parent.kind(), SyntaxKind::CodeBlock | SyntaxKind::Element) {
+                break;
+            }
+            node = parent;
+        }
+    }
+    let range = node.text_range();
+    Some(to_text_edits(&doc, edits.into_iter().filter(|(r, _)| range.contains_range(*r))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    /// Applies the edits to the source, like the editor would do it
+    fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+        let sf = i_slint_compiler::diagnostics::SourceFileInner::new(
+            Default::default(),
+            source.to_owned(),
+        );
+        let mut edits = edits
+            .iter()
+            .map(|e| {
+                let start = sf
+                    .offset(e.range.start.line as usize + 1, e.range.start.character as usize + 1);
+                let end =
+                    sf.offset(e.range.end.line as usize + 1, e.range.end.character as usize + 1);
+                (start, end, e.new_text.as_str())
+            })
+            .collect::<Vec<_>>();
+        edits.sort_by_key(|e| e.0);
+        let mut result = String::new();
+        let mut last = 0;
+        for (start, end, text) in edits {
+            result += &source[last..start];
+            result += text;
+            last = end;
+        }
+        result + &source[last..]
+    }
+
+    #[test]
+    fn test_format_document() {
+        let source = "component Foo{ property<int>   x:42; Text{text:\"hello\";} }\n";
+        let (mut dc, url, _) = loaded_document_cache(source.into());
+        let edits = format_document(&mut dc, &url).expect("the document is formatted");
+
+        // The edits give the same result as formatting the whole file
+        let node = i_slint_compiler::parser::parse(source.into(), None, &mut Default::default());
+        let mut formatted = Vec::new();
+        fmt::format_document(
+            syntax_nodes::Document::new(node).unwrap(),
+            &mut slint_fmt::writer::FileWriter { file: &mut formatted },
+        )
+        .unwrap();
+        assert_eq!(apply_edits(source, &edits), String::from_utf8(formatted).unwrap());
+        assert_eq!(
+            apply_edits(source, &edits),
+            "component Foo {\n    property <int> x:42;\n    Text {\n        text: \"hello\";\n    }\n}\n"
+        );
+
+        // Formatting a formatted document does nothing
+        let source = apply_edits(source, &edits);
+        let (mut dc, url, _) = loaded_document_cache(source);
+        assert_eq!(format_document(&mut dc, &url), Some(vec![]));
+    }
+
+    #[test]
+    fn test_format_document_with_errors() {
+        let (mut dc, url, _) = loaded_document_cache("component Foo{ Text{ text: ; } \n".into());
+        assert_eq!(format_document(&mut dc, &url), None);
+    }
+
+    #[test]
+    fn test_format_range() {
+        let source = "component Foo {\n    Text{text:\"a\";}\n    Rectangle{x:1px;}\n}\n";
+        let (mut dc, url, _) = loaded_document_cache(source.into());
+        let range = Range::new(Position::new(2, 0), Position::new(2, 22));
+        let edits = format_range(&mut dc, &url, &range).expect("the range is formatted");
+        assert_eq!(
+            apply_edits(source, &edits),
+            "component Foo {\n    Text{text:\"a\";}\n    Rectangle {\n        x: 1px;\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_on_type() {
+        let source = "component Foo {\n    Text{text:\"a\";}\n    Rectangle { x:1px; y:2px; }\n}\n";
+        let (mut dc, url, _) = loaded_document_cache(source.into());
+
+        // After the semicolon of `x:1px;`, only that binding is formatted
+        let edits = format_on_type(&mut dc, &url, &Position::new(2, 22), ";").unwrap();
+        assert_eq!(
+            apply_edits(source, &edits),
+            "component Foo {\n    Text{text:\"a\";}\n    Rectangle { x: 1px; y:2px; }\n}\n"
+        );
+
+        // After the closing brace of the Text, that element is formatted
+        let edits = format_on_type(&mut dc, &url, &Position::new(1, 20), "}").unwrap();
+        assert_eq!(
+            apply_edits(source, &edits),
+            "component Foo {\n    Text {\n        text: \"a\";\n    }\n    Rectangle { x:1px; y:2px; }\n}\n"
+        );
+
+        assert_eq!(format_on_type(&mut dc, &url, &Position::new(2, 10), "}"), None);
+    }
+}
@@ -17,6 +17,15 @@ pub fn goto_definition(
     document_cache: &mut DocumentCache,
     token: SyntaxToken,
 ) -> Option<GotoDefinitionResponse> {
+    goto_node(&find_definition(document_cache, token)?)
+}
+
+/// Returns the node that declares what the token refers to: the root element of a component,
+/// the declaration of a property, callback, function, struct or enum, or the element of an id.
+pub fn find_definition(
+    document_cache: &mut DocumentCache,
+    token: SyntaxToken,
+) -> Option<SyntaxNode> {
     let mut node = token.parent();
     loop {
         if let Some(n) = syntax_nodes::QualifiedName::new(node.clone()) {
@@ -26,8 +35,8 @@ pub fn goto_definition(
                     let qual = i_slint_compiler::object_tree::QualifiedTypeName::from_node(n);
                     let doc = document_cache.documents.get_document(node.source_file.path())?;
                     match doc.local_registry.lookup_qualified(&qual.members) {
-                        Type::Struct { node: Some(node), .. } => goto_node(node.parent().as_ref()?),
-                        Type::Enumeration(e) => goto_node(e.node.as_ref()?),
+                        Type::Struct { node: Some(node), .. } => node.parent(),
+                        Type::Enumeration(e) => Some(e.node.clone()?.into()),
                         _ => None,
                     }
                 }
@@ -36,7 +45,7 @@ pub fn goto_definition(
                     let doc = document_cache.documents.get_document(node.source_file.path())?;
                     match doc.local_registry.lookup_element(&qual.to_string()) {
                         Ok(ElementType::Component(c)) => {
-                            goto_node(c.root_element.borrow().node.as_ref()?)
+                            Some(c.root_element.borrow().node.clone()?.into())
                         }
                         _ => None,
                     }
@@ -45,25 +54,7 @@ pub fn goto_definition(
                     if token.kind() != SyntaxKind::Identifier {
                         return None;
                     }
-                    let lr = with_lookup_ctx(document_cache, node, |ctx| {
-                        let mut it = n
-                            .children_with_tokens()
-                            .filter_map(|t| t.into_token())
-                            .filter(|t| t.kind() == SyntaxKind::Identifier);
-                        let mut cur_tok = it.next()?;
-                        let first_str =
-                            i_slint_compiler::parser::normalize_identifier(cur_tok.text());
-                        let global = i_slint_compiler::lookup::global_lookup();
-                        let mut expr_it = global.lookup(ctx, &first_str)?;
-                        while cur_tok.token != token.token {
-                            cur_tok = it.next()?;
-                            let str =
-                                i_slint_compiler::parser::normalize_identifier(cur_tok.text());
-                            expr_it = expr_it.lookup(ctx, &str)?;
-                        }
-                        Some(expr_it)
-                    })?;
-                    let gn = match lr? {
+                    let gn = match lookup_expression_token(document_cache, &n, &token)? {
                         LookupResult::Expression {
                             expression: Expression::ElementReference(e),
                             ..
@@ -98,7 +89,7 @@ pub fn goto_definition(
                         LookupResult::Enumeration(e) => e.node.clone()?.into(),
                         _ => return None,
                     };
-                    goto_node(&gn)
+                    Some(gn)
                 }
                 _ => None,
             };
@@ -106,8 +97,12 @@ pub fn goto_definition(
             let doc = document_cache.documents.get_document(node.source_file.path())?;
             let imp_name = i_slint_compiler::typeloader::ImportedName::from_node(n);
             return match doc.local_registry.lookup_element(&imp_name.internal_name) {
-                Ok(ElementType::Component(c)) => goto_node(c.root_element.borrow().node.as_ref()?),
-                _ => None,
+                Ok(ElementType::Component(c)) => Some(c.root_element.borrow().node.clone()?.into()),
+                _ => match doc.local_registry.lookup(&imp_name.internal_name) {
+                    Type::Struct { node: Some(node), .. } => node.parent(),
+                    Type::Enumeration(e) => Some(e.node.clone()?.into()),
+                    _ => None,
+                },
             };
         } else if let Some(n) = syntax_nodes::ImportSpecifier::new(node.clone()) {
             let import_file = node
@@ -118,8 +113,7 @@ pub fn goto_definition(
                 .join(n.child_text(SyntaxKind::StringLiteral)?.trim_matches('\"'));
             let import_file = dunce::canonicalize(&import_file).unwrap_or(import_file);
             let doc = document_cache.documents.get_document(&import_file)?;
-            let doc_node = doc.node.clone()?;
-            return goto_node(&doc_node);
+            return Some(doc.node.clone()?.into());
         } else if syntax_nodes::BindingExpression::new(node.clone()).is_some() {
             // don't fallback to the Binding
             return None;
@@ -133,10 +127,9 @@ pub fn goto_definition(
                 (i_slint_compiler::parser::identifier_text(&p.DeclaredIdentifier())? == prop_name)
                     .then_some(p)
             }) {
-                return goto_node(&p);
+                return Some(p.into());
             }
-            let n = find_property_declaration_in_base(document_cache, element, prop_name)?;
-            return goto_node(&n);
+            return find_property_declaration_in_base(document_cache, element, prop_name);
         } else if let Some(n) = syntax_nodes::TwoWayBinding::new(node.clone()) {
             if token.kind() != SyntaxKind::Identifier {
                 return None;
@@ -150,10 +143,9 @@ pub fn goto_definition(
                 (i_slint_compiler::parser::identifier_text(&p.DeclaredIdentifier())? == prop_name)
                     .then_some(p)
             }) {
-                return goto_node(&p);
+                return Some(p.into());
             }
-            let n = find_property_declaration_in_base(document_cache, element, prop_name)?;
-            return goto_node(&n);
+            return find_property_declaration_in_base(document_cache, element, prop_name);
         } else if let Some(n) = syntax_nodes::CallbackConnection::new(node.clone()) {
             if token.kind() != SyntaxKind::Identifier {
                 return None;
@@ -167,15 +159,39 @@ pub fn goto_definition(
                 (i_slint_compiler::parser::identifier_text(&p.DeclaredIdentifier())? == prop_name)
                     .then_some(p)
             }) {
-                return goto_node(&p);
+                return Some(p.into());
             }
-            let n = find_property_declaration_in_base(document_cache, element, prop_name)?;
-            return goto_node(&n);
+            return find_property_declaration_in_base(document_cache, element, prop_name);
         }
         node = node.parent()?;
     }
 }
 
+/// Lookup what the `token` refers to, in a qualified name used in an expression.
+/// For example, in `foo.bar.baz`, the `bar` token is looked up as the `bar` member of `foo`.
+pub fn lookup_expression_token(
+    document_cache: &DocumentCache,
+    qualified_name: &syntax_nodes::QualifiedName,
+    token: &SyntaxToken,
+) -> Option<LookupResult> {
+    with_lookup_ctx(document_cache, qualified_name.clone().into(), |ctx| {
+        let mut it = qualified_name
+            .children_with_tokens()
+            .filter_map(|t| t.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Identifier);
+        let mut cur_tok = it.next()?;
+        let first_str = i_slint_compiler::parser::normalize_identifier(cur_tok.text());
+        let global = i_slint_compiler::lookup::global_lookup();
+        let mut expr_it = global.lookup(ctx, &first_str)?;
+        while cur_tok.token != token.token {
+            cur_tok = it.next()?;
+            let str = i_slint_compiler::parser::normalize_identifier(cur_tok.text());
+            expr_it = expr_it.lookup(ctx, &str)?;
+        }
+        Some(expr_it)
+    })?
+}
+
 /// Try to lookup the property `prop_name` in the base of the given Element
 fn find_property_declaration_in_base(
     document_cache: &DocumentCache,
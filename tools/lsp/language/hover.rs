@@ -0,0 +1,560 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::DocumentCache;
+use crate::util::map_token;
+
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::lookup::LookupResult;
+use i_slint_compiler::object_tree::{Component, ElementRc, PropertyVisibility};
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+use std::fmt::Write;
+use std::rc::Rc;
+
+/// Returns the hover information for the token: what it refers to, its type, where it is
+/// declared and the comment before its declaration, or the color it represents.
+pub fn get_tooltip(document_cache: &mut DocumentCache, token: SyntaxToken) -> Option<Hover> {
+    let value = match token.kind() {
+        SyntaxKind::ColorLiteral => {
+            let color = i_slint_compiler::literals::parse_color_literal(token.text())?;
+            format!("{}\n\n{}", code_block("color"), color_description(color))
+        }
+        SyntaxKind::Identifier => identifier_tooltip(document_cache, &token)?,
+        _ => return None,
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+        range: map_token(&token),
+    })
+}
+
+fn identifier_tooltip(document_cache: &mut DocumentCache, token: &SyntaxToken) -> Option<String> {
+    let mut node = token.parent();
+    loop {
+        match node.kind() {
+            SyntaxKind::DeclaredIdentifier => {
+                let declaration = node.parent()?;
+                return match declaration.kind() {
+                    SyntaxKind::PropertyDeclaration
+                    | SyntaxKind::CallbackDeclaration
+                    | SyntaxKind::Function => {
+                        let element = element_for_node(document_cache, &declaration.parent()?)?;
+                        property_tooltip(&element, &identifier_text(&node)?)
+                    }
+                    SyntaxKind::Component => {
+                        let doc = document_cache.documents.get_document(node.source_file.path())?;
+                        match doc.local_registry.lookup_element(&identifier_text(&node)?) {
+                            Ok(ElementType::Component(c)) => Some(component_tooltip(&c)),
+                            _ => None,
+                        }
+                    }
+                    SyntaxKind::StructDeclaration | SyntaxKind::EnumDeclaration => {
+                        let doc = document_cache.documents.get_document(node.source_file.path())?;
+                        type_tooltip(&doc.local_registry.lookup(&identifier_text(&node)?))
+                    }
+                    _ => None,
+                };
+            }
+            SyntaxKind::QualifiedName => {
+                let qualified_name = syntax_nodes::QualifiedName::new(node.clone())?;
+                let doc = document_cache.documents.get_document(node.source_file.path())?;
+                return match node.parent()?.kind() {
+                    SyntaxKind::Element => {
+                        let name = i_slint_compiler::object_tree::QualifiedTypeName::from_node(
+                            qualified_name,
+                        );
+                        match doc.local_registry.lookup_element(&name.to_string()).ok()? {
+                            ElementType::Component(c) => Some(component_tooltip(&c)),
+                            ElementType::Builtin(b) => {
+                                Some(format!("{}\n\nBuiltin element", code_block(&b.name)))
+                            }
+                            _ => None,
+                        }
+                    }
+                    SyntaxKind::Type => {
+                        let name = i_slint_compiler::object_tree::QualifiedTypeName::from_node(
+                            qualified_name,
+                        );
+                        type_tooltip(&doc.local_registry.lookup_qualified(&name.members))
+                    }
+                    SyntaxKind::Expression => {
+                        let result = super::goto::lookup_expression_token(
+                            document_cache,
+                            &qualified_name,
+                            token,
+                        )?;
+                        lookup_result_tooltip(result)
+                    }
+                    _ => None,
+                };
+            }
+            SyntaxKind::Binding | SyntaxKind::TwoWayBinding | SyntaxKind::CallbackConnection => {
+                if identifier_text(&node)? != normalize_identifier(token.text()) {
+                    return None;
+                }
+                let element = element_for_node(document_cache, &node.parent()?)?;
+                return property_tooltip(&element, &identifier_text(&node)?);
+            }
+            SyntaxKind::BindingExpression
+            | SyntaxKind::Element
+            | SyntaxKind::Component
+            | SyntaxKind::Document => return None,
+            _ => node = node.parent()?,
+        }
+    }
+}
+
+fn lookup_result_tooltip(result: LookupResult) -> Option<String> {
+    match result {
+        LookupResult::Expression { expression, .. } => match expression {
+            Expression::PropertyReference(nr)
+            | Expression::CallbackReference(nr, _)
+            | Expression::FunctionReference(nr, _) => property_tooltip(&nr.element(), nr.name()),
+            Expression::ElementReference(e) => {
+                let e = e.upgrade()?;
+                let component = e.borrow().enclosing_component.upgrade()?;
+                if component.is_global() && Rc::ptr_eq(&component.root_element, &e) {
+                    return Some(component_tooltip(&component));
+                }
+                let e = e.borrow();
+                if e.id.is_empty() {
+                    Some(code_block(&e.base_type.to_string()))
+                } else {
+                    Some(code_block(&format!("{} := {}", e.id, e.base_type)))
+                }
+            }
+            Expression::EnumerationValue(v) => {
+                let mut result = code_block(&format!("{}.{}", v.enumeration.name, v));
+                if let Some(doc) = v.enumeration.node.as_ref().and_then(|n| {
+                    let value = n.EnumValue().nth(v.value)?;
+                    doc_comment(&value.into())
+                }) {
+                    write!(result, "\n\n---\n\n{doc}").ok()?;
+                }
+                Some(result)
+            }
+            e => {
+                let ty = e.ty();
+                let mut result = code_block(&type_name(&ty));
+                if let Some(color) = constant_color(&e) {
+                    write!(result, "\n\n{}", color_description(color)).ok()?;
+                }
+                Some(result)
+            }
+        },
+        LookupResult::Enumeration(e) => type_tooltip(&Type::Enumeration(e)),
+        LookupResult::Namespace(_) => None,
+    }
+}
+
+/// The tooltip for a property, callback or function of an element
+fn property_tooltip(element: &ElementRc, name: &str) -> Option<String> {
+    let lookup = element.borrow().lookup_property(name);
+    if lookup.property_type == Type::Invalid {
+        return None;
+    }
+    let name = lookup.resolved_name.to_string();
+
+    let mut declaration_node = None;
+    let mut declared_in = None;
+    let mut default_value = None;
+    let mut el = element.clone();
+    loop {
+        if let Some(decl) = el.borrow().property_declarations.get(&name) {
+            declaration_node = decl.node.clone();
+            let component = el.borrow().enclosing_component.upgrade();
+            declared_in = component.map(|c| {
+                let file = decl.node.as_ref().and_then(|n| {
+                    Some(n.source_file.path().file_name()?.to_string_lossy().to_string())
+                });
+                match file {
+                    Some(file) => format!("Declared in `{}` (`{file}`)", c.id),
+                    None => format!("Declared in `{}`", c.id),
+                }
+            });
+            break;
+        }
+        let base = el.borrow().base_type.clone();
+        match base {
+            ElementType::Component(c) => el = c.root_element.clone(),
+            ElementType::Builtin(b) => {
+                declared_in = Some(format!("Builtin property of `{}`", b.name));
+                default_value = b.properties.get(&name).and_then(|p| p.default_value.clone());
+                break;
+            }
+            _ => break,
+        }
+    }
+
+    let declaration = match &lookup.property_type {
+        Type::Callback { args, return_type } => {
+            let args = args.iter().map(type_name).collect::<Vec<_>>().join(", ");
+            match return_type {
+                Some(ret) => format!("callback {name}({args}) -> {}", type_name(ret)),
+                None => format!("callback {name}({args})"),
+            }
+        }
+        Type::InferredCallback => format!("callback {name}"),
+        Type::Function { return_type, args } => {
+            let args = args.iter().map(type_name).collect::<Vec<_>>().join(", ");
+            let visibility = match lookup.property_visibility {
+                PropertyVisibility::Public => "public ",
+                _ => "",
+            };
+            let pure = if lookup.declared_pure == Some(true) { "pure " } else { "" };
+            match &**return_type {
+                Type::Void => format!("{visibility}{pure}function {name}({args})"),
+                ret => format!("{visibility}{pure}function {name}({args}) -> {}", type_name(ret)),
+            }
+        }
+        ty => {
+            let visibility = match lookup.property_visibility {
+                PropertyVisibility::Input => "in ",
+                PropertyVisibility::Output => "out ",
+                PropertyVisibility::InOut => "in-out ",
+                _ => "",
+            };
+            format!("{visibility}property <{}> {name}", type_name(ty))
+        }
+    };
+
+    let mut result = code_block(&declaration);
+    if let Some(declared_in) = declared_in {
+        write!(result, "\n\n{declared_in}").ok()?;
+    }
+    if let Some(default_value) = default_value {
+        match constant_color(&default_value) {
+            Some(color) => write!(result, "\n\nDefault value: {}", color_description(color)),
+            None => {
+                let mut value = String::new();
+                i_slint_compiler::expression_tree::pretty_print(&mut value, &default_value).ok()?;
+                write!(result, "\n\nDefault value: `{value}`")
+            }
+        }
+        .ok()?;
+    }
+    if let Some(doc) = declaration_node.as_ref().and_then(doc_comment) {
+        write!(result, "\n\n---\n\n{doc}").ok()?;
+    }
+    Some(result)
+}
+
+fn component_tooltip(component: &Rc<Component>) -> String {
+    let root = component.root_element.borrow();
+    let declaration = if component.is_global() {
+        format!("global {}", component.id)
+    } else {
+        match &root.base_type {
+            ElementType::Component(base) => {
+                format!("component {} inherits {}", component.id, base.id)
+            }
+            ElementType::Builtin(base) => {
+                format!("component {} inherits {}", component.id, base.name)
+            }
+            _ => format!("component {}", component.id),
+        }
+    };
+    let mut result = code_block(&declaration);
+    // The node of the root element is inside the node of the component
+    let component_node = root.node.as_ref().and_then(|n| n.parent());
+    if let Some(file) = component_node.as_ref().and_then(|n| n.source_file.path().file_name()) {
+        let file = file.to_string_lossy();
+        write!(result, "\n\nDeclared in `{file}`").unwrap();
+    }
+    if let Some(doc) = component_node.as_ref().and_then(doc_comment) {
+        write!(result, "\n\n---\n\n{doc}").unwrap();
+    }
+    result
+}
+
+fn type_tooltip(ty: &Type) -> Option<String> {
+    let (declaration, node) = match ty {
+        Type::Struct { fields, name: Some(name), node, .. } => {
+            let fields = fields
+                .iter()
+                .map(|(k, v)| format!("    {k}: {},\n", type_name(v)))
+                .collect::<String>();
+            (format!("struct {name} {{\n{fields}}}"), node.as_ref().and_then(|n| n.parent()))
+        }
+        Type::Enumeration(e) => {
+            let values = e.values.iter().map(|v| format!("    {v},\n")).collect::<String>();
+            (format!("enum {} {{\n{values}}}", e.name), e.node.clone().map(Into::into))
+        }
+        _ => return None,
+    };
+    let mut result = code_block(&declaration);
+    if let Some(doc) = node.as_ref().and_then(doc_comment) {
+        write!(result, "\n\n---\n\n{doc}").ok()?;
+    }
+    Some(result)
+}
+
+/// Find the element that corresponds to an `Element` syntax node
+pub fn element_for_node(document_cache: &DocumentCache, node: &SyntaxNode) -> Option<ElementRc> {
+    fn find(element: &ElementRc, node: &SyntaxNode) -> Option<ElementRc> {
+        let range = element.borrow().node.as_ref()?.text_range();
+        if range == node.text_range() {
+            return Some(element.clone());
+        }
+        if !range.contains_range(node.text_range()) {
+            return None;
+        }
+        element.borrow().children.iter().find_map(|c| find(c, node))
+    }
+    if node.kind() != SyntaxKind::Element {
+        return None;
+    }
+    let doc = document_cache.documents.get_document(node.source_file.path())?;
+    doc.inner_components.iter().find_map(|c| find(&c.root_element, node))
+}
+
+/// The name of a type like it would be written in a .slint file
+pub fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Enumeration(e) => e.name.clone(),
+        Type::Array(ty) => format!("[{}]", type_name(ty)),
+        // The name of builtin structs is the path of the native type
+        Type::Struct { name: Some(name), node: None, .. } => {
+            name.rsplit("::").next().unwrap_or(name).to_string()
+        }
+        ty => ty.to_string(),
+    }
+}
+
+/// Returns the comments right before the declaration, without the comment markers
+pub fn doc_comment(node: &SyntaxNode) -> Option<String> {
+    // The `export` keyword is part of the parent node
+    let node = match node.parent() {
+        Some(parent) if parent.kind() == SyntaxKind::ExportsList => parent,
+        _ => node.clone(),
+    };
+    let mut token = node.node.first_token()?;
+    while matches!(token.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment) {
+        token = token.next_token()?;
+    }
+
+    let mut comments = Vec::new();
+    while let Some(prev) = token.prev_token() {
+        match prev.kind() {
+            SyntaxKind::Comment => comments.push(prev.text().to_string()),
+            // An empty line separates the comments that are not about the declaration
+            SyntaxKind::Whitespace if prev.text().matches('\n').count() < 2 => (),
+            _ => break,
+        }
+        token = prev;
+    }
+    if comments.is_empty() {
+        return None;
+    }
+
+    let lines = comments
+        .iter()
+        .rev()
+        .flat_map(|c| {
+            if let Some(c) = c.strip_prefix("//") {
+                vec![c.trim_start_matches('/').trim().to_string()]
+            } else {
+                let c = c.trim_start_matches("/*").trim_end_matches("*/");
+                c.lines()
+                    .map(|l| l.trim().trim_start_matches('*').trim().to_string())
+                    .skip_while(|l| l.is_empty())
+                    .collect()
+            }
+        })
+        .collect::<Vec<_>>();
+    let result = lines.join("\n").trim().to_string();
+    (!result.is_empty()).then_some(result)
+}
+
+/// Returns the ARGB value of an expression that is a constant color
+fn constant_color(expression: &Expression) -> Option<u32> {
+    match expression {
+        Expression::Cast { from, to: Type::Color | Type::Brush } => constant_color(from),
+        Expression::NumberLiteral(value, _) if *value >= 0. && *value <= u32::MAX as f64 => {
+            Some(*value as u32)
+        }
+        _ => None,
+    }
+}
+
+/// A swatch showing the color, followed by its value
+fn color_description(argb: u32) -> String {
+    let [a, r, g, b] = argb.to_be_bytes();
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='14' height='14'>\
+         <rect x='0.5' y='0.5' width='13' height='13' fill='#{r:02x}{g:02x}{b:02x}' \
+         fill-opacity='{}' stroke='gray'/></svg>",
+        a as f32 / 255.
+    );
+    let svg = svg
+        .bytes()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || b"-_.~".contains(&c) {
+                (c as char).to_string()
+            } else {
+                format!("%{c:02X}")
+            }
+        })
+        .collect::<String>();
+    let value = if a == 255 {
+        format!("`#{r:02x}{g:02x}{b:02x}` `rgb({r}, {g}, {b})`")
+    } else {
+        format!("`#{r:02x}{g:02x}{b:02x}{a:02x}` `rgba({r}, {g}, {b}, {:.0}%)`", a as f32 / 2.55)
+    };
+    format!("![](data:image/svg+xml,{svg}) {value}")
+}
+
+fn code_block(code: &str) -> String {
+    format!("```slint\n{code}\n```")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::{complex_document_cache, loaded_document_cache};
+    use crate::language::token_at_offset;
+
+    /// Returns the markdown of the tooltip for the first occurrence of `needle` in the document
+    fn tooltip(dc: &mut DocumentCache, url: &lsp_types::Url, needle: &str) -> Option<String> {
+        let doc = dc.documents.get_document(&url.to_file_path().unwrap()).unwrap();
+        let doc_node = doc.node.clone().unwrap();
+        let offset = doc_node.source_file.source().unwrap().find(needle).unwrap() as u32;
+        let token = token_at_offset(&doc_node, offset + 1).unwrap();
+        match get_tooltip(dc, token)?.contents {
+            HoverContents::Markup(m) => Some(m.value),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_property_tooltip() {
+        let (mut dc, url, _) = complex_document_cache();
+        let expected = "```slint\nproperty <duration> total-time\n```\n\nDeclared in `MainWindow` (`bar.slint`)";
+        assert_eq!(tooltip(&mut dc, &url, "total-time:").unwrap(), expected);
+        assert_eq!(tooltip(&mut dc, &url, "total-time)").unwrap(), expected);
+        assert_eq!(
+            tooltip(&mut dc, &url, "tick(passed").unwrap(),
+            "```slint\ncallback tick(duration)\n```\n\nDeclared in `MainWindow` (`bar.slint`)"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "padding-left").unwrap(),
+            "```slint\nin-out property <length> padding-left\n```\n\nBuiltin property of `HorizontalLayout`"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "vertical-alignment").unwrap(),
+            "```slint\nin property <TextVerticalAlignment> vertical-alignment\n```\n\nBuiltin property of `Text`"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "slider.value").unwrap(),
+            "```slint\nslider := Slider\n```"
+        );
+        assert_eq!(tooltip(&mut dc, &url, "passed-time) =>"), None);
+    }
+
+    #[test]
+    fn test_component_and_type_tooltip() {
+        let (mut dc, url, _) = loaded_document_cache(
+            r#"
+/// The state of the application
+export global State {
+    // The number of clicks
+    /* (always positive) */
+    out property <int> counter;
+}
+
+// Not a doc comment
+
+/** A point
+ * in 2D */
+struct Point { x: length, y: length }
+
+enum Mode {
+    /// The default mode
+    normal,
+    strict
+}
+
+/// A button
+component MyButton inherits Rectangle {
+    in property <bool> checked: true;
+}
+
+export component Demo {
+    property <Point> p;
+    property <Mode> mode: Mode.normal;
+    b := MyButton { checked: false; }
+    TouchArea { clicked => { State.counter += 1; } }
+    TextInput { selection-background-color: #336699; }
+}
+"#
+            .into(),
+        );
+
+        assert_eq!(
+            tooltip(&mut dc, &url, "MyButton {").unwrap(),
+            "```slint\ncomponent MyButton inherits Rectangle\n```\n\nDeclared in `bar.slint`\n\n---\n\nA button"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "State.").unwrap(),
+            "```slint\nglobal State\n```\n\nDeclared in `bar.slint`\n\n---\n\nThe state of the application"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "counter +=").unwrap(),
+            "```slint\nout property <int> counter\n```\n\nDeclared in `State` (`bar.slint`)\n\n---\n\nThe number of clicks\n(always positive)"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "Point>").unwrap(),
+            "```slint\nstruct Point {\n    x: length,\n    y: length,\n}\n```\n\n---\n\nA point\nin 2D"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "Mode>").unwrap(),
+            "```slint\nenum Mode {\n    normal,\n    strict,\n}\n```"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "normal;").unwrap(),
+            "```slint\nMode.normal\n```\n\n---\n\nThe default mode"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "checked: false").unwrap(),
+            "```slint\nin property <bool> checked\n```\n\nDeclared in `MyButton` (`bar.slint`)"
+        );
+        assert_eq!(
+            tooltip(&mut dc, &url, "TouchArea").unwrap(),
+            "```slint\nTouchArea\n```\n\nBuiltin element"
+        );
+        let selection = tooltip(&mut dc, &url, "selection-background-color").unwrap();
+        assert!(selection.starts_with(
+            "```slint\nin property <color> selection-background-color\n```\n\nBuiltin property of `TextInput`\n\nDefault value: ![](data:image/svg+xml,"
+        ));
+        assert!(selection.ends_with(") `#808080` `rgb(128, 128, 128)`"));
+    }
+
+    #[test]
+    fn test_color_tooltip() {
+        let (mut dc, url, _) = loaded_document_cache(
+            r#"
+export component Demo {
+    Rectangle { background: #33669980; }
+    Rectangle { background: Colors.red; }
+    Rectangle { background: blue; }
+}
+"#
+            .into(),
+        );
+        let literal = tooltip(&mut dc, &url, "#3366").unwrap();
+        assert!(literal.starts_with("```slint\ncolor\n```\n\n![](data:image/svg+xml,%3Csvg%20"));
+        assert!(literal.contains("fill%3D%27%23336699%27%20fill-opacity%3D%270.5019608%27"));
+        assert!(literal.ends_with(") `#33669980` `rgba(51, 102, 153, 50%)`"));
+
+        let red = tooltip(&mut dc, &url, "red;").unwrap();
+        assert!(red.starts_with("```slint\ncolor\n```\n\n![]("));
+        assert!(red.ends_with(") `#ff0000` `rgb(255, 0, 0)`"));
+        let blue = tooltip(&mut dc, &url, "blue;").unwrap();
+        assert!(blue.ends_with(") `#0000ff` `rgb(0, 0, 255)`"));
+    }
+}
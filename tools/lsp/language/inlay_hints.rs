@@ -0,0 +1,275 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::hover::type_name;
+use super::DocumentCache;
+use crate::util::map_position;
+
+use i_slint_compiler::expression_tree::{BuiltinFunction, Expression, Unit};
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::object_tree::{recurse_elem, ElementRc};
+use i_slint_compiler::parser::{
+    normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken, TextSize,
+};
+
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel};
+use std::rc::Rc;
+
+/// Returns the hints for the types that are not written in the source (alias properties and
+/// `for` loops) and for the implicit conversions done on the bindings
+pub fn get_inlay_hints(
+    document_cache: &DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+    range: &lsp_types::Range,
+) -> Option<Vec<InlayHint>> {
+    let uri_path = super::uri_to_file(&text_document.uri)?;
+    let doc = document_cache.documents.get_document(&uri_path)?;
+    let doc_node = doc.node.as_ref()?;
+
+    let mut hints = Vec::new();
+    for component in &doc.inner_components {
+        recurse_elem(&component.root_element, &(), &mut |element, _| {
+            element_hints(element, &mut hints);
+        });
+    }
+
+    let mut result = hints
+        .into_iter()
+        .map(|(offset, label, kind)| InlayHint {
+            position: map_position(&doc_node.source_file, offset),
+            // The type of an alias is placed before its name, the conversions after the binding
+            padding_left: Some(kind.is_none()),
+            padding_right: Some(label.starts_with('<')),
+            label: InlayHintLabel::String(label),
+            kind,
+            text_edits: None,
+            tooltip: None,
+            data: None,
+        })
+        .filter(|h| range.start <= h.position && h.position <= range.end)
+        .collect::<Vec<_>>();
+    result.sort_by_key(|h| (h.position.line, h.position.character));
+    Some(result)
+}
+
+type Hint = (TextSize, String, Option<InlayHintKind>);
+
+fn element_hints(element: &ElementRc, hints: &mut Vec<Hint>) {
+    let e = element.borrow();
+    let Some(node) = e.node.clone() else { return };
+
+    // The type of the properties and callbacks declared as alias without type
+    for decl in e.property_declarations.values() {
+        if let Some(p) = decl.node.clone().and_then(syntax_nodes::PropertyDeclaration::new) {
+            if p.Type().is_none() && !matches!(decl.property_type, Type::Invalid) {
+                let label = format!("<{}>", type_name(&decl.property_type));
+                hints.push((
+                    p.DeclaredIdentifier().text_range().start(),
+                    label,
+                    Some(InlayHintKind::TYPE),
+                ));
+            }
+        } else if let Some(c) = decl.node.clone().and_then(syntax_nodes::CallbackDeclaration::new) {
+            if c.TwoWayBinding().is_some() && c.Type().next().is_none() && c.ReturnType().is_none()
+            {
+                let Type::Callback { args, return_type } = &decl.property_type else { continue };
+                if args.is_empty() && return_type.is_none() {
+                    continue;
+                }
+                let mut label =
+                    format!("({})", args.iter().map(type_name).collect::<Vec<_>>().join(", "));
+                if let Some(ret) = return_type {
+                    label += &format!(" -> {}", type_name(ret));
+                }
+                hints.push((
+                    c.DeclaredIdentifier().text_range().end(),
+                    label,
+                    Some(InlayHintKind::TYPE),
+                ));
+            }
+        }
+    }
+
+    // The type of the model data and index in `for` loops
+    if e.repeated.as_ref().map_or(false, |r| !r.is_conditional_element) {
+        if let Some(repeated) =
+            node.parent().and_then(|n| n.parent()).and_then(syntax_nodes::RepeatedElement::new)
+        {
+            if let Some(id) = repeated.DeclaredIdentifier() {
+                let ty =
+                    Expression::RepeaterModelReference { element: Rc::downgrade(element) }.ty();
+                hints.push((
+                    id.text_range().end(),
+                    format!(": {}", type_name(&ty)),
+                    Some(InlayHintKind::TYPE),
+                ));
+            }
+            if let Some(index) =
+                repeated.RepeatedIndex().and_then(|i| i.child_token(SyntaxKind::Identifier))
+            {
+                hints.push((index.text_range().end(), ": int".into(), Some(InlayHintKind::TYPE)));
+            }
+        }
+    }
+
+    // The implicit conversions of the bindings
+    for child in node.children() {
+        let (name, binding_expression) = match child.kind() {
+            SyntaxKind::Binding => (
+                child.child_text(SyntaxKind::Identifier),
+                child.child_node(SyntaxKind::BindingExpression),
+            ),
+            SyntaxKind::PropertyDeclaration => (
+                child
+                    .child_node(SyntaxKind::DeclaredIdentifier)
+                    .and_then(|d| i_slint_compiler::parser::identifier_text(&d)),
+                child.child_node(SyntaxKind::BindingExpression),
+            ),
+            _ => continue,
+        };
+        let (Some(name), Some(expression_node)) =
+            (name, binding_expression.and_then(|b| b.child_node(SyntaxKind::Expression)))
+        else {
+            continue;
+        };
+        let name = normalize_identifier(&name);
+        let Some(binding) = e.bindings.get(&name) else { continue };
+        let property_type = e.lookup_property(&name).property_type;
+        if let Some(label) = conversion_label(&binding.borrow().expression, &property_type, &name) {
+            if let Some(end) = last_token(&expression_node) {
+                hints.push((end.text_range().end(), label, None));
+            }
+        }
+    }
+}
+
+/// Describe the implicit conversion done on the result of a binding expression
+fn conversion_label(expression: &Expression, property_type: &Type, name: &str) -> Option<String> {
+    if *property_type == Type::LogicalLength && expression.ty() == Type::Percent {
+        return Some(format!("× parent.{name}"));
+    }
+    let Expression::Cast { from, to } = expression else { return None };
+    let mut operations = Vec::new();
+    let mut e = &**from;
+    while let Expression::BinaryExpression { lhs, rhs, op } = e {
+        let factor = match &**rhs {
+            Expression::NumberLiteral(x, Unit::None)
+                if *x == 0.01 && *op == '*' && lhs.ty() == Type::Percent =>
+            {
+                operations.push("÷ 100".to_string());
+                break;
+            }
+            Expression::FunctionCall { function, .. } => match &**function {
+                Expression::BuiltinFunctionReference(BuiltinFunction::GetWindowScaleFactor, _) => {
+                    "scale factor"
+                }
+                Expression::BuiltinFunctionReference(
+                    BuiltinFunction::GetWindowDefaultFontSize,
+                    _,
+                ) => "default font size",
+                _ => break,
+            },
+            _ => break,
+        };
+        operations.push(format!("{} {factor}", if *op == '*' { '×' } else { '÷' }));
+        e = lhs;
+    }
+    if !operations.is_empty() {
+        operations.reverse();
+        return Some(operations.join(" "));
+    }
+    match (from.ty(), to) {
+        (Type::String, _) | (_, Type::Float32) | (_, Type::Brush) => None,
+        (_, Type::String) | (Type::Float32, Type::Int32) => Some(format!("→ {}", type_name(to))),
+        _ => None,
+    }
+}
+
+/// The last token of the node that is not a whitespace or a comment
+fn last_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    let token = node
+        .descendants_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| !matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment))
+        .last()?;
+    Some(SyntaxToken { token, source_file: node.source_file.clone() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    /// Returns the hints of the whole document as `(line, column, label)`
+    fn hints(source: &str) -> Vec<(u32, u32, String)> {
+        let (dc, url, _) = loaded_document_cache(source.into());
+        let range = lsp_types::Range::new(
+            lsp_types::Position::new(0, 0),
+            lsp_types::Position::new(u32::MAX, 0),
+        );
+        get_inlay_hints(&dc, &lsp_types::TextDocumentIdentifier { uri: url }, &range)
+            .unwrap()
+            .into_iter()
+            .map(|h| match h.label {
+                InlayHintLabel::String(label) => (h.position.line, h.position.character, label),
+                _ => panic!("unexpected label"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_alias_and_repeater_hints() {
+        let source = r#"
+struct Item { name: string, checked: bool }
+component Foo {
+    property <[Item]> items;
+    property value <=> t.text;
+    callback pressed <=> fs.key-pressed;
+    for item[index] in items: Text { text: item.name; }
+    for x in 3: Rectangle {}
+    if true: Rectangle {}
+    t := TextInput {}
+    fs := FocusScope {}
+}"#;
+        assert_eq!(
+            hints(source),
+            [
+                (4, 13, "<string>".into()),
+                (5, 20, "(KeyEvent) -> EventResult".into()),
+                (6, 12, ": Item".into()),
+                (6, 18, ": int".into()),
+                (7, 9, ": int".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conversion_hints() {
+        let source = r#"
+component Foo {
+    property <float> ratio: 50%;
+    property <string> label: 42;
+    property <int> count: 4.5;
+    property <physical-length> ph: 10px;
+    property <length> text-size: 2rem;
+    property <float> f: 42;
+    Rectangle {
+        width: 50%;
+        height: 20phx;
+        background: red;
+    }
+}"#;
+        assert_eq!(
+            hints(source),
+            [
+                (2, 31, "÷ 100".into()),
+                (3, 31, "→ string".into()),
+                (4, 29, "→ int".into()),
+                (5, 39, "× scale factor".into()),
+                (6, 37, "× default font size".into()),
+                (9, 18, "× parent.width".into()),
+                (10, 21, "÷ scale factor".into()),
+            ]
+        );
+    }
+}
@@ -331,6 +331,10 @@ fn get_properties(element: &ElementRc) -> Vec<PropertyInformation> {
                         "rotation",
                         i_slint_compiler::typeregister::RESERVED_ROTATION_PROPERTIES,
                     ));
+                    result.extend(get_reserved_properties(
+                        "rotation",
+                        i_slint_compiler::typeregister::RESERVED_SCALE_PROPERTIES,
+                    ));
                 }
 
                 if b.name == "Rectangle" {
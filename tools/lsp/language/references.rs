@@ -0,0 +1,235 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::DocumentCache;
+use crate::util::map_token;
+
+#[cfg(target_arch = "wasm32")]
+use crate::wasm_prelude::*;
+
+use i_slint_compiler::parser::{
+    normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+
+use lsp_types::{Location, Url};
+
+/// Returns all the places that refer to the same component, global, property, callback,
+/// function, struct, enum or element id as the given token, in all the loaded documents.
+pub fn find_references(
+    document_cache: &mut DocumentCache,
+    token: SyntaxToken,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let definition = declaration_node(&token)
+        .or_else(|| super::goto::find_definition(document_cache, token.clone()))?;
+    let name = normalize_identifier(token.text());
+
+    // Only look at the identifiers with the same name, and check that they resolve to the same
+    // definition
+    let candidates = document_cache
+        .documents
+        .all_documents()
+        .filter_map(|doc| doc.node.clone())
+        .filter(|doc| !doc.source_file.path().starts_with("builtin:/"))
+        .flat_map(|doc| {
+            let source_file = doc.source_file.clone();
+            doc.descendants_with_tokens()
+                .filter_map(|t| t.into_token())
+                .filter(|t| {
+                    t.kind() == SyntaxKind::Identifier && normalize_identifier(t.text()) == name
+                })
+                .map(move |token| SyntaxToken { token, source_file: source_file.clone() })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut result = candidates
+        .into_iter()
+        .filter(|t| {
+            let decl = declaration_node(t);
+            if decl.is_some() && !include_declaration {
+                return false;
+            }
+            decl.or_else(|| super::goto::find_definition(document_cache, t.clone()))
+                .map_or(false, |d| is_same_node(&d, &definition))
+        })
+        .filter_map(|t| {
+            let uri = Url::from_file_path(t.source_file.path()).ok()?;
+            Some(Location { uri, range: map_token(&t)? })
+        })
+        .collect::<Vec<_>>();
+    result.sort_by_key(|l| (l.uri.to_string(), l.range.start.line, l.range.start.character));
+    Some(result)
+}
+
+/// If the token is the name in a declaration, returns the node that [`super::goto::find_definition`]
+/// returns for its uses
+fn declaration_node(token: &SyntaxToken) -> Option<SyntaxNode> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let parent = token.parent();
+    match parent.kind() {
+        // The id of an element
+        SyntaxKind::SubElement => parent.child_node(SyntaxKind::Element),
+        SyntaxKind::DeclaredIdentifier => {
+            let declaration = parent.parent()?;
+            match declaration.kind() {
+                SyntaxKind::Component => {
+                    Some(syntax_nodes::Component::new(declaration)?.Element().into())
+                }
+                SyntaxKind::PropertyDeclaration
+                | SyntaxKind::CallbackDeclaration
+                | SyntaxKind::Function
+                | SyntaxKind::StructDeclaration
+                | SyntaxKind::EnumDeclaration => Some(declaration),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_same_node(a: &SyntaxNode, b: &SyntaxNode) -> bool {
+    a.text_range() == b.text_range() && a.source_file.path() == b.source_file.path()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::{complex_document_cache, empty_document_cache};
+    use crate::language::{reload_document_impl, token_at_offset};
+
+    /// Returns the references to the first occurrence of `needle` in the document at `url`, as
+    /// `(file name, line, column)`
+    fn references(
+        dc: &mut DocumentCache,
+        url: &Url,
+        needle: &str,
+        include_declaration: bool,
+    ) -> Vec<(String, u32, u32)> {
+        let doc = dc.documents.get_document(&url.to_file_path().unwrap()).unwrap();
+        let doc_node = doc.node.clone().unwrap();
+        let offset = doc_node.source_file.source().unwrap().find(needle).unwrap() as u32;
+        let token = token_at_offset(&doc_node, offset + 1).unwrap();
+        find_references(dc, token, include_declaration)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|l| {
+                let path = l.uri.to_file_path().unwrap();
+                let file = path.file_name().unwrap().to_string_lossy().to_string();
+                (file, l.range.start.line, l.range.start.character)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_property_references() {
+        let (mut dc, url, _) = complex_document_cache();
+        let bar = |l, c| ("bar.slint".to_string(), l, c);
+        let expected = [bar(3, 24), bar(9, 41), bar(22, 56), bar(28, 19), bar(40, 25), bar(41, 68)];
+
+        assert_eq!(references(&mut dc, &url, "total-time:", true), expected);
+        // From a use, the result is the same
+        assert_eq!(references(&mut dc, &url, "total-time)", true), expected);
+        assert_eq!(references(&mut dc, &url, "total-time:", false), expected[1..]);
+
+        let expected = [
+            bar(4, 24),
+            bar(8, 8),
+            bar(9, 8),
+            bar(9, 27),
+            bar(22, 43),
+            bar(41, 25),
+            bar(41, 49),
+            bar(48, 16),
+        ];
+        assert_eq!(references(&mut dc, &url, "elapsed-time;", true), expected);
+    }
+
+    #[test]
+    fn test_element_id_references() {
+        let (mut dc, url, _) = complex_document_cache();
+        let bar = |l, c| ("bar.slint".to_string(), l, c);
+        assert_eq!(references(&mut dc, &url, "slider.", true), [bar(3, 36), bar(36, 12)]);
+    }
+
+    #[test]
+    fn test_references_across_files() {
+        let mut dc = empty_document_cache();
+        let lib_url = Url::from_file_path(if cfg!(target_family = "windows") {
+            "c://foo/lib.slint"
+        } else {
+            "/foo/lib.slint"
+        })
+        .unwrap();
+        let url = lib_url.join("main.slint").unwrap();
+        spin_on::spin_on(reload_document_impl(
+            None,
+            r#"export struct LibStruct { x: int }
+export component Lib {
+    in property <int> value;
+    callback activated;
+}
+export global Palette { out property <color> fg; }
+export enum Mode { light, dark }
+"#
+            .into(),
+            lib_url.clone(),
+            1,
+            &mut dc,
+        ));
+        spin_on::spin_on(reload_document_impl(
+            None,
+            r#"import { Lib, LibStruct, Palette, Mode } from "lib.slint";
+component Foo {
+    callback clicked;
+    property <LibStruct> s;
+    Lib { value: 42; }
+    Lib { value: s.x; activated => { root.clicked() } }
+    TouchArea { clicked => { root.clicked(); } }
+}
+export component Main {
+    f := Foo { clicked => {} }
+    property <Mode> mode: Mode.dark;
+    Rectangle { background: Palette.fg; }
+}
+"#
+            .into(),
+            url.clone(),
+            1,
+            &mut dc,
+        ));
+
+        let main = |l, c| ("main.slint".to_string(), l, c);
+        let lib = |l, c| ("lib.slint".to_string(), l, c);
+        assert_eq!(
+            references(&mut dc, &url, "Lib,", true),
+            [lib(1, 17), main(0, 9), main(4, 4), main(5, 4)]
+        );
+        assert_eq!(
+            references(&mut dc, &lib_url, "value", true),
+            [lib(2, 22), main(4, 10), main(5, 10)]
+        );
+        assert_eq!(references(&mut dc, &lib_url, "LibStruct", false), [main(0, 14), main(3, 14)]);
+        // The `clicked` of the TouchArea is another callback
+        assert_eq!(
+            references(&mut dc, &url, "clicked;", true),
+            [main(2, 13), main(5, 42), main(6, 34), main(9, 15)]
+        );
+        assert_eq!(references(&mut dc, &url, "clicked =>", true), []);
+
+        assert_eq!(
+            references(&mut dc, &lib_url, "Palette", true),
+            [lib(5, 14), main(0, 25), main(11, 28)]
+        );
+        assert_eq!(references(&mut dc, &lib_url, "fg", true), [lib(5, 45), main(11, 36)]);
+        assert_eq!(
+            references(&mut dc, &lib_url, "Mode", true),
+            [lib(6, 12), main(0, 34), main(10, 14), main(10, 26)]
+        );
+    }
+}
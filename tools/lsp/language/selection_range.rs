@@ -0,0 +1,124 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::DocumentCache;
+use crate::util::map_range;
+
+use i_slint_compiler::parser::{SyntaxKind, SyntaxNode, TextRange, TextSize};
+
+use lsp_types::SelectionRange;
+
+/// Returns, for each position, the ranges of the syntax nodes around it, from the innermost
+/// to the whole document, so that the editor can expand the selection.
+pub fn get_selection_ranges(
+    document_cache: &DocumentCache,
+    text_document: &lsp_types::TextDocumentIdentifier,
+    positions: &[lsp_types::Position],
+) -> Option<Vec<SelectionRange>> {
+    let uri_path = super::uri_to_file(&text_document.uri)?;
+    let doc = document_cache.documents.get_document(&uri_path)?;
+    let doc_node = doc.node.as_ref()?;
+    let sf = &doc_node.source_file;
+
+    let selection_range = |pos: &lsp_types::Position| -> Option<SelectionRange> {
+        let offset = sf.offset(pos.line as usize + 1, pos.character as usize + 1) as u32;
+        let token = super::token_at_offset(doc_node, offset)?;
+
+        let mut ranges = Vec::new();
+        if !matches!(token.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment) {
+            let range = token.text_range();
+            // The content of a string literal, without the quotes
+            if token.kind() == SyntaxKind::StringLiteral && range.len() > 2.into() {
+                let one = TextSize::from(1);
+                ranges.push(TextRange::new(range.start() + one, range.end() - one));
+            }
+            ranges.push(range);
+        }
+        let mut node = Some(token.parent());
+        while let Some(n) = node {
+            if let Some(range) = trimmed_range(&n) {
+                if ranges.last() != Some(&range) {
+                    ranges.push(range);
+                }
+            }
+            node = n.parent();
+        }
+
+        ranges.into_iter().rev().fold(None, |parent, range| {
+            Some(SelectionRange { range: map_range(sf, range), parent: parent.map(Box::new) })
+        })
+    };
+
+    // There must be one result per position
+    let result = positions
+        .iter()
+        .map(|pos| {
+            selection_range(pos).unwrap_or_else(|| SelectionRange {
+                range: lsp_types::Range::new(*pos, *pos),
+                parent: None,
+            })
+        })
+        .collect();
+    Some(result)
+}
+
+/// The range of the node, without the leading and trailing whitespaces and comments
+fn trimmed_range(node: &SyntaxNode) -> Option<TextRange> {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| !matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment));
+    let first = tokens.next()?;
+    let last = tokens.last().unwrap_or_else(|| first.clone());
+    Some(TextRange::new(first.text_range().start(), last.text_range().end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    #[test]
+    fn test_selection_ranges() {
+        let source = r#"component Foo {
+    Text {
+        text: "hello " + 42;
+    }
+}"#;
+        let (dc, url, _) = loaded_document_cache(source.into());
+        let position = lsp_types::Position::new(2, 18);
+        let result =
+            get_selection_ranges(&dc, &lsp_types::TextDocumentIdentifier { uri: url }, &[position])
+                .unwrap();
+        assert_eq!(result.len(), 1);
+
+        let mut texts = Vec::new();
+        let mut range = Some(&result[0]);
+        while let Some(r) = range {
+            let lines = source.lines().collect::<Vec<_>>();
+            let (start, end) = (r.range.start, r.range.end);
+            let text = if start.line == end.line {
+                lines[start.line as usize][start.character as usize..end.character as usize]
+                    .to_string()
+            } else {
+                format!("{}..{}", start.line, end.line)
+            };
+            texts.push(text);
+            range = r.parent.as_deref();
+        }
+        assert_eq!(
+            texts,
+            [
+                "hello ",
+                "\"hello \"",
+                "\"hello \" + 42",
+                "\"hello \" + 42;",
+                "text: \"hello \" + 42;",
+                "1..3",
+                // The root element, and then the component
+                "0..4",
+                "0..4",
+            ]
+        );
+    }
+}
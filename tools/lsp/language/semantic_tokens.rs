@@ -52,6 +52,7 @@ pub fn get_semantic_tokens(
                 SyntaxKind::ConditionalElement => Some((self::KEYWORD, 0)),
                 SyntaxKind::CallbackDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::CallbackConnection => Some((self::FUNCTION, 0)),
+                SyntaxKind::PropertyChangedCallback => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::Function => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyAnimation => Some((self::KEYWORD, 0)),
@@ -78,6 +79,7 @@ pub fn get_semantic_tokens(
                         SyntaxKind::CallbackConnection => {
                             Some((self::PARAMETER, 1 << self::DEFINITION))
                         }
+                        SyntaxKind::PropertyChangedCallback => Some((self::PROPERTY, 0)),
                         SyntaxKind::PropertyDeclaration => {
                             Some((self::PROPERTY, 1 << self::DEFINITION))
                         }
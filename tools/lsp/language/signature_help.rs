@@ -0,0 +1,410 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+use super::hover::{doc_comment, element_for_node, type_name};
+use super::DocumentCache;
+
+use i_slint_compiler::expression_tree::{BuiltinMacroFunction, Expression};
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::lookup::LookupResult;
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken,
+    TextSize,
+};
+
+use lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, SignatureHelp,
+    SignatureInformation,
+};
+
+/// The characters that trigger a signature help request
+pub const TRIGGER_CHARACTERS: &[&str] = &["(", ",", "{"];
+
+/// A function, callback or struct with the parameters (or fields) being edited
+struct Signature {
+    /// The text up to the first parameter, e.g. `foo(` or `Point { `
+    prefix: String,
+    /// The label and the type of each parameter (the type is `Invalid` for builtin macros)
+    parameters: Vec<(String, Type)>,
+    /// The text after the last parameter, e.g. `) -> int` or ` }`
+    suffix: String,
+    documentation: Option<String>,
+}
+
+/// Returns the signature of the function, callback or struct which has the argument or the
+/// field at `offset` in its parentheses or braces.
+/// `token` is the token right before the cursor.
+pub fn get_signature_help(
+    document_cache: &DocumentCache,
+    token: SyntaxToken,
+    offset: u32,
+) -> Option<SignatureHelp> {
+    let offset = TextSize::from(offset);
+    let mut node = token.parent();
+    loop {
+        match node.kind() {
+            SyntaxKind::FunctionCallExpression if is_inside(&node, offset, SyntaxKind::LParent) => {
+                let callee = node.child_node(SyntaxKind::Expression)?;
+                let signature = call_signature(document_cache, &callee)?;
+                let active = node
+                    .children_with_tokens()
+                    .filter(|t| t.kind() == SyntaxKind::Comma && t.text_range().end() <= offset)
+                    .count();
+                return Some(signature.into_signature_help(Some(active)));
+            }
+            SyntaxKind::ObjectLiteral if is_inside(&node, offset, SyntaxKind::LBrace) => {
+                let Type::Struct { fields, name, node: struct_node, .. } =
+                    expected_type(document_cache, &node.parent()?)?
+                else {
+                    return None;
+                };
+                let members = node
+                    .children()
+                    .filter(|n| n.kind() == SyntaxKind::ObjectMember)
+                    .collect::<Vec<_>>();
+                let member_name = |m: &SyntaxNode| {
+                    m.child_text(SyntaxKind::Identifier).map(|n| normalize_identifier(&n))
+                };
+                // Highlight the field being edited, or the first one that has no value yet
+                let active = match members
+                    .iter()
+                    .find(|m| m.text_range().contains_inclusive(offset))
+                    .and_then(member_name)
+                {
+                    Some(current) => fields.keys().position(|k| *k == current),
+                    None => {
+                        let used = members.iter().filter_map(member_name).collect::<Vec<_>>();
+                        fields.keys().position(|k| !used.contains(k))
+                    }
+                };
+                let signature = Signature {
+                    prefix: match name {
+                        Some(name) => format!("{name} {{ "),
+                        None => "{ ".into(),
+                    },
+                    parameters: fields
+                        .iter()
+                        .map(|(k, ty)| (format!("{k}: {}", type_name(ty)), ty.clone()))
+                        .collect(),
+                    suffix: " }".into(),
+                    documentation: struct_node.and_then(|n| doc_comment(&n.parent()?)),
+                };
+                return Some(signature.into_signature_help(active));
+            }
+            SyntaxKind::Element | SyntaxKind::Component | SyntaxKind::Document => return None,
+            _ => {}
+        }
+        node = node.parent()?;
+    }
+}
+
+/// Returns true if the offset is after the opening parenthesis or brace of the node, and not
+/// after its closing one
+fn is_inside(node: &SyntaxNode, offset: TextSize, open: SyntaxKind) -> bool {
+    let Some(open) = node.child_token(open) else { return false };
+    let close = node.children_with_tokens().filter_map(|t| t.into_token()).last();
+    open.text_range().end() <= offset
+        && close.map_or(true, |c| {
+            !matches!(c.kind(), SyntaxKind::RParent | SyntaxKind::RBrace)
+                || offset <= c.text_range().start()
+        })
+}
+
+/// Resolve the expression that is called, if it is a named callback, function or builtin function
+fn call_signature(document_cache: &DocumentCache, callee: &SyntaxNode) -> Option<Signature> {
+    let qualified_name =
+        syntax_nodes::QualifiedName::new(callee.child_node(SyntaxKind::QualifiedName)?)?;
+    let token = qualified_name
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .last()?;
+    let name = token.text().to_string();
+    let LookupResult::Expression { expression, .. } =
+        super::goto::lookup_expression_token(document_cache, &qualified_name, &token)?
+    else {
+        return None;
+    };
+
+    match expression {
+        Expression::CallbackReference(nr, _) | Expression::FunctionReference(nr, _) => {
+            let ty = nr.element().borrow().lookup_property(nr.name()).property_type;
+            let declaration = declaration_node(&nr.element(), nr.name());
+            // Only functions have named arguments
+            let arg_names = declaration
+                .clone()
+                .and_then(syntax_nodes::Function::new)
+                .map(|f| {
+                    f.ArgumentDeclaration()
+                        .map(|a| identifier_text(&a.DeclaredIdentifier()).unwrap_or_default())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            let (args, return_type) = match ty {
+                Type::Callback { args, return_type } => {
+                    (args, return_type.map(|r| *r).unwrap_or(Type::Void))
+                }
+                Type::Function { args, return_type } => (args, *return_type),
+                _ => return None,
+            };
+            let mut signature = function_signature(&name, &args, &return_type, 0);
+            for ((label, _), arg_name) in signature.parameters.iter_mut().zip(arg_names) {
+                *label = format!("{arg_name}: {label}");
+            }
+            signature.documentation = declaration.as_ref().and_then(doc_comment);
+            Some(signature)
+        }
+        Expression::BuiltinFunctionReference(f, _) => {
+            let Type::Function { args, return_type } = f.ty() else { return None };
+            Some(function_signature(&name, &args, &return_type, 0))
+        }
+        Expression::MemberFunction { member, .. } => {
+            let Expression::BuiltinFunctionReference(f, _) = *member else { return None };
+            let Type::Function { args, return_type } = f.ty() else { return None };
+            // The first argument is the object on which the function is called
+            Some(function_signature(&name, &args, &return_type, 1))
+        }
+        Expression::BuiltinMacroReference(m, _) => Some(macro_signature(&name, m)),
+        _ => None,
+    }
+}
+
+fn function_signature(name: &str, args: &[Type], return_type: &Type, skip: usize) -> Signature {
+    Signature {
+        prefix: format!("{name}("),
+        parameters: args.iter().skip(skip).map(|ty| (type_name(ty), ty.clone())).collect(),
+        suffix: match return_type {
+            Type::Void => ")".into(),
+            ret => format!(") -> {}", type_name(ret)),
+        },
+        documentation: None,
+    }
+}
+
+fn macro_signature(name: &str, m: BuiltinMacroFunction) -> Signature {
+    let (parameters, suffix): (&[&str], _) = match m {
+        BuiltinMacroFunction::Min | BuiltinMacroFunction::Max => (&["a", "b"], ", ...)"),
+        BuiltinMacroFunction::Mod => (&["a", "b"], ")"),
+        BuiltinMacroFunction::CubicBezier => {
+            (&["x1: float", "y1: float", "x2: float", "y2: float"], ") -> easing")
+        }
+        BuiltinMacroFunction::Steps => (&["n: int", "start | end"], ") -> easing"),
+        BuiltinMacroFunction::Spring => {
+            (&["mass: float", "stiffness: float", "damping: float"], ") -> easing")
+        }
+        BuiltinMacroFunction::Rgb => (
+            &[
+                "red: int | percent",
+                "green: int | percent",
+                "blue: int | percent",
+                "alpha: float | percent",
+            ],
+            ") -> color",
+        ),
+        BuiltinMacroFunction::Debug => (&["value"], ", ...)"),
+    };
+    Signature {
+        prefix: format!("{name}("),
+        parameters: parameters.iter().map(|p| (p.to_string(), Type::Invalid)).collect(),
+        suffix: suffix.into(),
+        documentation: None,
+    }
+}
+
+/// The type that an expression node is expected to have, given where it is placed
+fn expected_type(document_cache: &DocumentCache, expression: &SyntaxNode) -> Option<Type> {
+    let parent = expression.parent()?;
+    match parent.kind() {
+        SyntaxKind::Expression => expected_type(document_cache, &parent),
+        SyntaxKind::BindingExpression => {
+            let binding = parent.parent()?;
+            let name = match binding.kind() {
+                SyntaxKind::Binding => binding.child_text(SyntaxKind::Identifier)?,
+                SyntaxKind::PropertyDeclaration => {
+                    identifier_text(&binding.child_node(SyntaxKind::DeclaredIdentifier)?)?
+                }
+                _ => return None,
+            };
+            let element = element_for_node(document_cache, &binding.parent()?)?;
+            let ty = element.borrow().lookup_property(&normalize_identifier(&name)).property_type;
+            Some(ty)
+        }
+        SyntaxKind::ObjectMember => {
+            let Type::Struct { fields, .. } = expected_type(document_cache, &parent.parent()?)?
+            else {
+                return None;
+            };
+            fields.get(&normalize_identifier(&parent.child_text(SyntaxKind::Identifier)?)).cloned()
+        }
+        SyntaxKind::ObjectLiteral => expected_type(document_cache, &parent),
+        SyntaxKind::Array => match expected_type(document_cache, &parent.parent()?)? {
+            Type::Array(ty) => Some(*ty),
+            _ => None,
+        },
+        SyntaxKind::FunctionCallExpression => {
+            let mut args = parent.children().filter(|n| n.kind() == SyntaxKind::Expression);
+            let callee = args.next()?;
+            let index = args.position(|a| a.text_range() == expression.text_range())?;
+            let signature = call_signature(document_cache, &callee)?;
+            signature.parameters.into_iter().nth(index).map(|(_, ty)| ty)
+        }
+        _ => None,
+    }
+}
+
+/// Find the declaration of a property, callback or function in the element or its bases
+fn declaration_node(
+    element: &i_slint_compiler::object_tree::ElementRc,
+    name: &str,
+) -> Option<SyntaxNode> {
+    let mut el = element.clone();
+    loop {
+        if let Some(decl) = el.borrow().property_declarations.get(name) {
+            return decl.node.clone();
+        }
+        let base = el.borrow().base_type.clone();
+        match base {
+            ElementType::Component(c) => el = c.root_element.clone(),
+            _ => return None,
+        }
+    }
+}
+
+impl Signature {
+    fn into_signature_help(self, active_parameter: Option<usize>) -> SignatureHelp {
+        let mut label = self.prefix;
+        let mut parameters = Vec::with_capacity(self.parameters.len());
+        for (i, (parameter, _)) in self.parameters.iter().enumerate() {
+            if i > 0 {
+                label.push_str(", ");
+            }
+            let start = label.encode_utf16().count() as u32;
+            label.push_str(parameter);
+            let end = label.encode_utf16().count() as u32;
+            parameters.push(ParameterInformation {
+                label: ParameterLabel::LabelOffsets([start, end]),
+                documentation: None,
+            });
+        }
+        label.push_str(&self.suffix);
+        SignatureHelp {
+            signatures: vec![SignatureInformation {
+                label,
+                documentation: self.documentation.map(|value| {
+                    Documentation::MarkupContent(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value,
+                    })
+                }),
+                parameters: Some(parameters),
+                active_parameter: None,
+            }],
+            active_signature: Some(0),
+            active_parameter: active_parameter.map(|a| a as u32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::test::loaded_document_cache;
+
+    /// Returns the label of the signature and the active parameter at the position of the `🔺`
+    /// marker in `source`
+    fn signature_at(source: &str) -> Option<(String, Option<String>)> {
+        let offset = source.find('🔺').unwrap() as u32;
+        let (dc, url, _) = loaded_document_cache(source.replace('🔺', ""));
+        let doc = dc.documents.get_document(&url.to_file_path().unwrap()).unwrap();
+        let doc_node = doc.node.clone().unwrap();
+        let token = doc_node.token_at_offset(offset.into()).left_biased().unwrap();
+        let token = SyntaxToken { token, source_file: doc_node.source_file.clone() };
+        let help = get_signature_help(&dc, token, offset)?;
+        let signature = &help.signatures[0];
+        let active = help.active_parameter.and_then(|a| {
+            match &signature.parameters.as_ref()?.get(a as usize)?.label {
+                ParameterLabel::LabelOffsets([start, end]) => {
+                    let label = signature.label.encode_utf16().collect::<Vec<_>>();
+                    Some(String::from_utf16(&label[*start as usize..*end as usize]).unwrap())
+                }
+                ParameterLabel::Simple(s) => Some(s.clone()),
+            }
+        });
+        Some((signature.label.clone(), active))
+    }
+
+    #[test]
+    fn test_function_signature() {
+        let source = r#"
+component Foo {
+    /// Adds things
+    public function add(first: int, second: string) -> string { return second + first; }
+    callback notify(int, length) -> bool;
+    property <string> result: add(42, 🔺);
+    init => { root.notify(🔺1, 2px); }
+}"#;
+        let first = source.replacen('🔺', "", 1);
+        assert_eq!(
+            signature_at(&first),
+            Some(("notify(int, length) -> bool".into(), Some("int".into())))
+        );
+        let second = source.replacen('🔺', "", 2).replacen("(42, ", "(42, 🔺", 1);
+        assert_eq!(
+            signature_at(&second),
+            Some((
+                "add(first: int, second: string) -> string".into(),
+                Some("second: string".into())
+            ))
+        );
+        assert_eq!(signature_at("component Foo { property <int> x: 4🔺2; }"), None);
+    }
+
+    #[test]
+    fn test_builtin_signature() {
+        assert_eq!(
+            signature_at("component Foo { property <float> x: Math.pow(2, 🔺8); }"),
+            Some(("pow(float, float) -> float".into(), Some("float".into())))
+        );
+        assert_eq!(
+            signature_at(
+                "component Foo { in property <string> s; property <float> x: s.to-float(🔺); }"
+            )
+            .map(|s| s.0),
+            Some("to-float() -> float".into())
+        );
+        assert_eq!(
+            signature_at("component Foo { property <color> c: rgba(1, 2, 🔺3, 50%); }"),
+            Some((
+                "rgba(red: int | percent, green: int | percent, blue: int | percent, \
+                 alpha: float | percent) -> color"
+                    .into(),
+                Some("blue: int | percent".into())
+            ))
+        );
+        assert_eq!(
+            signature_at("component Foo { property <int> m: max(1, 2, 🔺3); }"),
+            Some(("max(a, b, ...)".into(), None))
+        );
+    }
+
+    #[test]
+    fn test_struct_signature() {
+        let source = r#"
+struct Point { x: length, y: length }
+struct Line { from: Point, to: Point, width: length }
+component Foo {
+    property <Line> line: { from: { x: 1px, y: 2px }, 🔺 };
+}"#;
+        assert_eq!(
+            signature_at(source),
+            Some((
+                "Line { from: Point, to: Point, width: length }".into(),
+                Some("to: Point".into())
+            ))
+        );
+        assert_eq!(
+            signature_at(&source.replace("🔺", "").replace("y: 2px", "y: 🔺2px")),
+            Some(("Point { x: length, y: length }".into(), Some("y: length".into())))
+        );
+    }
+}